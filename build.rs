@@ -0,0 +1,412 @@
+#[cfg(feature = "vendored")]
+extern crate cc;
+extern crate pkg_config;
+
+use std::env;
+#[cfg(feature = "vendored")]
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug)]
+enum Backend {
+    OpenBlas,
+    Netlib,
+    Blis,
+    Mkl,
+}
+
+impl Backend {
+    fn name(self) -> &'static str {
+        match self {
+            Backend::OpenBlas => "OpenBLAS",
+            Backend::Netlib => "Netlib",
+            Backend::Blis => "BLIS",
+            Backend::Mkl => "MKL",
+        }
+    }
+
+    fn selected() -> Option<Backend> {
+        let candidates = [
+            ("openblas", Backend::OpenBlas),
+            ("netlib", Backend::Netlib),
+            ("blis", Backend::Blis),
+            ("mkl", Backend::Mkl),
+        ];
+        let mut selected: Option<Backend> = None;
+        for &(name, backend) in candidates.iter() {
+            if !feature(name) {
+                continue;
+            }
+            if let Some(other) = selected {
+                panic!(
+                    "only one backend can be selected, but both {} and {} are",
+                    other.name(),
+                    backend.name()
+                );
+            }
+            selected = Some(backend);
+        }
+        selected
+    }
+
+    fn pkg_config_modules(self, statik: bool) -> Vec<&'static str> {
+        match self {
+            Backend::OpenBlas => openblas_names(),
+            Backend::Netlib if feature("fortran") => vec!["blas"],
+            Backend::Netlib => vec!["cblas"],
+            Backend::Blis => vec!["blis"],
+            Backend::Mkl => match (statik, feature("ilp64")) {
+                (false, false) => vec!["mkl-dynamic-lp64-seq", "mkl-sdl"],
+                // Not `mkl-sdl`, whose single library defaults to the LP64
+                // interface.
+                (false, true) => vec!["mkl-dynamic-ilp64-seq"],
+                (true, false) => vec!["mkl-static-lp64-seq"],
+                (true, true) => vec!["mkl-static-ilp64-seq"],
+            },
+        }
+    }
+
+    // The libraries to look for, in order of preference. Each entry is a set of
+    // libraries that has to be linked together; the first one is looked up.
+    fn libraries(self, statik: bool) -> Vec<Vec<&'static str>> {
+        match self {
+            Backend::OpenBlas => openblas_names()
+                .into_iter()
+                .map(|name| vec![name])
+                .collect(),
+            Backend::Netlib if feature("fortran") => vec![vec!["blas"]],
+            Backend::Netlib => vec![vec!["cblas", "blas"], vec!["blas"]],
+            Backend::Blis => vec![vec!["blis"]],
+            // The single dynamic library defaults to the LP64 interface, so the
+            // ILP64 one is linked explicitly.
+            Backend::Mkl if statik || feature("ilp64") => {
+                let interface = if feature("ilp64") {
+                    "mkl_intel_ilp64"
+                } else {
+                    "mkl_intel_lp64"
+                };
+                vec![vec![interface, "mkl_sequential", "mkl_core"]]
+            }
+            Backend::Mkl => vec![vec!["mkl_rt"]],
+        }
+    }
+
+    fn directories(self) -> Vec<PathBuf> {
+        let mut directories = vec![];
+        if let Backend::Mkl = self {
+            if let Some(root) = env::var_os("MKLROOT") {
+                directories.push(Path::new(&root).join("lib").join("intel64"));
+                directories.push(Path::new(&root).join("lib"));
+            }
+            directories.push(PathBuf::from("/opt/intel/oneapi/mkl/latest/lib/intel64"));
+            directories.push(PathBuf::from("/opt/intel/oneapi/mkl/latest/lib"));
+            directories.push(PathBuf::from("/opt/intel/mkl/lib/intel64"));
+        }
+        if let Backend::OpenBlas = self {
+            directories.push(PathBuf::from("/opt/homebrew/opt/openblas/lib"));
+            directories.push(PathBuf::from("/usr/local/opt/openblas/lib"));
+        }
+        directories
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=CBLAS_LIB");
+    println!("cargo:rerun-if-env-changed=CBLAS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=MKLROOT");
+    println!("cargo:rerun-if-env-changed=CBLAS_FORTRAN_COMPLEX");
+    println!("cargo:rustc-check-cfg=cfg(cblas_fortran_complex_pointer)");
+
+    // The pure-Rust implementation exports the functions itself.
+    if feature("pure-rust") {
+        if feature("fortran") {
+            panic!("the pure-Rust implementation cannot be selected together with Fortran BLAS");
+        }
+        if feature("vendored") {
+            panic!("the pure-Rust implementation cannot be selected together with the vendored sources");
+        }
+        if let Some(backend) = Backend::selected() {
+            panic!(
+                "{} cannot be selected together with the pure-Rust implementation",
+                backend.name()
+            );
+        }
+        return;
+    }
+
+    if feature("vendored") {
+        if feature("fortran") {
+            panic!("Fortran BLAS cannot be selected together with the vendored sources");
+        }
+        if let Some(backend) = Backend::selected() {
+            panic!(
+                "{} cannot be selected together with the vendored sources",
+                backend.name()
+            );
+        }
+        build_vendored();
+        return;
+    }
+
+    if feature("fortran") {
+        configure_fortran();
+    }
+
+    let statik = feature("static");
+    let kind = if statik { "static" } else { "dylib" };
+    let directory = env::var_os("CBLAS_LIB_DIR").map(PathBuf::from);
+
+    if let Ok(libraries) = env::var("CBLAS_LIB") {
+        if let Some(ref directory) = directory {
+            println!("cargo:rustc-link-search=native={}", directory.display());
+        }
+        for library in libraries
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            println!("cargo:rustc-link-lib={}={}", kind, library);
+        }
+        return;
+    }
+
+    // Without a backend, linking is left to the user or to a crate such as
+    // `openblas-src`, which is how the package has always been used, except
+    // that Fortran BLAS defaults to the reference implementation.
+    let backend = match Backend::selected() {
+        Some(backend) => backend,
+        None if feature("fortran") => Backend::Netlib,
+        None => return,
+    };
+
+    if directory.is_none() {
+        for module in backend.pkg_config_modules(statik) {
+            if pkg_config::Config::new()
+                .statik(statik)
+                .probe(module)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    let mut directories = directory.into_iter().collect::<Vec<_>>();
+    directories.extend(backend.directories());
+    directories.extend(standard_directories());
+    for libraries in backend.libraries(statik) {
+        if let Some(directory) = directories
+            .iter()
+            .find(|directory| exists(directory, libraries[0], statik))
+        {
+            println!("cargo:rustc-link-search=native={}", directory.display());
+            for library in libraries {
+                println!("cargo:rustc-link-lib={}={}", kind, library);
+            }
+            if statik {
+                link_system_libraries(backend);
+            }
+            return;
+        }
+    }
+
+    panic!(
+        "failed to find {}; install it, make it visible to pkg-config, or set CBLAS_LIB_DIR",
+        backend.name(),
+    );
+}
+
+// Selects how the Fortran functions returning a complex number, e.g., `cdotu_`,
+// return it: by value, as C functions returning `float _Complex` do, which is
+// the convention of gfortran and of OpenBLAS and BLIS, or via a hidden pointer
+// passed before the other arguments, which is the convention of the Intel
+// compilers, of f2c, and of g77, and hence that of the Intel interface of MKL.
+// `CBLAS_FORTRAN_COMPLEX` can be set to `value` or `pointer` to override the
+// choice, which follows the backend otherwise.
+fn configure_fortran() {
+    let pointer = match env::var("CBLAS_FORTRAN_COMPLEX") {
+        Ok(ref convention) if convention == "value" => false,
+        Ok(ref convention) if convention == "pointer" => true,
+        Ok(convention) => panic!(
+            "CBLAS_FORTRAN_COMPLEX has to be either `value` or `pointer`, but it is `{}`",
+            convention
+        ),
+        Err(_) => matches!(Backend::selected(), Some(Backend::Mkl)),
+    };
+    if pointer {
+        println!("cargo:rustc-cfg=cblas_fortran_complex_pointer");
+    }
+}
+
+// The names of the pkg-config module and the library of OpenBLAS, which are
+// those of the 64-bit integer builds, e.g., `libopenblas64_.so`, if `ilp64` or
+// a suffix is selected, since the plain library has 32-bit integers and no
+// suffix.
+fn openblas_names() -> Vec<&'static str> {
+    if feature("suffix-64_") {
+        vec!["openblas64_", "openblas64"]
+    } else if feature("suffix-_64") {
+        vec!["openblas_64", "openblas64"]
+    } else if feature("ilp64") {
+        vec!["openblas64"]
+    } else {
+        vec!["openblas"]
+    }
+}
+
+// Compiles the reference implementations of CBLAS and BLAS, which are copied
+// from a release of LAPACK into `vendor/lapack` by `vendor/fetch.sh`.
+#[cfg(feature = "vendored")]
+fn build_vendored() {
+    let root = Path::new("vendor").join("lapack");
+    let cblas = root.join("CBLAS");
+    let blas = root.join("BLAS").join("SRC");
+    println!("cargo:rerun-if-changed={}", root.display());
+    println!("cargo:rerun-if-env-changed=FC");
+    if !cblas.join("src").is_dir() || !blas.is_dir() {
+        panic!(
+            "the reference sources are missing from {}; run vendor/fetch.sh",
+            root.display()
+        );
+    }
+    if feature("suffix-64_") || feature("suffix-_64") {
+        panic!("the vendored sources cannot be built with a suffix");
+    }
+
+    // The Fortran names are mangled as by gfortran, lowercase with a trailing
+    // underscore, which the header selected by CMake otherwise describes.
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let include = cblas.join("include");
+    fs::copy(
+        include.join("cblas_mangling_with_flags.h.in"),
+        out.join("cblas_mangling.h"),
+    )
+    .unwrap();
+
+    let mut c = cc::Build::new();
+    c.include(&include).include(&out).define("ADD_", None).warnings(false);
+    if feature("ilp64") {
+        c.define("WeirdNEC", None);
+    }
+    for file in sources(&cblas.join("src"), &["c"]) {
+        // The error handler is defined in Rust if the `xerbla` feature is
+        // enabled.
+        if feature("xerbla") && file.ends_with("cblas_xerbla.c") {
+            continue;
+        }
+        c.file(file);
+    }
+    c.compile("cblas_vendored");
+
+    let compiler = env::var("FC").unwrap_or_else(|_| String::from("gfortran"));
+    let mut fortran = cc::Build::new();
+    fortran.compiler(&compiler).warnings(false);
+    // The modules of the constants are written to and read from the output
+    // directory.
+    fortran.flag(format!("-J{}", out.display()));
+    if feature("ilp64") {
+        fortran.flag("-fdefault-integer-8");
+    }
+    let mut files = sources(&cblas.join("src"), &["f"]);
+    // The modules have to be compiled before the routines using them, and the
+    // handler of CBLAS, which forwards the errors to `cblas_xerbla`, replaces
+    // the one of BLAS.
+    let modules = ["la_constants.f90", "la_xisnan.F90"];
+    for module in modules.iter() {
+        files.push(blas.join(module));
+    }
+    files.extend(
+        sources(&blas, &["f", "f90", "F90"])
+            .into_iter()
+            .filter(|file| !file.ends_with("xerbla.f"))
+            .filter(|file| !modules.iter().any(|module| file.ends_with(module))),
+    );
+    for file in files {
+        fortran.file(file);
+    }
+    fortran.compile("blas_vendored");
+    println!("cargo:rustc-link-lib=dylib=gfortran");
+}
+
+// The source files in a directory with one of the extensions, in a stable
+// order.
+#[cfg(feature = "vendored")]
+fn sources(directory: &Path, extensions: &[&str]) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| extensions.contains(&extension))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+#[cfg(not(feature = "vendored"))]
+fn build_vendored() {}
+
+fn exists(directory: &Path, library: &str, statik: bool) -> bool {
+    let target = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let names = if target == "windows" {
+        vec![format!("{}.lib", library), format!("lib{}.a", library)]
+    } else if statik {
+        vec![format!("lib{}.a", library)]
+    } else if target == "macos" || target == "ios" {
+        vec![format!("lib{}.dylib", library)]
+    } else {
+        vec![format!("lib{}.so", library)]
+    };
+    names.iter().any(|name| directory.join(name).is_file())
+}
+
+fn feature(name: &str) -> bool {
+    let name = name.to_uppercase().replace('-', "_");
+    env::var_os(format!("CARGO_FEATURE_{}", name)).is_some()
+}
+
+fn link_system_libraries(backend: Backend) {
+    if env::var("CARGO_CFG_TARGET_OS")
+        .map(|os| os == "windows")
+        .unwrap_or(false)
+    {
+        return;
+    }
+    match backend {
+        Backend::OpenBlas | Backend::Netlib => {
+            println!("cargo:rustc-link-lib=dylib=gfortran");
+            println!("cargo:rustc-link-lib=dylib=pthread");
+        }
+        Backend::Blis => println!("cargo:rustc-link-lib=dylib=pthread"),
+        Backend::Mkl => {
+            println!("cargo:rustc-link-lib=dylib=pthread");
+            println!("cargo:rustc-link-lib=dylib=m");
+            println!("cargo:rustc-link-lib=dylib=dl");
+        }
+    }
+}
+
+fn standard_directories() -> Vec<PathBuf> {
+    let mut directories = vec![];
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    if !arch.is_empty() && !env.is_empty() {
+        let triplet = format!("{}-linux-{}", arch, env);
+        directories.push(Path::new("/usr/lib").join(&triplet));
+        directories.push(Path::new("/lib").join(&triplet));
+    }
+    for directory in [
+        "/usr/local/lib",
+        "/usr/local/lib64",
+        "/usr/lib64",
+        "/usr/lib",
+        "/lib",
+    ]
+    .iter()
+    {
+        directories.push(PathBuf::from(directory));
+    }
+    directories
+}
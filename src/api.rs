@@ -0,0 +1,43 @@
+//! A table of the functions declared by the package.
+
+use libc::{c_char, c_double, c_float};
+
+use {blasint, c_double_complex, c_float_complex, CBLAS_INDEX};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+macro_rules! api_cblas {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $ty:ty),* $(,)*) $(-> $ret:ty)*;
+    )*) => {
+        /// A table with a pointer to every function declared by the package.
+        ///
+        /// The table allows for switching between implementations at run time,
+        /// e.g., between the linked library, a library loaded via the
+        /// `dynamic` feature, and instrumented or mock functions. The fields
+        /// follow the declarations in order, and the layout is that of the
+        /// corresponding C struct.
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub struct CblasApi {
+            $(pub $name: unsafe extern "C" fn($($ty),*) $(-> $ret)*,)*
+            pub cblas_xerbla: unsafe extern "C" fn(blasint, *const c_char, *const c_char, ...),
+        }
+
+        impl CblasApi {
+            /// Create a table of the functions linked to the binary.
+            ///
+            /// Every function has to be provided by the linked library, even
+            /// if only some of them are called via the table.
+            #[inline]
+            pub fn linked() -> CblasApi {
+                CblasApi {
+                    $($name: ::$name,)*
+                    cblas_xerbla: ::cblas_xerbla,
+                }
+            }
+        }
+    };
+}
+
+cblas_functions!(api_cblas);
@@ -0,0 +1,482 @@
+//! Identification of the library providing the functions.
+//!
+//! [`detect`] reports which implementation is linked, its version, the width
+//! of its integers, its threading model, and which of the functions of the
+//! [`extensions`](../extensions/index.html) module it provides natively, as
+//! well as whether the CBLAS functions are those of the `fortran` feature,
+//! which calls the Fortran routines of the library. If the `openblas` or
+//! `mkl` feature is enabled, the service functions of the selected library
+//! are called directly. Otherwise, the implementation is
+//! identified by looking up service functions of OpenBLAS, MKL, and BLIS, and
+//! a global variable of the reference CBLAS, among the loaded libraries,
+//! taking into account the suffix selected via the `suffix-*` features, if
+//! any, for OpenBLAS and the extensions. If none of them is found, e.g., when
+//! the library is linked statically, the backend selected at build time is
+//! reported, if any, but without the details only the library can tell. A
+//! library loaded via the `dynamic` feature is not taken into account. The
+//! lookup is only supported on Unix.
+//!
+//! ```no_run
+//! # extern crate cblas_sys;
+//! # fn main() {
+//! use cblas_sys::backend;
+//!
+//! let backend = backend::detect();
+//! println!("CBLAS: {}", backend);
+//! if backend.symbols.batch {
+//!     // Pass the problems to `cblas_dgemm_batch` at once.
+//! }
+//! # }
+//! ```
+
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+use std::ffi::CStr;
+use std::fmt;
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+use std::mem;
+use std::string::{String, ToString};
+
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+use libc::c_char;
+#[cfg(not(feature = "mkl"))]
+use libc::c_int;
+
+use lookup::lookup;
+
+/// An implementation of CBLAS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Implementation {
+    /// OpenBLAS.
+    OpenBlas,
+    /// Intel MKL.
+    Mkl,
+    /// BLIS.
+    Blis,
+    /// The reference implementation from Netlib.
+    Netlib,
+    /// The implementation written in Rust, selected via the `pure-rust`
+    /// feature.
+    PureRust,
+    /// The reference implementation bundled with the package, selected via
+    /// the `vendored` feature.
+    Vendored,
+    /// An implementation that could not be identified.
+    Unknown,
+}
+
+impl fmt::Display for Implementation {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match *self {
+            Implementation::OpenBlas => "OpenBLAS",
+            Implementation::Mkl => "MKL",
+            Implementation::Blis => "BLIS",
+            Implementation::Netlib => "Netlib",
+            Implementation::PureRust => "pure Rust",
+            Implementation::Vendored => "vendored",
+            Implementation::Unknown => "unknown",
+        })
+    }
+}
+
+/// The width of the integers taken by the functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Integer {
+    /// 32-bit integers.
+    Lp64,
+    /// 64-bit integers.
+    Ilp64,
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match *self {
+            Integer::Lp64 => "LP64",
+            Integer::Ilp64 => "ILP64",
+        })
+    }
+}
+
+/// The threading model of an implementation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Threading {
+    /// A single thread.
+    Sequential,
+    /// Threads managed by the library, e.g., POSIX threads.
+    Threads,
+    /// OpenMP.
+    OpenMp,
+}
+
+impl fmt::Display for Threading {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match *self {
+            Threading::Sequential => "sequential",
+            Threading::Threads => "threads",
+            Threading::OpenMp => "OpenMP",
+        })
+    }
+}
+
+/// The functions of the [`extensions`](../extensions/index.html) module that
+/// the library provides natively rather than via the fallbacks, as judged by
+/// the double-precision variant.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Symbols {
+    /// `cblas_?gemm_batch`.
+    pub batch: bool,
+    /// `cblas_?gemmt`.
+    pub gemmt: bool,
+    /// `cblas_?axpby`.
+    pub axpby: bool,
+    /// `cblas_?omatcopy`.
+    pub omatcopy: bool,
+}
+
+impl Symbols {
+    fn detect() -> Symbols {
+        Symbols {
+            batch: lookup(symbol!(cblas_dgemm_batch)) != 0,
+            gemmt: lookup(symbol!(cblas_dgemmt)) != 0,
+            axpby: lookup(symbol!(cblas_daxpby)) != 0,
+            omatcopy: lookup(symbol!(cblas_domatcopy)) != 0,
+        }
+    }
+}
+
+/// A description of the library providing the functions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backend {
+    /// The implementation.
+    pub implementation: Implementation,
+    /// The version as reported by the library, if it does.
+    pub version: Option<String>,
+    /// The width of the integers as reported by the library, or that of
+    /// [`blasint`](../type.blasint.html) if it does not, e.g., MKL, whose
+    /// interface is selected at run time.
+    pub integer: Integer,
+    /// The threading model, if known.
+    pub threading: Option<Threading>,
+    /// The extensions provided natively.
+    pub symbols: Symbols,
+    /// Whether the CBLAS functions are those of the package calling the
+    /// Fortran routines of the library, i.e., the `fortran` feature is
+    /// enabled, in which case the rest describes the library.
+    pub fortran: bool,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.implementation)?;
+        if let Some(ref version) = self.version {
+            write!(formatter, " {}", version)?;
+        }
+        if self.fortran {
+            write!(formatter, " via Fortran")?;
+        }
+        write!(formatter, " ({}", self.integer)?;
+        if let Some(threading) = self.threading {
+            write!(formatter, ", {}", threading)?;
+        }
+        let symbols = [
+            (self.symbols.batch, "batch"),
+            (self.symbols.gemmt, "gemmt"),
+            (self.symbols.axpby, "axpby"),
+            (self.symbols.omatcopy, "omatcopy"),
+        ];
+        let mut separator = ", with ";
+        for &(present, name) in symbols.iter() {
+            if present {
+                write!(formatter, "{}{}", separator, name)?;
+                separator = ", ";
+            }
+        }
+        write!(formatter, ")")
+    }
+}
+
+/// Identify the library providing the functions.
+pub fn detect() -> Backend {
+    let mut backend = Backend {
+        implementation: Implementation::Unknown,
+        version: None,
+        integer: if cfg!(feature = "ilp64") {
+            Integer::Ilp64
+        } else {
+            Integer::Lp64
+        },
+        threading: None,
+        symbols: Symbols::detect(),
+        fortran: cfg!(feature = "fortran"),
+    };
+    if cfg!(feature = "pure-rust") || cfg!(feature = "vendored") {
+        backend.implementation = if cfg!(feature = "pure-rust") {
+            Implementation::PureRust
+        } else {
+            Implementation::Vendored
+        };
+        backend.version = Some(env!("CARGO_PKG_VERSION").to_string());
+        backend.threading = Some(Threading::Sequential);
+    } else if !unsafe { probe(&mut backend) } {
+        backend.implementation = if cfg!(feature = "openblas") {
+            Implementation::OpenBlas
+        } else if cfg!(feature = "mkl") {
+            Implementation::Mkl
+        } else if cfg!(feature = "blis") {
+            Implementation::Blis
+        } else if cfg!(feature = "netlib") {
+            Implementation::Netlib
+        } else {
+            Implementation::Unknown
+        };
+    }
+    backend
+}
+
+/// Identify OpenBLAS, which the `openblas` feature links, by its service
+/// functions.
+#[cfg(feature = "openblas")]
+unsafe fn probe(backend: &mut Backend) -> bool {
+    describe_openblas(
+        backend,
+        &::openblas::config(),
+        Some(::openblas::openblas_get_parallel()),
+    );
+    true
+}
+
+/// Identify MKL, which the `mkl` feature links, by its service functions.
+#[cfg(feature = "mkl")]
+unsafe fn probe(backend: &mut Backend) -> bool {
+    describe_mkl(backend, &::mkl::version_string());
+    true
+}
+
+/// Identify the library by its service functions, returning whether it was
+/// found.
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+unsafe fn probe(backend: &mut Backend) -> bool {
+    type GetString = unsafe extern "C" fn() -> *const c_char;
+    type GetInt = unsafe extern "C" fn() -> c_int;
+    // The `bool_t` of BLIS is a `bool` or, in older versions, a `gint_t`, of
+    // which either way the lowest byte is read.
+    type GetBool = unsafe extern "C" fn() -> u8;
+
+    if let Some(get_config) = function::<GetString>(symbol!(openblas_get_config)) {
+        let parallel = function::<GetInt>(symbol!(openblas_get_parallel));
+        describe_openblas(
+            backend,
+            &string(get_config()),
+            parallel.map(|get_parallel| get_parallel()),
+        );
+        return true;
+    }
+
+    type GetVersionString = unsafe extern "C" fn(*mut c_char, c_int);
+    if let Some(get_version_string) = function::<GetVersionString>("MKL_Get_Version_String\0") {
+        let mut buffer = [0 as c_char; 256];
+        get_version_string(buffer.as_mut_ptr(), buffer.len() as c_int - 1);
+        describe_mkl(backend, string(buffer.as_ptr()).trim());
+        return true;
+    }
+
+    if let Some(get_version_str) = function::<GetString>("bli_info_get_version_str\0") {
+        backend.implementation = Implementation::Blis;
+        backend.version = Some(string(get_version_str()));
+        // The size is a `gint_t`, of which the lower 32 bits are read.
+        if let Some(get_size) = function::<GetInt>("bli_info_get_blas_int_type_size\0") {
+            match get_size() {
+                32 => backend.integer = Integer::Lp64,
+                64 => backend.integer = Integer::Ilp64,
+                _ => {}
+            }
+        }
+        let openmp = function::<GetBool>("bli_info_get_enable_openmp\0");
+        let pthreads = function::<GetBool>("bli_info_get_enable_pthreads\0");
+        if let (Some(openmp), Some(pthreads)) = (openmp, pthreads) {
+            backend.threading = Some(if openmp() != 0 {
+                Threading::OpenMp
+            } else if pthreads() != 0 {
+                Threading::Threads
+            } else {
+                Threading::Sequential
+            });
+        }
+        return true;
+    }
+
+    // The reference CBLAS keeps the layout of the current call in a global.
+    if lookup("RowMajorStrg\0") != 0 {
+        backend.implementation = Implementation::Netlib;
+        backend.threading = Some(Threading::Sequential);
+        return true;
+    }
+
+    false
+}
+
+/// Describe OpenBLAS given its configuration and threading model, if known.
+#[cfg(not(feature = "mkl"))]
+fn describe_openblas(backend: &mut Backend, config: &str, parallel: Option<c_int>) {
+    // The configuration starts with the version, e.g.,
+    // `OpenBLAS 0.3.26 USE64BITINT DYNAMIC_ARCH Haswell MAX_THREADS=64`.
+    let mut options = config.split_whitespace();
+    backend.implementation = Implementation::OpenBlas;
+    if options.next() == Some("OpenBLAS") {
+        backend.version = options.next().map(String::from);
+    }
+    backend.integer = if options.any(|option| option == "USE64BITINT") {
+        Integer::Ilp64
+    } else {
+        Integer::Lp64
+    };
+    backend.threading = match parallel {
+        Some(0) => Some(Threading::Sequential),
+        Some(1) => Some(Threading::Threads),
+        Some(2) => Some(Threading::OpenMp),
+        _ => None,
+    };
+}
+
+/// Describe MKL given its version string.
+#[cfg(not(feature = "openblas"))]
+fn describe_mkl(backend: &mut Backend, string: &str) {
+    // The string contains the version, e.g., `Intel(R) oneAPI Math Kernel
+    // Library Version 2024.0-Product Build 20231011 for ...`.
+    let mut words = string.split_whitespace();
+    backend.implementation = Implementation::Mkl;
+    backend.version = match words.position(|word| word == "Version") {
+        Some(_) => words
+            .next()
+            .and_then(|word| word.split('-').next())
+            .map(String::from),
+        None => Some(string.to_string()),
+    };
+}
+
+/// Look up a function among the loaded libraries, given the type of a pointer
+/// to it.
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+unsafe fn function<F: Copy>(symbol: &str) -> Option<F> {
+    match lookup(symbol) {
+        0 => None,
+        address => Some(mem::transmute_copy::<usize, F>(&address)),
+    }
+}
+
+/// Convert a string owned by the library, replacing invalid UTF-8 sequences.
+#[cfg(not(any(feature = "openblas", feature = "mkl")))]
+unsafe fn string(pointer: *const c_char) -> String {
+    if pointer.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(pointer).to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use super::{Backend, Implementation, Integer, Symbols, Threading};
+
+    fn unknown() -> Backend {
+        Backend {
+            implementation: Implementation::Unknown,
+            version: None,
+            integer: Integer::Lp64,
+            threading: None,
+            symbols: Symbols::default(),
+            fortran: false,
+        }
+    }
+
+    #[cfg(feature = "pure-rust")]
+    #[test]
+    fn detect() {
+        let backend = super::detect();
+        assert_eq!(backend.implementation, Implementation::PureRust);
+        assert_eq!(
+            backend.version.as_ref().map(|version| &version[..]),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(backend.threading, Some(Threading::Sequential));
+        let integer = if cfg!(feature = "ilp64") {
+            Integer::Ilp64
+        } else {
+            Integer::Lp64
+        };
+        assert_eq!(backend.integer, integer);
+        assert!(!backend.fortran);
+    }
+
+    #[cfg(feature = "fortran")]
+    #[test]
+    fn fortran() {
+        let backend = super::detect();
+        assert!(backend.fortran);
+        assert!(backend.to_string().contains(" via Fortran ("));
+    }
+
+    #[test]
+    fn display() {
+        let mut backend = unknown();
+        assert_eq!(backend.to_string(), "unknown (LP64)");
+        backend.implementation = Implementation::OpenBlas;
+        backend.version = Some("0.3.26".to_string());
+        backend.threading = Some(Threading::OpenMp);
+        backend.symbols.batch = true;
+        backend.symbols.gemmt = true;
+        assert_eq!(
+            backend.to_string(),
+            "OpenBLAS 0.3.26 (LP64, OpenMP, with batch, gemmt)"
+        );
+        backend.integer = Integer::Ilp64;
+        backend.threading = None;
+        backend.symbols = Symbols::default();
+        backend.symbols.omatcopy = true;
+        assert_eq!(
+            backend.to_string(),
+            "OpenBLAS 0.3.26 (ILP64, with omatcopy)"
+        );
+        backend.fortran = true;
+        assert_eq!(
+            backend.to_string(),
+            "OpenBLAS 0.3.26 via Fortran (ILP64, with omatcopy)"
+        );
+    }
+
+    #[cfg(not(feature = "mkl"))]
+    #[test]
+    fn openblas() {
+        use super::describe_openblas;
+
+        let mut backend = unknown();
+        let config = "OpenBLAS 0.3.26 USE64BITINT DYNAMIC_ARCH Haswell MAX_THREADS=64";
+        describe_openblas(&mut backend, config, Some(1));
+        assert_eq!(backend.implementation, Implementation::OpenBlas);
+        assert_eq!(backend.version, Some("0.3.26".to_string()));
+        assert_eq!(backend.integer, Integer::Ilp64);
+        assert_eq!(backend.threading, Some(Threading::Threads));
+        describe_openblas(&mut backend, "OpenBLAS 0.3.21 Zen MAX_THREADS=8", None);
+        assert_eq!(backend.version, Some("0.3.21".to_string()));
+        assert_eq!(backend.integer, Integer::Lp64);
+        assert_eq!(backend.threading, None);
+    }
+
+    #[cfg(not(feature = "openblas"))]
+    #[test]
+    fn mkl() {
+        use super::describe_mkl;
+
+        let mut backend = unknown();
+        let string = "Intel(R) oneAPI Math Kernel Library Version 2024.0-Product Build 20231011 \
+                      for Intel(R) 64 architecture applications";
+        describe_mkl(&mut backend, string);
+        assert_eq!(backend.implementation, Implementation::Mkl);
+        assert_eq!(backend.version, Some("2024.0".to_string()));
+        describe_mkl(&mut backend, "Intel(R) Math Kernel Library");
+        assert_eq!(
+            backend.version,
+            Some("Intel(R) Math Kernel Library".to_string())
+        );
+    }
+}
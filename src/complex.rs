@@ -0,0 +1,594 @@
+//! Interoperability with [`num_complex`].
+//!
+//! `Complex<T>` is `#[repr(C)]` with the real part followed by the imaginary
+//! one, which is the layout of `[T; 2]`, so that slices can be cast in both
+//! directions without copying. Moreover, [`BlasScalar`] is implemented for
+//! `Complex32` and `Complex64`, which allows for calling the safe wrappers
+//! with complex numbers directly. The wrappers that only exist for complex
+//! numbers, i.e., the unconjugated and conjugated dot products and the
+//! routines for Hermitian matrices, are provided as functions taking complex
+//! numbers, e.g., [`zhemv`](fn.zhemv.html).
+//!
+//! ```
+//! # extern crate cblas_sys;
+//! # extern crate num_complex;
+//! # fn main() {
+//! use cblas_sys::complex::as_arrays;
+//! use cblas_sys::safe::BlasScalar;
+//! use num_complex::Complex64;
+//!
+//! let x = [Complex64::new(1.0, 2.0), Complex64::new(3.0, 4.0)];
+//! assert_eq!(as_arrays(&x), &[[1.0, 2.0], [3.0, 4.0]]);
+//!
+//! # #[cfg(any(feature = "pure-rust", feature = "vendored"))]
+//! assert_eq!(Complex64::dotc(2, &x, 1, &x, 1), Ok(Complex64::new(30.0, 0.0)));
+//! # }
+//! ```
+//!
+//! [`num_complex`]: https://docs.rs/num-complex
+//! [`BlasScalar`]: ../safe/trait.BlasScalar.html
+
+#![allow(clippy::too_many_arguments)]
+
+use core::{mem, slice};
+
+use libc::{c_double, c_float};
+use num_complex::{Complex, Complex32, Complex64};
+
+#[cfg(feature = "extensions")]
+use safe::batch::{Axpy, Gemm, Gemv, Trsm};
+use safe::{level1, level2, level3, BlasScalar, CblasError};
+#[cfg(feature = "extensions")]
+use std::vec::Vec;
+use {c_double_complex, c_float_complex};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+const _: () = assert!(mem::size_of::<Complex32>() == mem::size_of::<c_float_complex>());
+const _: () = assert!(mem::align_of::<Complex32>() == mem::align_of::<c_float_complex>());
+const _: () = assert!(mem::size_of::<Complex64>() == mem::size_of::<c_double_complex>());
+const _: () = assert!(mem::align_of::<Complex64>() == mem::align_of::<c_double_complex>());
+
+/// Convert a complex number into an array.
+#[inline]
+pub fn to_array<T>(z: Complex<T>) -> [T; 2] {
+    [z.re, z.im]
+}
+
+/// Convert an array into a complex number.
+#[inline]
+pub fn from_array<T>(z: [T; 2]) -> Complex<T> {
+    let [re, im] = z;
+    Complex::new(re, im)
+}
+
+/// Cast a slice of complex numbers into a slice of arrays.
+#[inline]
+pub fn as_arrays<T>(x: &[Complex<T>]) -> &[[T; 2]] {
+    unsafe { slice::from_raw_parts(x.as_ptr() as *const [T; 2], x.len()) }
+}
+
+/// Cast a mutable slice of complex numbers into a mutable slice of arrays.
+#[inline]
+pub fn as_arrays_mut<T>(x: &mut [Complex<T>]) -> &mut [[T; 2]] {
+    unsafe { slice::from_raw_parts_mut(x.as_mut_ptr() as *mut [T; 2], x.len()) }
+}
+
+/// Cast a slice of arrays into a slice of complex numbers.
+#[inline]
+pub fn as_complex<T>(x: &[[T; 2]]) -> &[Complex<T>] {
+    unsafe { slice::from_raw_parts(x.as_ptr() as *const Complex<T>, x.len()) }
+}
+
+/// Cast a mutable slice of arrays into a mutable slice of complex numbers.
+#[inline]
+pub fn as_complex_mut<T>(x: &mut [[T; 2]]) -> &mut [Complex<T>] {
+    unsafe { slice::from_raw_parts_mut(x.as_mut_ptr() as *mut Complex<T>, x.len()) }
+}
+
+macro_rules! implement {
+    ($t:ty, $array:ty, $real:ty) => {
+        impl BlasScalar for $t {
+            type Real = $real;
+
+            #[inline]
+            fn dot(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+            ) -> Result<Self, CblasError> {
+                <$array>::dot(n, as_arrays(x), incx, as_arrays(y), incy).map(from_array)
+            }
+
+            #[inline]
+            fn dotc(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+            ) -> Result<Self, CblasError> {
+                <$array>::dotc(n, as_arrays(x), incx, as_arrays(y), incy).map(from_array)
+            }
+
+            #[inline]
+            fn nrm2(n: usize, x: &[Self], incx: isize) -> Result<$real, CblasError> {
+                <$array>::nrm2(n, as_arrays(x), incx)
+            }
+
+            #[inline]
+            fn asum(n: usize, x: &[Self], incx: isize) -> Result<$real, CblasError> {
+                <$array>::asum(n, as_arrays(x), incx)
+            }
+
+            #[inline]
+            fn iamax(n: usize, x: &[Self], incx: isize) -> Result<usize, CblasError> {
+                <$array>::iamax(n, as_arrays(x), incx)
+            }
+
+            #[inline]
+            fn swap(
+                n: usize,
+                x: &mut [Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                <$array>::swap(n, as_arrays_mut(x), incx, as_arrays_mut(y), incy)
+            }
+
+            #[inline]
+            fn copy(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                <$array>::copy(n, as_arrays(x), incx, as_arrays_mut(y), incy)
+            }
+
+            #[inline]
+            fn axpy(
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                let (x, y) = (as_arrays(x), as_arrays_mut(y));
+                <$array>::axpy(n, to_array(alpha), x, incx, y, incy)
+            }
+
+            #[inline]
+            fn scal(n: usize, alpha: Self, x: &mut [Self], incx: isize) -> Result<(), CblasError> {
+                <$array>::scal(n, to_array(alpha), as_arrays_mut(x), incx)
+            }
+
+            #[inline]
+            fn gemv(
+                layout: CBLAS_LAYOUT,
+                trans: CBLAS_TRANSPOSE,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                x: &[Self],
+                incx: isize,
+                beta: Self,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                let (alpha, beta) = (to_array(alpha), to_array(beta));
+                let (a, x, y) = (as_arrays(a), as_arrays(x), as_arrays_mut(y));
+                <$array>::gemv(layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+            }
+
+            #[inline]
+            fn ger(
+                layout: CBLAS_LAYOUT,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+                a: &mut [Self],
+                lda: usize,
+            ) -> Result<(), CblasError> {
+                let (x, y, a) = (as_arrays(x), as_arrays(y), as_arrays_mut(a));
+                <$array>::ger(layout, m, n, to_array(alpha), x, incx, y, incy, a, lda)
+            }
+
+            #[inline]
+            fn trmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: usize,
+                a: &[Self],
+                lda: usize,
+                x: &mut [Self],
+                incx: isize,
+            ) -> Result<(), CblasError> {
+                let (a, x) = (as_arrays(a), as_arrays_mut(x));
+                <$array>::trmv(layout, uplo, trans, diag, n, a, lda, x, incx)
+            }
+
+            #[inline]
+            fn trsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: usize,
+                a: &[Self],
+                lda: usize,
+                x: &mut [Self],
+                incx: isize,
+            ) -> Result<(), CblasError> {
+                let (a, x) = (as_arrays(a), as_arrays_mut(x));
+                <$array>::trsv(layout, uplo, trans, diag, n, a, lda, x, incx)
+            }
+
+            #[inline]
+            fn gemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: usize,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                let (alpha, beta) = (to_array(alpha), to_array(beta));
+                let (a, b, c) = (as_arrays(a), as_arrays(b), as_arrays_mut(c));
+                <$array>::gemm(
+                    layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+                )
+            }
+
+            #[inline]
+            fn symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                let (alpha, beta) = (to_array(alpha), to_array(beta));
+                let (a, b, c) = (as_arrays(a), as_arrays(b), as_arrays_mut(c));
+                <$array>::symm(
+                    layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+                )
+            }
+
+            #[inline]
+            fn syrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                let (alpha, beta) = (to_array(alpha), to_array(beta));
+                let (a, c) = (as_arrays(a), as_arrays_mut(c));
+                <$array>::syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+            }
+
+            #[inline]
+            fn trmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) -> Result<(), CblasError> {
+                let (alpha, a, b) = (to_array(alpha), as_arrays(a), as_arrays_mut(b));
+                <$array>::trmm(
+                    layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                )
+            }
+
+            #[inline]
+            fn trsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) -> Result<(), CblasError> {
+                let (alpha, a, b) = (to_array(alpha), as_arrays(a), as_arrays_mut(b));
+                <$array>::trsm(
+                    layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                )
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn axpy_batch(problems: &mut [Axpy<Self>]) -> Result<(), CblasError> {
+                let mut problems = problems
+                    .iter_mut()
+                    .map(|problem| Axpy {
+                        params: problem.params.map(to_array),
+                        x: as_arrays(problem.x),
+                        y: as_arrays_mut(problem.y),
+                    })
+                    .collect::<Vec<_>>();
+                <$array>::axpy_batch(&mut problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn gemv_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Gemv<Self>],
+            ) -> Result<(), CblasError> {
+                let mut problems = problems
+                    .iter_mut()
+                    .map(|problem| Gemv {
+                        params: problem.params.map(to_array),
+                        a: as_arrays(problem.a),
+                        x: as_arrays(problem.x),
+                        y: as_arrays_mut(problem.y),
+                    })
+                    .collect::<Vec<_>>();
+                <$array>::gemv_batch(layout, &mut problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn gemm_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Gemm<Self>],
+            ) -> Result<(), CblasError> {
+                let mut problems = problems
+                    .iter_mut()
+                    .map(|problem| Gemm {
+                        params: problem.params.map(to_array),
+                        a: as_arrays(problem.a),
+                        b: as_arrays(problem.b),
+                        c: as_arrays_mut(problem.c),
+                    })
+                    .collect::<Vec<_>>();
+                <$array>::gemm_batch(layout, &mut problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn trsm_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Trsm<Self>],
+            ) -> Result<(), CblasError> {
+                let mut problems = problems
+                    .iter_mut()
+                    .map(|problem| Trsm {
+                        params: problem.params.map(to_array),
+                        a: as_arrays(problem.a),
+                        b: as_arrays_mut(problem.b),
+                    })
+                    .collect::<Vec<_>>();
+                <$array>::trsm_batch(layout, &mut problems)
+            }
+        }
+    };
+}
+
+implement!(Complex32, c_float_complex, c_float);
+implement!(Complex64, c_double_complex, c_double);
+
+macro_rules! hermitian {
+    (
+        $t:ty, $real:ty,
+        $dotu:ident => $dotu_value:ident,
+        $dotc:ident => $dotc_value:ident,
+        $hemv:ident, $her:ident, $her2:ident,
+        $hemm:ident, $herk:ident, $her2k:ident,
+    ) => {
+        /// Compute the dot product of x and y.
+        #[inline]
+        pub fn $dotu(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+        ) -> Result<$t, CblasError> {
+            level1::$dotu_value(n, as_arrays(x), incx, as_arrays(y), incy).map(from_array)
+        }
+
+        /// Compute the dot product of the conjugate of x and y.
+        #[inline]
+        pub fn $dotc(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+        ) -> Result<$t, CblasError> {
+            level1::$dotc_value(n, as_arrays(x), incx, as_arrays(y), incy).map(from_array)
+        }
+
+        /// Compute y := alpha * A * x + beta * y for a Hermitian A of order n.
+        #[inline]
+        pub fn $hemv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let (alpha, beta) = (to_array(alpha), to_array(beta));
+            let (a, x, y) = (as_arrays(a), as_arrays(x), as_arrays_mut(y));
+            level2::$hemv(layout, uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+
+        /// Compute A := alpha * x * x' + A for a Hermitian A of order n.
+        #[inline]
+        pub fn $her(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $real,
+            x: &[$t],
+            incx: isize,
+            a: &mut [$t],
+            lda: usize,
+        ) -> Result<(), CblasError> {
+            let (x, a) = (as_arrays(x), as_arrays_mut(a));
+            level2::$her(layout, uplo, n, alpha, x, incx, a, lda)
+        }
+
+        /// Compute A := alpha * x * y' + alpha' * y * x' + A for a Hermitian A
+        /// of order n.
+        #[inline]
+        pub fn $her2(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            a: &mut [$t],
+            lda: usize,
+        ) -> Result<(), CblasError> {
+            let (x, y, a) = (as_arrays(x), as_arrays(y), as_arrays_mut(a));
+            level2::$her2(layout, uplo, n, to_array(alpha), x, incx, y, incy, a, lda)
+        }
+
+        /// Compute C := alpha * A * B + beta * C or
+        /// C := alpha * B * A + beta * C, depending on `side`, for a Hermitian
+        /// A and m-by-n B and C.
+        #[inline]
+        pub fn $hemm(
+            layout: CBLAS_LAYOUT,
+            side: CBLAS_SIDE,
+            uplo: CBLAS_UPLO,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &[$t],
+            ldb: usize,
+            beta: $t,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            let (alpha, beta) = (to_array(alpha), to_array(beta));
+            let (a, b, c) = (as_arrays(a), as_arrays(b), as_arrays_mut(c));
+            level3::$hemm(
+                layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+            )
+        }
+
+        /// Compute C := alpha * op(A) * op(A)' + beta * C for an n-by-k op(A)
+        /// and a Hermitian C of order n.
+        #[inline]
+        pub fn $herk(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            n: usize,
+            k: usize,
+            alpha: $real,
+            a: &[$t],
+            lda: usize,
+            beta: $real,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            let (a, c) = (as_arrays(a), as_arrays_mut(c));
+            level3::$herk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        }
+
+        /// Compute C := alpha * op(A) * op(B)' + alpha' * op(B) * op(A)' +
+        /// beta * C for n-by-k op(A) and op(B) and a Hermitian C of order n.
+        #[inline]
+        pub fn $her2k(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            n: usize,
+            k: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &[$t],
+            ldb: usize,
+            beta: $real,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            let alpha = to_array(alpha);
+            let (a, b, c) = (as_arrays(a), as_arrays(b), as_arrays_mut(c));
+            level3::$her2k(
+                layout, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+            )
+        }
+    };
+}
+
+hermitian!(
+    Complex32, c_float,
+    cdotu => cdotu_value,
+    cdotc => cdotc_value,
+    chemv, cher, cher2,
+    chemm, cherk, cher2k,
+);
+
+hermitian!(
+    Complex64, c_double,
+    zdotu => zdotu_value,
+    zdotc => zdotc_value,
+    zhemv, zher, zher2,
+    zhemm, zherk, zher2k,
+);
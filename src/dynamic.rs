@@ -0,0 +1,131 @@
+//! Loading of a CBLAS library at run time.
+
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use libloading::{Library, Symbol};
+use std::ffi::OsStr;
+use std::vec::Vec;
+
+use libc::{c_char, c_double, c_float};
+
+use {blasint, c_double_complex, c_float_complex, CblasApi, CBLAS_INDEX};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+macro_rules! dynamic_cblas {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $ty:ty),* $(,)*) $(-> $ret:ty)*;
+    )*) => {
+        struct Functions {
+            $($name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)*>,)*
+            cblas_xerbla: Option<unsafe extern "C" fn(blasint, *const c_char, *const c_char, ...)>,
+        }
+
+        impl Functions {
+            unsafe fn load(library: &Library, missing: &mut Vec<&'static str>) -> Functions {
+                Functions {
+                    $($name: resolve(library, symbol!($name), missing),)*
+                    cblas_xerbla: resolve(library, symbol!(cblas_xerbla), missing),
+                }
+            }
+        }
+
+        impl CblasLibrary {
+            $(
+                $(#[$attr])*
+                #[inline]
+                pub unsafe fn $name(&self, $($arg: $ty),*) $(-> $ret)* {
+                    match self.functions.$name {
+                        Some(function) => function($($arg),*),
+                        None => unavailable(symbol!($name)),
+                    }
+                }
+            )*
+
+            /// Create a table of the functions if the library provides all of
+            /// them.
+            ///
+            /// The pointers are valid as long as the library is loaded.
+            pub fn api(&self) -> Option<CblasApi> {
+                Some(CblasApi {
+                    $($name: self.functions.$name?,)*
+                    cblas_xerbla: self.functions.cblas_xerbla?,
+                })
+            }
+        }
+    };
+}
+
+/// A CBLAS library loaded at run time.
+///
+/// The functions have the same signatures as the bindings but are called as
+/// methods, and calling a function that the library does not provide panics.
+/// The symbols are looked up with the suffix selected via the `suffix-*`
+/// features, if any.
+pub struct CblasLibrary {
+    functions: Functions,
+    missing: Vec<&'static str>,
+    _library: Library,
+}
+
+cblas_functions!(dynamic_cblas);
+
+impl CblasLibrary {
+    /// Load a library and resolve the functions that it provides.
+    ///
+    /// The path is passed to the dynamic linker, which searches the standard
+    /// locations if it does not contain a separator.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization routines, and the library
+    /// has to provide the functions with the signatures declared by the
+    /// package.
+    pub unsafe fn open<P: AsRef<OsStr>>(path: P) -> Result<CblasLibrary, libloading::Error> {
+        let library = Library::new(path)?;
+        let mut missing = Vec::new();
+        let functions = Functions::load(&library, &mut missing);
+        Ok(CblasLibrary {
+            functions,
+            missing,
+            _library: library,
+        })
+    }
+
+    /// Return the symbols that the library does not provide.
+    pub fn missing(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    /// Call `cblas_xerbla` without variadic arguments.
+    #[inline]
+    pub unsafe fn cblas_xerbla(&self, p: blasint, rout: *const c_char, form: *const c_char) {
+        match self.functions.cblas_xerbla {
+            Some(function) => function(p, rout, form),
+            None => unavailable(symbol!(cblas_xerbla)),
+        }
+    }
+}
+
+unsafe fn resolve<T: Copy>(
+    library: &Library,
+    symbol: &'static str,
+    missing: &mut Vec<&'static str>,
+) -> Option<T> {
+    let result: Result<Symbol<T>, _> = library.get(symbol.as_bytes());
+    match result {
+        Ok(function) => Some(*function),
+        Err(_) => {
+            missing.push(&symbol[..symbol.len() - 1]);
+            None
+        }
+    }
+}
+
+#[cold]
+fn unavailable(symbol: &str) -> ! {
+    panic!(
+        "the library does not provide {}",
+        &symbol[..symbol.len() - 1]
+    )
+}
@@ -0,0 +1,194 @@
+//! Conversions of the enumerations.
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use libc::c_int;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use {CblasColMajor, CblasConjTrans, CblasLeft, CblasLower, CblasNoTrans, CblasNonUnit};
+use {CblasRight, CblasRowMajor, CblasTrans, CblasUnit, CblasUpper};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// An error converting a value that does not correspond to any variant of an
+/// enumeration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidEnumValue {
+    /// The name of the enumeration, e.g., `CBLAS_UPLO`.
+    pub name: &'static str,
+}
+
+impl fmt::Display for InvalidEnumValue {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "invalid value for `{}`", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for InvalidEnumValue {}
+
+// Implements the conversions of an enumeration given the character code used
+// by Fortran BLAS and the name used by `Display`, `FromStr`, and serde for
+// every variant.
+macro_rules! enumeration {
+    ($name:ident { $($variant:ident: $code:expr, $string:expr;)* }) => {
+        impl TryFrom<c_int> for $name {
+            type Error = InvalidEnumValue;
+
+            /// Convert a value passed by a C caller.
+            fn try_from(value: c_int) -> Result<Self, Self::Error> {
+                $(
+                    if value == $variant as c_int {
+                        return Ok($variant);
+                    }
+                )*
+                Err(InvalidEnumValue { name: stringify!($name) })
+            }
+        }
+
+        impl From<$name> for c_int {
+            #[inline]
+            fn from(value: $name) -> c_int {
+                value as c_int
+            }
+        }
+
+        impl TryFrom<char> for $name {
+            type Error = InvalidEnumValue;
+
+            /// Convert a character code, which is case-insensitive as in
+            /// Fortran BLAS.
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                match value.to_ascii_uppercase() {
+                    $($code => Ok($variant),)*
+                    _ => Err(InvalidEnumValue { name: stringify!($name) }),
+                }
+            }
+        }
+
+        impl From<$name> for char {
+            /// Convert into the uppercase character code.
+            #[inline]
+            fn from(value: $name) -> char {
+                match value {
+                    $($variant => $code,)*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(match *self {
+                    $($variant => $string,)*
+                })
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidEnumValue;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($string => Ok($variant),)*
+                    _ => Err(InvalidEnumValue { name: stringify!($name) }),
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(match *self {
+                    $($variant => $string,)*
+                })
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(concat!("a variant of `", stringify!($name), "`"))
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<$name, E> {
+                        value
+                            .parse()
+                            .map_err(|_| E::unknown_variant(value, &[$($string),*]))
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+    };
+}
+
+enumeration!(CBLAS_LAYOUT {
+    CblasRowMajor: 'R', "RowMajor";
+    CblasColMajor: 'C', "ColMajor";
+});
+
+enumeration!(CBLAS_TRANSPOSE {
+    CblasNoTrans: 'N', "NoTrans";
+    CblasTrans: 'T', "Trans";
+    CblasConjTrans: 'C', "ConjTrans";
+});
+
+enumeration!(CBLAS_UPLO {
+    CblasUpper: 'U', "Upper";
+    CblasLower: 'L', "Lower";
+});
+
+enumeration!(CBLAS_DIAG {
+    CblasNonUnit: 'N', "NonUnit";
+    CblasUnit: 'U', "Unit";
+});
+
+enumeration!(CBLAS_SIDE {
+    CblasLeft: 'L', "Left";
+    CblasRight: 'R', "Right";
+});
+
+impl CBLAS_LAYOUT {
+    /// Return the other layout, in which a matrix stored in this one is
+    /// interpreted as its transpose.
+    #[inline]
+    pub fn transposed(self) -> Self {
+        match self {
+            CblasRowMajor => CblasColMajor,
+            CblasColMajor => CblasRowMajor,
+        }
+    }
+}
+
+impl CBLAS_UPLO {
+    /// Return the other triangle, which is where the stored one is found in
+    /// the transpose.
+    #[inline]
+    pub fn flip(self) -> Self {
+        match self {
+            CblasUpper => CblasLower,
+            CblasLower => CblasUpper,
+        }
+    }
+}
+
+impl CBLAS_SIDE {
+    /// Return the other side.
+    #[inline]
+    pub fn flip(self) -> Self {
+        match self {
+            CblasLeft => CblasRight,
+            CblasRight => CblasLeft,
+        }
+    }
+}
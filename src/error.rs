@@ -0,0 +1,62 @@
+//! The reporting of invalid arguments shared by the functions that the
+//! package defines itself, that is, the implementations of the `pure-rust`
+//! and `fortran` features and the handler of the `xerbla` feature.
+
+// Defines `cblas_xerbla` with the given body, binding the position and the
+// name of the routine to the given identifiers.
+//
+// The function is declared variadic by the C header, but variadic functions
+// cannot be defined in stable Rust, so that the arguments described by the
+// format are omitted and ignored. Callers using the variadic prototype pass
+// the three fixed arguments, which are integers and pointers, as they are
+// passed to a non-variadic function, and the variadic ones after them, which
+// the callee is free to ignore:
+//
+// * on x86 and x86-64, both System V and Windows, the fixed arguments take
+//   the same registers or stack slots, the stack is cleaned up by the caller,
+//   and the number of vector registers passed in `al` on x86-64 System V is
+//   only read by variadic callees;
+// * on ARM, AArch64, PowerPC, RISC-V, and s390x, the fixed arguments take the
+//   same registers, and the variadic ones differ, if at all, only in how
+//   floating-point values are passed or, on Apple AArch64, in being passed on
+//   the stack;
+// * on WebAssembly, the variadic arguments are stored in a buffer whose
+//   address is passed after the fixed arguments, so that this parameter is
+//   declared to match the signature of the call.
+macro_rules! define_xerbla {
+    ($(#[$attr:meta])* |$p:ident, $rout:ident| $body:block) => {
+        export_cblas! {
+            $(#[$attr])*
+            pub unsafe extern "C" fn cblas_xerbla(
+                $p: ::blasint,
+                $rout: *const ::libc::c_char,
+                _form: *const ::libc::c_char,
+                #[cfg(target_family = "wasm")] _arguments: *const ::libc::c_void,
+            ) $body
+        }
+    };
+}
+
+// Converts an integer into an enumeration, reporting an invalid value.
+#[allow(unused_macros)]
+macro_rules! convert {
+    ($routine:expr, $($position:expr => $name:ident: $kind:ident,)*) => {$(
+        let $name = match <::$kind as ::core::convert::TryFrom<::libc::c_int>>::try_from($name) {
+            Ok(value) => value,
+            Err(_) => return ::error::xerbla($position, $routine),
+        };
+    )*};
+}
+
+/// Report an invalid argument of a routine, whose name is null-terminated,
+/// via `cblas_xerbla`.
+#[cfg(any(feature = "pure-rust", feature = "fortran"))]
+pub unsafe fn xerbla(position: ::blasint, routine: &[u8]) {
+    use libc::c_char;
+
+    ::cblas_xerbla(
+        position,
+        routine.as_ptr() as *const c_char,
+        b"\0".as_ptr() as *const c_char,
+    );
+}
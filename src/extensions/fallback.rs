@@ -0,0 +1,574 @@
+//! The implementations used if no library provides an extension.
+
+use std::vec::Vec;
+
+use index::{first, ld_valid, offset, op_size};
+use scalar::Scalar;
+use {blasint, CBLAS_INDEX};
+use {CblasConjTrans, CblasLower, CblasNoTrans};
+use {CblasTrans, CblasUpper};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// The signature of `cblas_?axpy`.
+pub type Axpy<T> =
+    unsafe extern "C" fn(blasint, <T as Scalar>::Arg, *const T, blasint, *mut T, blasint);
+
+/// The signature of `cblas_?gemv`.
+pub type Gemv<T> = unsafe extern "C" fn(
+    CBLAS_LAYOUT,
+    CBLAS_TRANSPOSE,
+    blasint,
+    blasint,
+    <T as Scalar>::Arg,
+    *const T,
+    blasint,
+    *const T,
+    blasint,
+    <T as Scalar>::Arg,
+    *mut T,
+    blasint,
+);
+
+/// The signature of `cblas_?gemm`.
+pub type Gemm<T> = unsafe extern "C" fn(
+    CBLAS_LAYOUT,
+    CBLAS_TRANSPOSE,
+    CBLAS_TRANSPOSE,
+    blasint,
+    blasint,
+    blasint,
+    <T as Scalar>::Arg,
+    *const T,
+    blasint,
+    *const T,
+    blasint,
+    <T as Scalar>::Arg,
+    *mut T,
+    blasint,
+);
+
+/// The signature of `cblas_?trsm`.
+pub type Trsm<T> = unsafe extern "C" fn(
+    CBLAS_LAYOUT,
+    CBLAS_SIDE,
+    CBLAS_UPLO,
+    CBLAS_TRANSPOSE,
+    CBLAS_DIAG,
+    blasint,
+    blasint,
+    <T as Scalar>::Arg,
+    *const T,
+    blasint,
+    *mut T,
+    blasint,
+);
+
+/// Call f for every index below n, in parallel if the `rayon` feature is
+/// enabled.
+#[cfg(feature = "rayon")]
+unsafe fn each<F: Fn(usize)>(n: usize, f: F) {
+    use rayon::prelude::*;
+
+    // The function captures raw pointers, which the caller vouches for.
+    struct Shared<F>(F);
+    unsafe impl<F> Sync for Shared<F> {}
+
+    let f = Shared(f);
+    (0..n).into_par_iter().for_each(|i| (f.0)(i));
+}
+
+/// Call f for every index below n, in parallel if the `rayon` feature is
+/// enabled.
+#[cfg(not(feature = "rayon"))]
+unsafe fn each<F: Fn(usize)>(n: usize, f: F) {
+    (0..n).for_each(f);
+}
+
+/// A pointer to element (i, j) of a matrix.
+#[inline]
+unsafe fn element<T>(
+    layout: CBLAS_LAYOUT,
+    a: *const T,
+    ld: blasint,
+    i: blasint,
+    j: blasint,
+) -> *mut T {
+    a.offset(offset(layout, ld as isize, i as isize, j as isize)) as *mut T
+}
+
+/// Element (i, j) of op(A).
+#[inline]
+unsafe fn get_op<T: Scalar>(
+    layout: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    a: *const T,
+    ld: blasint,
+    i: blasint,
+    j: blasint,
+) -> T {
+    match trans {
+        CblasNoTrans => *element(layout, a, ld, i, j),
+        CblasTrans => *element(layout, a, ld, j, i),
+        CblasConjTrans => (*element(layout, a, ld, j, i)).conj(),
+    }
+}
+
+/// alpha * t + beta * c, where c is not read if beta is zero.
+#[inline]
+unsafe fn update<T: Scalar>(alpha: T, t: T, beta: T, c: *mut T) {
+    *c = if beta.is_zero() {
+        alpha.mul(t)
+    } else {
+        alpha.mul(t).add(beta.mul(*c))
+    };
+}
+
+pub unsafe fn axpby<T: Scalar>(
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+) {
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (x.offset(first(n, incx)), y.offset(first(n, incy)));
+    for i in 0..n as isize {
+        update(
+            alpha,
+            *x.offset(i * incx as isize),
+            beta,
+            y.offset(i * incy as isize),
+        );
+    }
+}
+
+pub unsafe fn omatcopy<T: Scalar>(
+    order: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    rows: blasint,
+    cols: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: *mut T,
+    ldb: blasint,
+) {
+    let (brows, bcols) = op_size(trans, rows, cols);
+    if rows < 0
+        || cols < 0
+        || !ld_valid(order, lda, rows, cols)
+        || !ld_valid(order, ldb, brows, bcols)
+    {
+        return;
+    }
+    for j in 0..bcols {
+        for i in 0..brows {
+            let value = alpha.mul(get_op(order, trans, a, lda, i, j));
+            *element(order, b, ldb, i, j) = value;
+        }
+    }
+}
+
+pub unsafe fn imatcopy<T: Scalar>(
+    order: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    rows: blasint,
+    cols: blasint,
+    alpha: T,
+    a: *mut T,
+    lda: blasint,
+    ldb: blasint,
+) {
+    let (brows, bcols) = op_size(trans, rows, cols);
+    if rows < 0
+        || cols < 0
+        || !ld_valid(order, lda, rows, cols)
+        || !ld_valid(order, ldb, brows, bcols)
+    {
+        return;
+    }
+    // The result is computed into a copy since it overlaps A.
+    let mut b = Vec::with_capacity(brows as usize * bcols as usize);
+    for j in 0..bcols {
+        for i in 0..brows {
+            b.push(alpha.mul(get_op(order, trans, a, lda, i, j)));
+        }
+    }
+    for j in 0..bcols {
+        for i in 0..brows {
+            *element(order, a, ldb, i, j) = b[(i + j * brows) as usize];
+        }
+    }
+}
+
+pub unsafe fn geadd<T: Scalar>(
+    order: CBLAS_LAYOUT,
+    rows: blasint,
+    cols: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    beta: T,
+    c: *mut T,
+    ldc: blasint,
+) {
+    if rows < 0
+        || cols < 0
+        || !ld_valid(order, lda, rows, cols)
+        || !ld_valid(order, ldc, rows, cols)
+    {
+        return;
+    }
+    for j in 0..cols {
+        for i in 0..rows {
+            let value = *element(order, a, lda, i, j);
+            update(alpha, value, beta, element(order, c, ldc, i, j));
+        }
+    }
+}
+
+pub unsafe fn iamin<T: Scalar>(n: blasint, x: *const T, incx: blasint) -> CBLAS_INDEX {
+    if n <= 0 || incx <= 0 {
+        return 0;
+    }
+    let (mut index, mut min) = (0, (*x).abs1());
+    for i in 1..n {
+        let value = (*x.offset(i as isize * incx as isize)).abs1();
+        if value < min {
+            index = i;
+            min = value;
+        }
+    }
+    index
+}
+
+pub unsafe fn gemmt<T: Scalar>(
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    n: blasint,
+    k: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: *const T,
+    ldb: blasint,
+    beta: T,
+    c: *mut T,
+    ldc: blasint,
+) {
+    let (arows, acols) = op_size(transa, n, k);
+    let (brows, bcols) = op_size(transb, k, n);
+    if n < 0
+        || k < 0
+        || !ld_valid(layout, lda, arows, acols)
+        || !ld_valid(layout, ldb, brows, bcols)
+        || !ld_valid(layout, ldc, n, n)
+    {
+        return;
+    }
+    for j in 0..n {
+        let rows = match uplo {
+            CblasUpper => 0..j + 1,
+            CblasLower => j..n,
+        };
+        for i in rows {
+            let mut t = T::zero();
+            if !alpha.is_zero() {
+                for l in 0..k {
+                    let product = get_op(layout, transa, a, lda, i, l)
+                        .mul(get_op(layout, transb, b, ldb, l, j));
+                    t = t.add(product);
+                }
+            }
+            update(alpha, t, beta, element(layout, c, ldc, i, j));
+        }
+    }
+}
+
+/// Call f for every problem of a batch with the index of its group and its
+/// own index, unless a count is negative.
+unsafe fn groups<F: Fn(usize, usize)>(group_count: blasint, group_size: *const blasint, f: F) {
+    if group_count < 0 || (0..group_count as usize).any(|g| *group_size.add(g) < 0) {
+        return;
+    }
+    let mut first = 0;
+    for g in 0..group_count as usize {
+        let size = *group_size.add(g) as usize;
+        each(size, |i| f(g, first + i));
+        first += size;
+    }
+}
+
+/// Call f for every problem of a batch with the offset of its operands in
+/// units of the stride, unless the size is negative.
+unsafe fn strided<F: Fn(isize)>(batch_size: blasint, f: F) {
+    if batch_size >= 0 {
+        each(batch_size as usize, |i| f(i as isize));
+    }
+}
+
+pub unsafe fn axpy_batch<T: Scalar>(
+    axpy: Axpy<T>,
+    n_array: *const blasint,
+    alpha_array: *const T,
+    x_array: *const *const T,
+    incx_array: *const blasint,
+    y_array: *const *mut T,
+    incy_array: *const blasint,
+    group_count: blasint,
+    group_size: *const blasint,
+) {
+    groups(group_count, group_size, |g, i| {
+        axpy(
+            *n_array.add(g),
+            T::arg(&*alpha_array.add(g)),
+            *x_array.add(i),
+            *incx_array.add(g),
+            *y_array.add(i),
+            *incy_array.add(g),
+        )
+    });
+}
+
+pub unsafe fn axpy_batch_strided<T: Scalar>(
+    axpy: Axpy<T>,
+    n: blasint,
+    alpha: T::Arg,
+    x: *const T,
+    incx: blasint,
+    stridex: blasint,
+    y: *mut T,
+    incy: blasint,
+    stridey: blasint,
+    batch_size: blasint,
+) {
+    strided(batch_size, |i| {
+        let (x, y) = (
+            x.offset(i * stridex as isize),
+            y.offset(i * stridey as isize),
+        );
+        axpy(n, alpha, x, incx, y, incy)
+    });
+}
+
+pub unsafe fn gemv_batch<T: Scalar>(
+    gemv: Gemv<T>,
+    layout: CBLAS_LAYOUT,
+    trans_array: *const CBLAS_TRANSPOSE,
+    m_array: *const blasint,
+    n_array: *const blasint,
+    alpha_array: *const T,
+    a_array: *const *const T,
+    lda_array: *const blasint,
+    x_array: *const *const T,
+    incx_array: *const blasint,
+    beta_array: *const T,
+    y_array: *const *mut T,
+    incy_array: *const blasint,
+    group_count: blasint,
+    group_size: *const blasint,
+) {
+    groups(group_count, group_size, |g, i| {
+        gemv(
+            layout,
+            *trans_array.add(g),
+            *m_array.add(g),
+            *n_array.add(g),
+            T::arg(&*alpha_array.add(g)),
+            *a_array.add(i),
+            *lda_array.add(g),
+            *x_array.add(i),
+            *incx_array.add(g),
+            T::arg(&*beta_array.add(g)),
+            *y_array.add(i),
+            *incy_array.add(g),
+        )
+    });
+}
+
+pub unsafe fn gemv_batch_strided<T: Scalar>(
+    gemv: Gemv<T>,
+    layout: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    alpha: T::Arg,
+    a: *const T,
+    lda: blasint,
+    stridea: blasint,
+    x: *const T,
+    incx: blasint,
+    stridex: blasint,
+    beta: T::Arg,
+    y: *mut T,
+    incy: blasint,
+    stridey: blasint,
+    batch_size: blasint,
+) {
+    strided(batch_size, |i| {
+        gemv(
+            layout,
+            trans,
+            m,
+            n,
+            alpha,
+            a.offset(i * stridea as isize),
+            lda,
+            x.offset(i * stridex as isize),
+            incx,
+            beta,
+            y.offset(i * stridey as isize),
+            incy,
+        )
+    });
+}
+
+pub unsafe fn gemm_batch<T: Scalar>(
+    gemm: Gemm<T>,
+    layout: CBLAS_LAYOUT,
+    transa_array: *const CBLAS_TRANSPOSE,
+    transb_array: *const CBLAS_TRANSPOSE,
+    m_array: *const blasint,
+    n_array: *const blasint,
+    k_array: *const blasint,
+    alpha_array: *const T,
+    a_array: *const *const T,
+    lda_array: *const blasint,
+    b_array: *const *const T,
+    ldb_array: *const blasint,
+    beta_array: *const T,
+    c_array: *const *mut T,
+    ldc_array: *const blasint,
+    group_count: blasint,
+    group_size: *const blasint,
+) {
+    groups(group_count, group_size, |g, i| {
+        gemm(
+            layout,
+            *transa_array.add(g),
+            *transb_array.add(g),
+            *m_array.add(g),
+            *n_array.add(g),
+            *k_array.add(g),
+            T::arg(&*alpha_array.add(g)),
+            *a_array.add(i),
+            *lda_array.add(g),
+            *b_array.add(i),
+            *ldb_array.add(g),
+            T::arg(&*beta_array.add(g)),
+            *c_array.add(i),
+            *ldc_array.add(g),
+        )
+    });
+}
+
+pub unsafe fn gemm_batch_strided<T: Scalar>(
+    gemm: Gemm<T>,
+    layout: CBLAS_LAYOUT,
+    transa: CBLAS_TRANSPOSE,
+    transb: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    alpha: T::Arg,
+    a: *const T,
+    lda: blasint,
+    stridea: blasint,
+    b: *const T,
+    ldb: blasint,
+    strideb: blasint,
+    beta: T::Arg,
+    c: *mut T,
+    ldc: blasint,
+    stridec: blasint,
+    batch_size: blasint,
+) {
+    strided(batch_size, |i| {
+        gemm(
+            layout,
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a.offset(i * stridea as isize),
+            lda,
+            b.offset(i * strideb as isize),
+            ldb,
+            beta,
+            c.offset(i * stridec as isize),
+            ldc,
+        )
+    });
+}
+
+pub unsafe fn trsm_batch<T: Scalar>(
+    trsm: Trsm<T>,
+    layout: CBLAS_LAYOUT,
+    side_array: *const CBLAS_SIDE,
+    uplo_array: *const CBLAS_UPLO,
+    transa_array: *const CBLAS_TRANSPOSE,
+    diag_array: *const CBLAS_DIAG,
+    m_array: *const blasint,
+    n_array: *const blasint,
+    alpha_array: *const T,
+    a_array: *const *const T,
+    lda_array: *const blasint,
+    b_array: *const *mut T,
+    ldb_array: *const blasint,
+    group_count: blasint,
+    group_size: *const blasint,
+) {
+    groups(group_count, group_size, |g, i| {
+        trsm(
+            layout,
+            *side_array.add(g),
+            *uplo_array.add(g),
+            *transa_array.add(g),
+            *diag_array.add(g),
+            *m_array.add(g),
+            *n_array.add(g),
+            T::arg(&*alpha_array.add(g)),
+            *a_array.add(i),
+            *lda_array.add(g),
+            *b_array.add(i),
+            *ldb_array.add(g),
+        )
+    });
+}
+
+pub unsafe fn trsm_batch_strided<T: Scalar>(
+    trsm: Trsm<T>,
+    layout: CBLAS_LAYOUT,
+    side: CBLAS_SIDE,
+    uplo: CBLAS_UPLO,
+    transa: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    m: blasint,
+    n: blasint,
+    alpha: T::Arg,
+    a: *const T,
+    lda: blasint,
+    stridea: blasint,
+    b: *mut T,
+    ldb: blasint,
+    strideb: blasint,
+    batch_size: blasint,
+) {
+    strided(batch_size, |i| {
+        let (a, b) = (
+            a.offset(i * stridea as isize),
+            b.offset(i * strideb as isize),
+        );
+        trsm(
+            layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+        )
+    });
+}
@@ -0,0 +1,581 @@
+//! Extensions of CBLAS provided by some libraries.
+//!
+//! The functions are not part of the standard but are provided with the same
+//! signatures by OpenBLAS and, in part, by MKL and BLIS:
+//!
+//! - `cblas_?axpby` computes y := alpha * x + beta * y,
+//! - `cblas_?omatcopy` computes B := alpha * op(A) out of place,
+//! - `cblas_?imatcopy` computes A := alpha * op(A) in place, changing the
+//!   leading dimension from `lda` to `ldb`,
+//! - `cblas_?geadd` computes C := alpha * A + beta * C,
+//! - `cblas_i?amin` returns the index of the element with the smallest
+//!   absolute value, as `cblas_i?amax` does for the largest,
+//! - `cblas_?gemmt` computes the upper or lower triangle of
+//!   C := alpha * op(A) * op(B) + beta * C, and
+//! - `cblas_?axpy_batch`, `cblas_?gemv_batch`, `cblas_?gemm_batch`, and
+//!   `cblas_?trsm_batch` call the corresponding routine for every problem of
+//!   groups of problems that share the arguments other than the vectors and
+//!   matrices, whereas the `_strided` variants do the same for one group whose
+//!   vectors and matrices are a fixed number of elements apart.
+//!
+//! Moreover, the reference implementation provides the following functions
+//! since version 3.10, which older versions and other libraries may lack:
+//!
+//! - `cblas_crotg` and `cblas_zrotg` construct a Givens rotation for complex
+//!   numbers, replacing a with r, and
+//! - `cblas_csrot` and `cblas_zdrot` apply a Givens rotation with a real
+//!   cosine and sine to complex vectors.
+//!
+//! Finally, `cblas_cdotu`, `cblas_cdotc`, `cblas_zdotu`, and `cblas_zdotc`
+//! return the dot products that the `_sub` variants store via a pointer. Some
+//! libraries, e.g., OpenBLAS, provide them and return a C structure or a C99
+//! complex number by value, which are passed in the same way. Others follow
+//! other conventions, e.g., that of f2c, which passes a pointer to the result
+//! as a hidden first argument, and calling a function with the wrong one is
+//! undefined behavior. A function is hence only called if it belongs to the
+//! same library as `openblas_get_config`, and the `_sub` variant is called
+//! instead otherwise.
+//!
+//! Every function is looked up among the loaded libraries the first time it
+//! is called, taking into account the suffix selected via the `suffix-*`
+//! features, if any. If the symbol is not found, e.g., when the provider is
+//! linked statically, does not support the extension, or is the pure-Rust or
+//! vendored implementation, the function is computed by a straightforward
+//! implementation written in Rust instead, which does nothing if an argument
+//! is invalid. The batched fallbacks call the unbatched routine for every
+//! problem, in parallel if the `rayon` feature is enabled, in which case the
+//! outputs of different problems must not overlap. The lookup is only
+//! supported on Unix, and the fallback is always used on other platforms.
+
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use core::mem;
+
+use libc::{c_double, c_float};
+
+use lookup::{lookup, same_library, Function};
+use scalar::Scalar;
+use {blasint, c_double_complex, c_float_complex, CBLAS_INDEX};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+mod fallback;
+
+// Defines functions calling the symbol of the same name if a library provides
+// it and evaluating the given fallback otherwise.
+macro_rules! extensions {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $ty:ty),* $(,)*) $(-> $ret:ty)* => $fallback:expr;
+    )*) => {$(
+        $(#[$attr])*
+        #[inline]
+        pub unsafe fn $name($($arg: $ty),*) $(-> $ret)* {
+            static FUNCTION: Function = Function::new(symbol!($name));
+            match FUNCTION.address() {
+                Some(address) => {
+                    let function: unsafe extern "C" fn($($ty),*) $(-> $ret)* =
+                        mem::transmute(address);
+                    function($($arg),*)
+                }
+                None => $fallback,
+            }
+        }
+    )*};
+}
+
+macro_rules! standard {
+    (
+        $t:ty,
+        $axpby:ident,
+        $omatcopy:ident,
+        $imatcopy:ident,
+        $geadd:ident,
+        $iamin:ident,
+        $gemmt:ident,
+    ) => {
+        extensions! {
+            pub fn $axpby(
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) => fallback::axpby(n, <$t>::load(alpha), x, incx, <$t>::load(beta), y, incy);
+
+            pub fn $omatcopy(
+                order: CBLAS_LAYOUT,
+                trans: CBLAS_TRANSPOSE,
+                rows: blasint,
+                cols: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *mut $t,
+                ldb: blasint,
+            ) => fallback::omatcopy(order, trans, rows, cols, <$t>::load(alpha), a, lda, b, ldb);
+
+            pub fn $imatcopy(
+                order: CBLAS_LAYOUT,
+                trans: CBLAS_TRANSPOSE,
+                rows: blasint,
+                cols: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *mut $t,
+                lda: blasint,
+                ldb: blasint,
+            ) => fallback::imatcopy(order, trans, rows, cols, <$t>::load(alpha), a, lda, ldb);
+
+            pub fn $geadd(
+                order: CBLAS_LAYOUT,
+                rows: blasint,
+                cols: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) => {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                fallback::geadd(order, rows, cols, alpha, a, lda, beta, c, ldc)
+            };
+
+            pub fn $iamin(n: blasint, x: *const $t, incx: blasint) -> CBLAS_INDEX => {
+                fallback::iamin(n, x, incx)
+            };
+
+            pub fn $gemmt(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) => {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                fallback::gemmt(
+                    layout, uplo, transa, transb, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+                )
+            };
+        }
+    };
+}
+
+macro_rules! complex {
+    ($t:ty, $real:ty, $rotg:ident, $rot:ident,) => {
+        extensions! {
+            pub fn $rotg(a: *mut $t, b: *mut $t, c: *mut $real, s: *mut $t) => {
+                ::rotation::rotg(a, b, c, s)
+            };
+
+            pub fn $rot(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                c: $real,
+                s: $real,
+            ) => ::rotation::rot(n, x, incx, y, incy, c, s);
+        }
+    };
+}
+
+/// A complex number returned by value.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Complex<T> {
+    re: T,
+    im: T,
+}
+
+// Defines functions returning a dot product by value, which call the symbol
+// of the same name if it is provided by OpenBLAS and the `_sub` variant
+// otherwise.
+macro_rules! dot {
+    ($t:ty, $real:ty, $($dot:ident => $dot_sub:ident,)*) => {$(
+        #[inline]
+        pub unsafe fn $dot(
+            n: blasint,
+            x: *const $t,
+            incx: blasint,
+            y: *const $t,
+            incy: blasint,
+        ) -> $t {
+            type Dot = unsafe extern "C" fn(
+                blasint,
+                *const $t,
+                blasint,
+                *const $t,
+                blasint,
+            ) -> Complex<$real>;
+
+            // Calling the symbol with the wrong convention is undefined, so
+            // it is only called if it belongs to OpenBLAS.
+            unsafe fn check(address: usize) -> bool {
+                let config = lookup(symbol!(openblas_get_config));
+                config != 0 && same_library(address, config)
+            }
+
+            static FUNCTION: Function = Function::new(symbol!($dot));
+            match FUNCTION.checked_address(check) {
+                Some(address) => {
+                    let function: Dot = mem::transmute(address);
+                    let value = function(n, x, incx, y, incy);
+                    [value.re, value.im]
+                }
+                None => {
+                    let mut value = [0.0; 2];
+                    ::$dot_sub(n, x, incx, y, incy, &mut value);
+                    value
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! batch {
+    (
+        $t:ty,
+        $axpy:ident => $axpy_batch:ident, $axpy_batch_strided:ident,
+        $gemv:ident => $gemv_batch:ident, $gemv_batch_strided:ident,
+        $gemm:ident => $gemm_batch:ident, $gemm_batch_strided:ident,
+        $trsm:ident => $trsm_batch:ident, $trsm_batch_strided:ident,
+    ) => {
+        extensions! {
+            pub fn $axpy_batch(
+                n_array: *const blasint,
+                alpha_array: *const $t,
+                x_array: *const *const $t,
+                incx_array: *const blasint,
+                y_array: *const *mut $t,
+                incy_array: *const blasint,
+                group_count: blasint,
+                group_size: *const blasint,
+            ) => fallback::axpy_batch(
+                ::$axpy,
+                n_array,
+                alpha_array,
+                x_array,
+                incx_array,
+                y_array,
+                incy_array,
+                group_count,
+                group_size,
+            );
+
+            pub fn $axpy_batch_strided(
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                stridex: blasint,
+                y: *mut $t,
+                incy: blasint,
+                stridey: blasint,
+                batch_size: blasint,
+            ) => fallback::axpy_batch_strided(
+                ::$axpy, n, alpha, x, incx, stridex, y, incy, stridey, batch_size,
+            );
+
+            pub fn $gemv_batch(
+                layout: CBLAS_LAYOUT,
+                trans_array: *const CBLAS_TRANSPOSE,
+                m_array: *const blasint,
+                n_array: *const blasint,
+                alpha_array: *const $t,
+                a_array: *const *const $t,
+                lda_array: *const blasint,
+                x_array: *const *const $t,
+                incx_array: *const blasint,
+                beta_array: *const $t,
+                y_array: *const *mut $t,
+                incy_array: *const blasint,
+                group_count: blasint,
+                group_size: *const blasint,
+            ) => fallback::gemv_batch(
+                ::$gemv,
+                layout,
+                trans_array,
+                m_array,
+                n_array,
+                alpha_array,
+                a_array,
+                lda_array,
+                x_array,
+                incx_array,
+                beta_array,
+                y_array,
+                incy_array,
+                group_count,
+                group_size,
+            );
+
+            pub fn $gemv_batch_strided(
+                layout: CBLAS_LAYOUT,
+                trans: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                stridea: blasint,
+                x: *const $t,
+                incx: blasint,
+                stridex: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+                stridey: blasint,
+                batch_size: blasint,
+            ) => fallback::gemv_batch_strided(
+                ::$gemv,
+                layout,
+                trans,
+                m,
+                n,
+                alpha,
+                a,
+                lda,
+                stridea,
+                x,
+                incx,
+                stridex,
+                beta,
+                y,
+                incy,
+                stridey,
+                batch_size,
+            );
+
+            pub fn $gemm_batch(
+                layout: CBLAS_LAYOUT,
+                transa_array: *const CBLAS_TRANSPOSE,
+                transb_array: *const CBLAS_TRANSPOSE,
+                m_array: *const blasint,
+                n_array: *const blasint,
+                k_array: *const blasint,
+                alpha_array: *const $t,
+                a_array: *const *const $t,
+                lda_array: *const blasint,
+                b_array: *const *const $t,
+                ldb_array: *const blasint,
+                beta_array: *const $t,
+                c_array: *const *mut $t,
+                ldc_array: *const blasint,
+                group_count: blasint,
+                group_size: *const blasint,
+            ) => fallback::gemm_batch(
+                ::$gemm,
+                layout,
+                transa_array,
+                transb_array,
+                m_array,
+                n_array,
+                k_array,
+                alpha_array,
+                a_array,
+                lda_array,
+                b_array,
+                ldb_array,
+                beta_array,
+                c_array,
+                ldc_array,
+                group_count,
+                group_size,
+            );
+
+            pub fn $gemm_batch_strided(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                stridea: blasint,
+                b: *const $t,
+                ldb: blasint,
+                strideb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+                stridec: blasint,
+                batch_size: blasint,
+            ) => fallback::gemm_batch_strided(
+                ::$gemm,
+                layout,
+                transa,
+                transb,
+                m,
+                n,
+                k,
+                alpha,
+                a,
+                lda,
+                stridea,
+                b,
+                ldb,
+                strideb,
+                beta,
+                c,
+                ldc,
+                stridec,
+                batch_size,
+            );
+
+            pub fn $trsm_batch(
+                layout: CBLAS_LAYOUT,
+                side_array: *const CBLAS_SIDE,
+                uplo_array: *const CBLAS_UPLO,
+                transa_array: *const CBLAS_TRANSPOSE,
+                diag_array: *const CBLAS_DIAG,
+                m_array: *const blasint,
+                n_array: *const blasint,
+                alpha_array: *const $t,
+                a_array: *const *const $t,
+                lda_array: *const blasint,
+                b_array: *const *mut $t,
+                ldb_array: *const blasint,
+                group_count: blasint,
+                group_size: *const blasint,
+            ) => fallback::trsm_batch(
+                ::$trsm,
+                layout,
+                side_array,
+                uplo_array,
+                transa_array,
+                diag_array,
+                m_array,
+                n_array,
+                alpha_array,
+                a_array,
+                lda_array,
+                b_array,
+                ldb_array,
+                group_count,
+                group_size,
+            );
+
+            pub fn $trsm_batch_strided(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                stridea: blasint,
+                b: *mut $t,
+                ldb: blasint,
+                strideb: blasint,
+                batch_size: blasint,
+            ) => fallback::trsm_batch_strided(
+                ::$trsm, layout, side, uplo, transa, diag, m, n, alpha, a, lda, stridea, b, ldb,
+                strideb, batch_size,
+            );
+        }
+    };
+}
+
+standard!(
+    c_float,
+    cblas_saxpby,
+    cblas_somatcopy,
+    cblas_simatcopy,
+    cblas_sgeadd,
+    cblas_isamin,
+    cblas_sgemmt,
+);
+
+standard!(
+    c_double,
+    cblas_daxpby,
+    cblas_domatcopy,
+    cblas_dimatcopy,
+    cblas_dgeadd,
+    cblas_idamin,
+    cblas_dgemmt,
+);
+
+standard!(
+    c_float_complex,
+    cblas_caxpby,
+    cblas_comatcopy,
+    cblas_cimatcopy,
+    cblas_cgeadd,
+    cblas_icamin,
+    cblas_cgemmt,
+);
+
+standard!(
+    c_double_complex,
+    cblas_zaxpby,
+    cblas_zomatcopy,
+    cblas_zimatcopy,
+    cblas_zgeadd,
+    cblas_izamin,
+    cblas_zgemmt,
+);
+
+complex!(c_float_complex, c_float, cblas_crotg, cblas_csrot,);
+complex!(c_double_complex, c_double, cblas_zrotg, cblas_zdrot,);
+
+dot!(
+    c_float_complex, c_float,
+    cblas_cdotu => cblas_cdotu_sub,
+    cblas_cdotc => cblas_cdotc_sub,
+);
+
+dot!(
+    c_double_complex, c_double,
+    cblas_zdotu => cblas_zdotu_sub,
+    cblas_zdotc => cblas_zdotc_sub,
+);
+
+batch!(
+    c_float,
+    cblas_saxpy => cblas_saxpy_batch, cblas_saxpy_batch_strided,
+    cblas_sgemv => cblas_sgemv_batch, cblas_sgemv_batch_strided,
+    cblas_sgemm => cblas_sgemm_batch, cblas_sgemm_batch_strided,
+    cblas_strsm => cblas_strsm_batch, cblas_strsm_batch_strided,
+);
+
+batch!(
+    c_double,
+    cblas_daxpy => cblas_daxpy_batch, cblas_daxpy_batch_strided,
+    cblas_dgemv => cblas_dgemv_batch, cblas_dgemv_batch_strided,
+    cblas_dgemm => cblas_dgemm_batch, cblas_dgemm_batch_strided,
+    cblas_dtrsm => cblas_dtrsm_batch, cblas_dtrsm_batch_strided,
+);
+
+batch!(
+    c_float_complex,
+    cblas_caxpy => cblas_caxpy_batch, cblas_caxpy_batch_strided,
+    cblas_cgemv => cblas_cgemv_batch, cblas_cgemv_batch_strided,
+    cblas_cgemm => cblas_cgemm_batch, cblas_cgemm_batch_strided,
+    cblas_ctrsm => cblas_ctrsm_batch, cblas_ctrsm_batch_strided,
+);
+
+batch!(
+    c_double_complex,
+    cblas_zaxpy => cblas_zaxpy_batch, cblas_zaxpy_batch_strided,
+    cblas_zgemv => cblas_zgemv_batch, cblas_zgemv_batch_strided,
+    cblas_zgemm => cblas_zgemm_batch, cblas_zgemm_batch_strided,
+    cblas_ztrsm => cblas_ztrsm_batch, cblas_ztrsm_batch_strided,
+);
@@ -0,0 +1,413 @@
+use libc::{c_double, c_float};
+
+#[cfg(not(cblas_fortran_complex_pointer))]
+use super::Complex;
+use super::Scalar;
+use {blasint, c_double_complex, c_float_complex, CBLAS_INDEX};
+
+macro_rules! standard {
+    (
+        $t:ty, $real:ty,
+        $swap:ident => $fswap:ident,
+        $copy:ident => $fcopy:ident,
+        $axpy:ident => $faxpy:ident,
+        $scal:ident => $fscal:ident,
+        $nrm2:ident => $fnrm2:ident,
+        $asum:ident => $fasum:ident,
+        $iamax:ident => $fiamax:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fswap(
+                n: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+            );
+            pub fn $fcopy(
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+            );
+            pub fn $faxpy(
+                n: *const blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+            );
+            pub fn $fscal(n: *const blasint, alpha: *const $t, x: *mut $t, incx: *const blasint);
+            pub fn $fnrm2(n: *const blasint, x: *const $t, incx: *const blasint) -> $real;
+            pub fn $fasum(n: *const blasint, x: *const $t, incx: *const blasint) -> $real;
+            pub fn $fiamax(n: *const blasint, x: *const $t, incx: *const blasint) -> blasint;
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $swap(n: blasint, x: *mut $t, incx: blasint, y: *mut $t, incy: blasint) {
+                $fswap(&n, x, &incx, y, &incy)
+            }
+
+            pub unsafe extern "C" fn $copy(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                $fcopy(&n, x, &incx, y, &incy)
+            }
+
+            pub unsafe extern "C" fn $axpy(
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                $faxpy(&n, &<$t>::load(alpha), x, &incx, y, &incy)
+            }
+
+            pub unsafe extern "C" fn $scal(n: blasint, alpha: <$t as Scalar>::Arg, x: *mut $t, incx: blasint) {
+                $fscal(&n, &<$t>::load(alpha), x, &incx)
+            }
+
+            pub unsafe extern "C" fn $nrm2(n: blasint, x: *const $t, incx: blasint) -> $real {
+                $fnrm2(&n, x, &incx)
+            }
+
+            pub unsafe extern "C" fn $asum(n: blasint, x: *const $t, incx: blasint) -> $real {
+                $fasum(&n, x, &incx)
+            }
+
+            // Fortran indices start from one, and zero is returned for an
+            // empty vector.
+            pub unsafe extern "C" fn $iamax(n: blasint, x: *const $t, incx: blasint) -> CBLAS_INDEX {
+                ($fiamax(&n, x, &incx) - 1).max(0)
+            }
+        }
+    };
+}
+
+standard!(
+    c_float, c_float,
+    cblas_sswap => sswap_,
+    cblas_scopy => scopy_,
+    cblas_saxpy => saxpy_,
+    cblas_sscal => sscal_,
+    cblas_snrm2 => snrm2_,
+    cblas_sasum => sasum_,
+    cblas_isamax => isamax_,
+);
+
+standard!(
+    c_double, c_double,
+    cblas_dswap => dswap_,
+    cblas_dcopy => dcopy_,
+    cblas_daxpy => daxpy_,
+    cblas_dscal => dscal_,
+    cblas_dnrm2 => dnrm2_,
+    cblas_dasum => dasum_,
+    cblas_idamax => idamax_,
+);
+
+standard!(
+    c_float_complex, c_float,
+    cblas_cswap => cswap_,
+    cblas_ccopy => ccopy_,
+    cblas_caxpy => caxpy_,
+    cblas_cscal => cscal_,
+    cblas_scnrm2 => scnrm2_,
+    cblas_scasum => scasum_,
+    cblas_icamax => icamax_,
+);
+
+standard!(
+    c_double_complex, c_double,
+    cblas_zswap => zswap_,
+    cblas_zcopy => zcopy_,
+    cblas_zaxpy => zaxpy_,
+    cblas_zscal => zscal_,
+    cblas_dznrm2 => dznrm2_,
+    cblas_dzasum => dzasum_,
+    cblas_izamax => izamax_,
+);
+
+macro_rules! real {
+    (
+        $t:ty,
+        $dot:ident => $fdot:ident,
+        $rotg:ident => $frotg:ident,
+        $rotmg:ident => $frotmg:ident,
+        $rot:ident => $frot:ident,
+        $rotm:ident => $frotm:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fdot(
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+            ) -> $t;
+            pub fn $frotg(a: *mut $t, b: *mut $t, c: *mut $t, s: *mut $t);
+            pub fn $frotmg(d1: *mut $t, d2: *mut $t, x1: *mut $t, y1: *const $t, param: *mut $t);
+            pub fn $frot(
+                n: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+                c: *const $t,
+                s: *const $t,
+            );
+            pub fn $frotm(
+                n: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+                param: *const $t,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $dot(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+                $fdot(&n, x, &incx, y, &incy)
+            }
+
+            pub unsafe extern "C" fn $rotg(a: *mut $t, b: *mut $t, c: *mut $t, s: *mut $t) {
+                $frotg(a, b, c, s)
+            }
+
+            pub unsafe extern "C" fn $rotmg(d1: *mut $t, d2: *mut $t, b1: *mut $t, b2: $t, p: *mut $t) {
+                $frotmg(d1, d2, b1, &b2, p)
+            }
+
+            pub unsafe extern "C" fn $rot(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                c: $t,
+                s: $t,
+            ) {
+                $frot(&n, x, &incx, y, &incy, &c, &s)
+            }
+
+            pub unsafe extern "C" fn $rotm(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                p: *const $t,
+            ) {
+                $frotm(&n, x, &incx, y, &incy, p)
+            }
+        }
+    };
+}
+
+real!(
+    c_float,
+    cblas_sdot => sdot_,
+    cblas_srotg => srotg_,
+    cblas_srotmg => srotmg_,
+    cblas_srot => srot_,
+    cblas_srotm => srotm_,
+);
+
+real!(
+    c_double,
+    cblas_ddot => ddot_,
+    cblas_drotg => drotg_,
+    cblas_drotmg => drotmg_,
+    cblas_drot => drot_,
+    cblas_drotm => drotm_,
+);
+
+macro_rules! complex {
+    (
+        $t:ty, $real:ty,
+        $dotu:ident => $fdotu:ident,
+        $dotc:ident => $fdotc:ident,
+        $rscal:ident => $frscal:ident,
+        $rotg:ident => $frotg:ident,
+        $rot:ident => $frot:ident,
+        $abs1:ident,
+    ) => {
+        extern_cblas! {
+            #[cfg(not(cblas_fortran_complex_pointer))]
+            pub fn $fdotu(
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+            ) -> Complex<$real>;
+            #[cfg(not(cblas_fortran_complex_pointer))]
+            pub fn $fdotc(
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+            ) -> Complex<$real>;
+            #[cfg(cblas_fortran_complex_pointer)]
+            pub fn $fdotu(
+                dotu: *mut $t,
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+            );
+            #[cfg(cblas_fortran_complex_pointer)]
+            pub fn $fdotc(
+                dotc: *mut $t,
+                n: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+            );
+            pub fn $frscal(n: *const blasint, alpha: *const $real, x: *mut $t, incx: *const blasint);
+            pub fn $frotg(a: *mut $t, b: *const $t, c: *mut $real, s: *mut $t);
+            pub fn $frot(
+                n: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                y: *mut $t,
+                incy: *const blasint,
+                c: *const $real,
+                s: *const $real,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $dotu(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                dotu: *mut $t,
+            ) {
+                #[cfg(not(cblas_fortran_complex_pointer))]
+                {
+                    *dotu = $fdotu(&n, x, &incx, y, &incy).into();
+                }
+                #[cfg(cblas_fortran_complex_pointer)]
+                $fdotu(dotu, &n, x, &incx, y, &incy);
+            }
+
+            pub unsafe extern "C" fn $dotc(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                dotc: *mut $t,
+            ) {
+                #[cfg(not(cblas_fortran_complex_pointer))]
+                {
+                    *dotc = $fdotc(&n, x, &incx, y, &incy).into();
+                }
+                #[cfg(cblas_fortran_complex_pointer)]
+                $fdotc(dotc, &n, x, &incx, y, &incy);
+            }
+
+            pub unsafe extern "C" fn $rscal(n: blasint, alpha: $real, x: *mut $t, incx: blasint) {
+                $frscal(&n, &alpha, x, &incx)
+            }
+
+            pub unsafe extern "C" fn $rotg(a: *mut $t, b: *mut $t, c: *mut $real, s: *mut $t) {
+                $frotg(a, b, c, s)
+            }
+
+            pub unsafe extern "C" fn $rot(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                c: $real,
+                s: $real,
+            ) {
+                $frot(&n, x, &incx, y, &incy, &c, &s)
+            }
+
+            // The function is missing from some libraries and trivial.
+            pub unsafe extern "C" fn $abs1(z: *const $t) -> $real {
+                let [re, im] = *z;
+                re.abs() + im.abs()
+            }
+        }
+    };
+}
+
+complex!(
+    c_float_complex, c_float,
+    cblas_cdotu_sub => cdotu_,
+    cblas_cdotc_sub => cdotc_,
+    cblas_csscal => csscal_,
+    cblas_crotg => crotg_,
+    cblas_csrot => csrot_,
+    cblas_scabs1,
+);
+
+complex!(
+    c_double_complex, c_double,
+    cblas_zdotu_sub => zdotu_,
+    cblas_zdotc_sub => zdotc_,
+    cblas_zdscal => zdscal_,
+    cblas_zrotg => zrotg_,
+    cblas_zdrot => zdrot_,
+    cblas_dcabs1,
+);
+
+extern_cblas! {
+    pub fn sdsdot_(
+        n: *const blasint,
+        sb: *const c_float,
+        x: *const c_float,
+        incx: *const blasint,
+        y: *const c_float,
+        incy: *const blasint,
+    ) -> c_float;
+    pub fn dsdot_(
+        n: *const blasint,
+        x: *const c_float,
+        incx: *const blasint,
+        y: *const c_float,
+        incy: *const blasint,
+    ) -> c_double;
+}
+
+export_cblas! {
+    pub unsafe extern "C" fn cblas_sdsdot(
+        n: blasint,
+        alpha: c_float,
+        x: *const c_float,
+        incx: blasint,
+        y: *const c_float,
+        incy: blasint,
+    ) -> c_float {
+        sdsdot_(&n, &alpha, x, &incx, y, &incy)
+    }
+
+    pub unsafe extern "C" fn cblas_dsdot(
+        n: blasint,
+        x: *const c_float,
+        incx: blasint,
+        y: *const c_float,
+        incy: blasint,
+    ) -> c_double {
+        dsdot_(&n, x, &incx, y, &incy)
+    }
+}
@@ -0,0 +1,1057 @@
+use libc::{c_char, c_double, c_float, c_int};
+
+use super::{code, conjugate, conjugated, Length, Scalar};
+use {blasint, c_double_complex, c_float_complex};
+use {CblasColMajor, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans};
+
+/// y := alpha * op(A) * x + beta * y for a general matrix A, where `mv` is
+/// given the Fortran option for op, whether the dimensions of A are swapped,
+/// alpha, x, incx, and beta.
+unsafe fn general<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    trans: c_int,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+    mv: F,
+) where
+    T: Scalar,
+    F: FnOnce(c_char, bool, &T, *const T, blasint, &T),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => trans: CBLAS_TRANSPOSE,
+    );
+    match (layout, trans) {
+        (CblasColMajor, _) => mv(code(trans), false, &alpha, x, incx, &beta),
+        (CblasRowMajor, CblasNoTrans) => mv(code(CblasTrans), true, &alpha, x, incx, &beta),
+        // conj(y) := conj(alpha) * A * conj(x) + conj(beta) * conj(y).
+        (CblasRowMajor, CblasConjTrans) if T::COMPLEX => {
+            let x = conjugated(m, x, incx);
+            conjugate(n, y, incy);
+            mv(
+                code(CblasNoTrans),
+                true,
+                &alpha.conj(),
+                x.as_ptr(),
+                1,
+                &beta.conj(),
+            );
+            conjugate(n, y, incy);
+        }
+        (CblasRowMajor, _) => mv(code(CblasNoTrans), true, &alpha, x, incx, &beta),
+    }
+}
+
+/// x := op(A) * x or x := inv(op(A)) * x for a triangular matrix A, where
+/// `op` is given the Fortran options for uplo, trans, and diag.
+unsafe fn triangular<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    trans: c_int,
+    diag: c_int,
+    n: blasint,
+    x: *mut T,
+    incx: blasint,
+    op: F,
+) where
+    T: Scalar,
+    F: FnOnce(c_char, c_char, c_char),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+        3 => trans: CBLAS_TRANSPOSE,
+        4 => diag: CBLAS_DIAG,
+    );
+    let diag = code(diag);
+    match (layout, trans) {
+        (CblasColMajor, _) => op(code(uplo), code(trans), diag),
+        (CblasRowMajor, CblasNoTrans) => op(code(uplo.flip()), code(CblasTrans), diag),
+        // conj(x) := A * conj(x) or conj(x) := inv(A) * conj(x).
+        (CblasRowMajor, CblasConjTrans) if T::COMPLEX => {
+            conjugate(n, x, incx);
+            op(code(uplo.flip()), code(CblasNoTrans), diag);
+            conjugate(n, x, incx);
+        }
+        (CblasRowMajor, _) => op(code(uplo.flip()), code(CblasNoTrans), diag),
+    }
+}
+
+/// y := alpha * A * x + beta * y for a symmetric or Hermitian matrix A, where
+/// `mv` is given the Fortran option for uplo, alpha, x, incx, and beta.
+unsafe fn symmetric<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+    mv: F,
+) where
+    T: Scalar,
+    F: FnOnce(c_char, &T, *const T, blasint, &T),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+    );
+    match layout {
+        CblasColMajor => mv(code(uplo), &alpha, x, incx, &beta),
+        // The transpose of a Hermitian matrix is its conjugate.
+        CblasRowMajor if T::COMPLEX => {
+            let x = conjugated(n, x, incx);
+            conjugate(n, y, incy);
+            mv(
+                code(uplo.flip()),
+                &alpha.conj(),
+                x.as_ptr(),
+                1,
+                &beta.conj(),
+            );
+            conjugate(n, y, incy);
+        }
+        CblasRowMajor => mv(code(uplo.flip()), &alpha, x, incx, &beta),
+    }
+}
+
+/// A := alpha * x * y^T + A or A := alpha * x * y^H + A for a general matrix
+/// A, where `r` is given whether y is conjugated already, m, n, x, incx, y,
+/// and incy.
+unsafe fn ger<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    conj: bool,
+    m: blasint,
+    n: blasint,
+    x: *const T,
+    incx: blasint,
+    y: *const T,
+    incy: blasint,
+    r: F,
+) where
+    T: Scalar,
+    F: FnOnce(bool, blasint, blasint, *const T, blasint, *const T, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+    );
+    match layout {
+        CblasColMajor => r(false, m, n, x, incx, y, incy),
+        // A^T := alpha * conj(y) * x^T + A^T.
+        CblasRowMajor if conj => {
+            let y = conjugated(n, y, incy);
+            r(true, n, m, y.as_ptr(), 1, x, incx)
+        }
+        CblasRowMajor => r(false, n, m, y, incy, x, incx),
+    }
+}
+
+/// A := alpha * x * x^H + A for a symmetric or Hermitian matrix A, where `r`
+/// is given the Fortran option for uplo, x, and incx.
+unsafe fn rank1<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    n: blasint,
+    x: *const T,
+    incx: blasint,
+    r: F,
+) where
+    T: Scalar,
+    F: FnOnce(c_char, *const T, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+    );
+    match layout {
+        CblasColMajor => r(code(uplo), x, incx),
+        // A^T := alpha * conj(x) * conj(x)^H + A^T.
+        CblasRowMajor if T::COMPLEX => {
+            let x = conjugated(n, x, incx);
+            r(code(uplo.flip()), x.as_ptr(), 1)
+        }
+        CblasRowMajor => r(code(uplo.flip()), x, incx),
+    }
+}
+
+/// A := alpha * x * y^H + conj(alpha) * y * x^H + A for a symmetric or
+/// Hermitian matrix A, where `r` is given the Fortran option for uplo, x,
+/// incx, y, and incy.
+unsafe fn rank2<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    n: blasint,
+    x: *const T,
+    incx: blasint,
+    y: *const T,
+    incy: blasint,
+    r: F,
+) where
+    T: Scalar,
+    F: FnOnce(c_char, *const T, blasint, *const T, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+    );
+    match layout {
+        CblasColMajor => r(code(uplo), x, incx, y, incy),
+        // A^T := alpha * conj(y) * conj(x)^H + conj(alpha) * conj(x) * conj(y)^H + A^T.
+        CblasRowMajor if T::COMPLEX => {
+            let (x, y) = (conjugated(n, x, incx), conjugated(n, y, incy));
+            r(code(uplo.flip()), y.as_ptr(), 1, x.as_ptr(), 1)
+        }
+        CblasRowMajor => r(code(uplo.flip()), x, incx, y, incy),
+    }
+}
+
+macro_rules! standard {
+    (
+        $t:ty,
+        $gemv:ident => $fgemv:ident,
+        $gbmv:ident => $fgbmv:ident,
+        $trmv:ident => $ftrmv:ident,
+        $tbmv:ident => $ftbmv:ident,
+        $tpmv:ident => $ftpmv:ident,
+        $trsv:ident => $ftrsv:ident,
+        $tbsv:ident => $ftbsv:ident,
+        $tpsv:ident => $ftpsv:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fgemv(
+                trans: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                beta: *const $t,
+                y: *mut $t,
+                incy: *const blasint,
+                trans_len: Length,
+            );
+            pub fn $fgbmv(
+                trans: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                kl: *const blasint,
+                ku: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                beta: *const $t,
+                y: *mut $t,
+                incy: *const blasint,
+                trans_len: Length,
+            );
+            pub fn $ftrmv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                a: *const $t,
+                lda: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftbmv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                a: *const $t,
+                lda: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftpmv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftrsv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                a: *const $t,
+                lda: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftbsv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                a: *const $t,
+                lda: *const blasint,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftpsv(
+                uplo: *const c_char,
+                trans: *const c_char,
+                diag: *const c_char,
+                n: *const blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+                diag_len: Length,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $gemv(
+                layout: c_int,
+                trans: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                general(
+                    concat!(stringify!($gemv), "\0").as_bytes(),
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                    |trans, swap, alpha, x, incx, beta| {
+                        let (m, n) = if swap { (n, m) } else { (m, n) };
+                        $fgemv(&trans, &m, &n, alpha, a, &lda, x, &incx, beta, y, &incy, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $gbmv(
+                layout: c_int,
+                trans: c_int,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                general(
+                    concat!(stringify!($gbmv), "\0").as_bytes(),
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                    |trans, swap, alpha, x, incx, beta| {
+                        let (m, n, kl, ku) = if swap { (n, m, ku, kl) } else { (m, n, kl, ku) };
+                        $fgbmv(
+                            &trans, &m, &n, &kl, &ku, alpha, a, &lda, x, &incx, beta, y, &incy, 1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $trmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($trmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| {
+                        $ftrmv(&uplo, &trans, &diag, &n, a, &lda, x, &incx, 1, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $tbmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                k: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($tbmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| {
+                        $ftbmv(&uplo, &trans, &diag, &n, &k, a, &lda, x, &incx, 1, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $tpmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($tpmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| $ftpmv(&uplo, &trans, &diag, &n, ap, x, &incx, 1, 1, 1),
+                )
+            }
+
+            pub unsafe extern "C" fn $trsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($trsv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| {
+                        $ftrsv(&uplo, &trans, &diag, &n, a, &lda, x, &incx, 1, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $tbsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                k: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($tbsv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| {
+                        $ftbsv(&uplo, &trans, &diag, &n, &k, a, &lda, x, &incx, 1, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $tpsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(
+                    concat!(stringify!($tpsv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    x,
+                    incx,
+                    |uplo, trans, diag| $ftpsv(&uplo, &trans, &diag, &n, ap, x, &incx, 1, 1, 1),
+                )
+            }
+        }
+    };
+}
+
+standard!(
+    c_float,
+    cblas_sgemv => sgemv_,
+    cblas_sgbmv => sgbmv_,
+    cblas_strmv => strmv_,
+    cblas_stbmv => stbmv_,
+    cblas_stpmv => stpmv_,
+    cblas_strsv => strsv_,
+    cblas_stbsv => stbsv_,
+    cblas_stpsv => stpsv_,
+);
+
+standard!(
+    c_double,
+    cblas_dgemv => dgemv_,
+    cblas_dgbmv => dgbmv_,
+    cblas_dtrmv => dtrmv_,
+    cblas_dtbmv => dtbmv_,
+    cblas_dtpmv => dtpmv_,
+    cblas_dtrsv => dtrsv_,
+    cblas_dtbsv => dtbsv_,
+    cblas_dtpsv => dtpsv_,
+);
+
+standard!(
+    c_float_complex,
+    cblas_cgemv => cgemv_,
+    cblas_cgbmv => cgbmv_,
+    cblas_ctrmv => ctrmv_,
+    cblas_ctbmv => ctbmv_,
+    cblas_ctpmv => ctpmv_,
+    cblas_ctrsv => ctrsv_,
+    cblas_ctbsv => ctbsv_,
+    cblas_ctpsv => ctpsv_,
+);
+
+standard!(
+    c_double_complex,
+    cblas_zgemv => zgemv_,
+    cblas_zgbmv => zgbmv_,
+    cblas_ztrmv => ztrmv_,
+    cblas_ztbmv => ztbmv_,
+    cblas_ztpmv => ztpmv_,
+    cblas_ztrsv => ztrsv_,
+    cblas_ztbsv => ztbsv_,
+    cblas_ztpsv => ztpsv_,
+);
+
+macro_rules! symmetric {
+    (
+        $t:ty, $real:ty,
+        $symv:ident => $fsymv:ident,
+        $sbmv:ident => $fsbmv:ident,
+        $spmv:ident => $fspmv:ident,
+        $syr:ident => $fsyr:ident,
+        $spr:ident => $fspr:ident,
+        $syr2:ident => $fsyr2:ident,
+        $spr2:ident => $fspr2:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fsymv(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                beta: *const $t,
+                y: *mut $t,
+                incy: *const blasint,
+                uplo_len: Length,
+            );
+            pub fn $fsbmv(
+                uplo: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                x: *const $t,
+                incx: *const blasint,
+                beta: *const $t,
+                y: *mut $t,
+                incy: *const blasint,
+                uplo_len: Length,
+            );
+            pub fn $fspmv(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $t,
+                ap: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                beta: *const $t,
+                y: *mut $t,
+                incy: *const blasint,
+                uplo_len: Length,
+            );
+            pub fn $fsyr(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $real,
+                x: *const $t,
+                incx: *const blasint,
+                a: *mut $t,
+                lda: *const blasint,
+                uplo_len: Length,
+            );
+            pub fn $fspr(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $real,
+                x: *const $t,
+                incx: *const blasint,
+                ap: *mut $t,
+                uplo_len: Length,
+            );
+            pub fn $fsyr2(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+                a: *mut $t,
+                lda: *const blasint,
+                uplo_len: Length,
+            );
+            pub fn $fspr2(
+                uplo: *const c_char,
+                n: *const blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+                ap: *mut $t,
+                uplo_len: Length,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $symv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($symv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                    |uplo, alpha, x, incx, beta| {
+                        $fsymv(&uplo, &n, alpha, a, &lda, x, &incx, beta, y, &incy, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $sbmv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($sbmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                    |uplo, alpha, x, incx, beta| {
+                        $fsbmv(&uplo, &n, &k, alpha, a, &lda, x, &incx, beta, y, &incy, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $spmv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                ap: *const $t,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($spmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                    |uplo, alpha, x, incx, beta| {
+                        $fspmv(&uplo, &n, alpha, ap, x, &incx, beta, y, &incy, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $syr(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: $real,
+                x: *const $t,
+                incx: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                rank1(
+                    concat!(stringify!($syr), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    x,
+                    incx,
+                    |uplo, x, incx| $fsyr(&uplo, &n, &alpha, x, &incx, a, &lda, 1),
+                )
+            }
+
+            pub unsafe extern "C" fn $spr(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: $real,
+                x: *const $t,
+                incx: blasint,
+                ap: *mut $t,
+            ) {
+                rank1(
+                    concat!(stringify!($spr), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    x,
+                    incx,
+                    |uplo, x, incx| $fspr(&uplo, &n, &alpha, x, &incx, ap, 1),
+                )
+            }
+
+            pub unsafe extern "C" fn $syr2(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                let alpha = <$t>::load(alpha);
+                rank2(
+                    concat!(stringify!($syr2), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    |uplo, x, incx, y, incy| {
+                        $fsyr2(&uplo, &n, &alpha, x, &incx, y, &incy, a, &lda, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $spr2(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                ap: *mut $t,
+            ) {
+                let alpha = <$t>::load(alpha);
+                rank2(
+                    concat!(stringify!($spr2), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    |uplo, x, incx, y, incy| {
+                        $fspr2(&uplo, &n, &alpha, x, &incx, y, &incy, ap, 1)
+                    },
+                )
+            }
+        }
+    };
+}
+
+symmetric!(
+    c_float, c_float,
+    cblas_ssymv => ssymv_,
+    cblas_ssbmv => ssbmv_,
+    cblas_sspmv => sspmv_,
+    cblas_ssyr => ssyr_,
+    cblas_sspr => sspr_,
+    cblas_ssyr2 => ssyr2_,
+    cblas_sspr2 => sspr2_,
+);
+
+symmetric!(
+    c_double, c_double,
+    cblas_dsymv => dsymv_,
+    cblas_dsbmv => dsbmv_,
+    cblas_dspmv => dspmv_,
+    cblas_dsyr => dsyr_,
+    cblas_dspr => dspr_,
+    cblas_dsyr2 => dsyr2_,
+    cblas_dspr2 => dspr2_,
+);
+
+symmetric!(
+    c_float_complex, c_float,
+    cblas_chemv => chemv_,
+    cblas_chbmv => chbmv_,
+    cblas_chpmv => chpmv_,
+    cblas_cher => cher_,
+    cblas_chpr => chpr_,
+    cblas_cher2 => cher2_,
+    cblas_chpr2 => chpr2_,
+);
+
+symmetric!(
+    c_double_complex, c_double,
+    cblas_zhemv => zhemv_,
+    cblas_zhbmv => zhbmv_,
+    cblas_zhpmv => zhpmv_,
+    cblas_zher => zher_,
+    cblas_zhpr => zhpr_,
+    cblas_zher2 => zher2_,
+    cblas_zhpr2 => zhpr2_,
+);
+
+macro_rules! ger {
+    ($t:ty, $ger:ident => $fger:ident) => {
+        extern_cblas! {
+            pub fn $fger(
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+                a: *mut $t,
+                lda: *const blasint,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $ger(
+                layout: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                let alpha = <$t>::load(alpha);
+                ger(
+                    concat!(stringify!($ger), "\0").as_bytes(),
+                    layout,
+                    false,
+                    m,
+                    n,
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    |_, m, n, x, incx, y, incy| {
+                        $fger(&m, &n, &alpha, x, &incx, y, &incy, a, &lda)
+                    },
+                )
+            }
+        }
+    };
+    ($t:ty, $geru:ident => $fgeru:ident, $gerc:ident => $fgerc:ident) => {
+        ger!($t, $geru => $fgeru);
+
+        extern_cblas! {
+            pub fn $fgerc(
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: *const blasint,
+                y: *const $t,
+                incy: *const blasint,
+                a: *mut $t,
+                lda: *const blasint,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $gerc(
+                layout: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: *const $t,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                ger(
+                    concat!(stringify!($gerc), "\0").as_bytes(),
+                    layout,
+                    true,
+                    m,
+                    n,
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    |conjugated, m, n, x, incx, y, incy| {
+                        if conjugated {
+                            $fgeru(&m, &n, alpha, x, &incx, y, &incy, a, &lda)
+                        } else {
+                            $fgerc(&m, &n, alpha, x, &incx, y, &incy, a, &lda)
+                        }
+                    },
+                )
+            }
+        }
+    };
+}
+
+ger!(c_float, cblas_sger => sger_);
+ger!(c_double, cblas_dger => dger_);
+ger!(c_float_complex, cblas_cgeru => cgeru_, cblas_cgerc => cgerc_);
+ger!(c_double_complex, cblas_zgeru => zgeru_, cblas_zgerc => zgerc_);
@@ -0,0 +1,628 @@
+use libc::{c_char, c_double, c_float, c_int};
+
+use super::{code, Length, Scalar};
+use {blasint, c_double_complex, c_float_complex};
+use {CblasColMajor, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans};
+
+/// C := alpha * op(A) * op(B) + beta * C, where `mm` is given the Fortran
+/// options for op(A) and op(B), m, n, a, lda, b, and ldb.
+unsafe fn gemm<T, F>(
+    routine: &[u8],
+    layout: c_int,
+    transa: c_int,
+    transb: c_int,
+    m: blasint,
+    n: blasint,
+    a: *const T,
+    lda: blasint,
+    b: *const T,
+    ldb: blasint,
+    mm: F,
+) where
+    F: FnOnce(c_char, c_char, blasint, blasint, *const T, blasint, *const T, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => transa: CBLAS_TRANSPOSE,
+        3 => transb: CBLAS_TRANSPOSE,
+    );
+    match layout {
+        CblasColMajor => mm(code(transa), code(transb), m, n, a, lda, b, ldb),
+        // C^T := alpha * op(B)^T * op(A)^T + beta * C^T.
+        CblasRowMajor => mm(code(transb), code(transa), n, m, b, ldb, a, lda),
+    }
+}
+
+/// An operation on an m by n matrix B with a square matrix A applied from the
+/// given side, where `op` is given the Fortran options for side and uplo, m,
+/// and n.
+unsafe fn sided<F>(
+    routine: &[u8],
+    layout: c_int,
+    side: c_int,
+    uplo: c_int,
+    m: blasint,
+    n: blasint,
+    op: F,
+) where
+    F: FnOnce(c_char, c_char, blasint, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => side: CBLAS_SIDE,
+        3 => uplo: CBLAS_UPLO,
+    );
+    match layout {
+        CblasColMajor => op(code(side), code(uplo), m, n),
+        CblasRowMajor => op(code(side.flip()), code(uplo.flip()), n, m),
+    }
+}
+
+/// B := alpha * op(A) * B or B := alpha * inv(op(A)) * B, or the same with A
+/// on the right, for a triangular matrix A, where `op` is given the Fortran
+/// options for side, uplo, transa, and diag, m, and n.
+unsafe fn triangular<F>(
+    routine: &[u8],
+    layout: c_int,
+    side: c_int,
+    uplo: c_int,
+    transa: c_int,
+    diag: c_int,
+    m: blasint,
+    n: blasint,
+    op: F,
+) where
+    F: FnOnce(c_char, c_char, c_char, c_char, blasint, blasint),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => side: CBLAS_SIDE,
+        3 => uplo: CBLAS_UPLO,
+        4 => transa: CBLAS_TRANSPOSE,
+        5 => diag: CBLAS_DIAG,
+    );
+    let (transa, diag) = (code(transa), code(diag));
+    match layout {
+        CblasColMajor => op(code(side), code(uplo), transa, diag, m, n),
+        CblasRowMajor => op(code(side.flip()), code(uplo.flip()), transa, diag, n, m),
+    }
+}
+
+/// C := alpha * A * A^T + beta * C and the like for a symmetric or Hermitian
+/// matrix C, where `rk` is given the Fortran options for uplo and trans, and
+/// whether the layout is row-major.
+unsafe fn rank<T, F>(routine: &[u8], herm: bool, layout: c_int, uplo: c_int, trans: c_int, rk: F)
+where
+    T: Scalar,
+    F: FnOnce(c_char, c_char, bool),
+{
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+        3 => trans: CBLAS_TRANSPOSE,
+    );
+    let trans = match (layout, trans) {
+        (CblasColMajor, _) => trans,
+        (CblasRowMajor, CblasNoTrans) if herm => CblasConjTrans,
+        (CblasRowMajor, CblasNoTrans) => CblasTrans,
+        (CblasRowMajor, CblasConjTrans) if herm || !T::COMPLEX => CblasNoTrans,
+        (CblasRowMajor, CblasTrans) if !herm => CblasNoTrans,
+        // Invalid options are passed through for the Fortran routine to report.
+        (CblasRowMajor, _) => trans,
+    };
+    match layout {
+        CblasColMajor => rk(code(uplo), code(trans), false),
+        CblasRowMajor => rk(code(uplo.flip()), code(trans), true),
+    }
+}
+
+macro_rules! standard {
+    (
+        $t:ty,
+        $gemm:ident => $fgemm:ident,
+        $symm:ident => $fsymm:ident,
+        $syrk:ident => $fsyrk:ident,
+        $syr2k:ident => $fsyr2k:ident,
+        $trmm:ident => $ftrmm:ident,
+        $trsm:ident => $ftrsm:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fgemm(
+                transa: *const c_char,
+                transb: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *const $t,
+                ldb: *const blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: *const blasint,
+                transa_len: Length,
+                transb_len: Length,
+            );
+            pub fn $fsymm(
+                side: *const c_char,
+                uplo: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *const $t,
+                ldb: *const blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: *const blasint,
+                side_len: Length,
+                uplo_len: Length,
+            );
+            pub fn $fsyrk(
+                uplo: *const c_char,
+                trans: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+            );
+            pub fn $fsyr2k(
+                uplo: *const c_char,
+                trans: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *const $t,
+                ldb: *const blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+            );
+            pub fn $ftrmm(
+                side: *const c_char,
+                uplo: *const c_char,
+                transa: *const c_char,
+                diag: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *mut $t,
+                ldb: *const blasint,
+                side_len: Length,
+                uplo_len: Length,
+                transa_len: Length,
+                diag_len: Length,
+            );
+            pub fn $ftrsm(
+                side: *const c_char,
+                uplo: *const c_char,
+                transa: *const c_char,
+                diag: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *mut $t,
+                ldb: *const blasint,
+                side_len: Length,
+                uplo_len: Length,
+                transa_len: Length,
+                diag_len: Length,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $gemm(
+                layout: c_int,
+                transa: c_int,
+                transb: c_int,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                gemm(
+                    concat!(stringify!($gemm), "\0").as_bytes(),
+                    layout,
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    |transa, transb, m, n, a, lda, b, ldb| {
+                        $fgemm(
+                            &transa, &transb, &m, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c,
+                            &ldc, 1, 1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $symm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                sided(
+                    concat!(stringify!($symm), "\0").as_bytes(),
+                    layout,
+                    side,
+                    uplo,
+                    m,
+                    n,
+                    |side, uplo, m, n| {
+                        $fsymm(
+                            &side, &uplo, &m, &n, &alpha, a, &lda, b, &ldb, &beta, c, &ldc, 1,
+                            1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $syrk(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                rank::<$t, _>(
+                    concat!(stringify!($syrk), "\0").as_bytes(),
+                    false,
+                    layout,
+                    uplo,
+                    trans,
+                    |uplo, trans, _| {
+                        $fsyrk(&uplo, &trans, &n, &k, &alpha, a, &lda, &beta, c, &ldc, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $syr2k(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                let (alpha, beta) = (<$t>::load(alpha), <$t>::load(beta));
+                rank::<$t, _>(
+                    concat!(stringify!($syr2k), "\0").as_bytes(),
+                    false,
+                    layout,
+                    uplo,
+                    trans,
+                    |uplo, trans, _| {
+                        $fsyr2k(
+                            &uplo, &trans, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc, 1,
+                            1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $trmm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                transa: c_int,
+                diag: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *mut $t,
+                ldb: blasint,
+            ) {
+                let alpha = <$t>::load(alpha);
+                triangular(
+                    concat!(stringify!($trmm), "\0").as_bytes(),
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    |side, uplo, transa, diag, m, n| {
+                        $ftrmm(
+                            &side, &uplo, &transa, &diag, &m, &n, &alpha, a, &lda, b, &ldb, 1,
+                            1, 1, 1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $trsm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                transa: c_int,
+                diag: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *mut $t,
+                ldb: blasint,
+            ) {
+                let alpha = <$t>::load(alpha);
+                triangular(
+                    concat!(stringify!($trsm), "\0").as_bytes(),
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    |side, uplo, transa, diag, m, n| {
+                        $ftrsm(
+                            &side, &uplo, &transa, &diag, &m, &n, &alpha, a, &lda, b, &ldb, 1,
+                            1, 1, 1,
+                        )
+                    },
+                )
+            }
+        }
+    };
+}
+
+standard!(
+    c_float,
+    cblas_sgemm => sgemm_,
+    cblas_ssymm => ssymm_,
+    cblas_ssyrk => ssyrk_,
+    cblas_ssyr2k => ssyr2k_,
+    cblas_strmm => strmm_,
+    cblas_strsm => strsm_,
+);
+
+standard!(
+    c_double,
+    cblas_dgemm => dgemm_,
+    cblas_dsymm => dsymm_,
+    cblas_dsyrk => dsyrk_,
+    cblas_dsyr2k => dsyr2k_,
+    cblas_dtrmm => dtrmm_,
+    cblas_dtrsm => dtrsm_,
+);
+
+standard!(
+    c_float_complex,
+    cblas_cgemm => cgemm_,
+    cblas_csymm => csymm_,
+    cblas_csyrk => csyrk_,
+    cblas_csyr2k => csyr2k_,
+    cblas_ctrmm => ctrmm_,
+    cblas_ctrsm => ctrsm_,
+);
+
+standard!(
+    c_double_complex,
+    cblas_zgemm => zgemm_,
+    cblas_zsymm => zsymm_,
+    cblas_zsyrk => zsyrk_,
+    cblas_zsyr2k => zsyr2k_,
+    cblas_ztrmm => ztrmm_,
+    cblas_ztrsm => ztrsm_,
+);
+
+macro_rules! hermitian {
+    (
+        $t:ty, $real:ty,
+        $hemm:ident => $fhemm:ident,
+        $herk:ident => $fherk:ident,
+        $her2k:ident => $fher2k:ident,
+    ) => {
+        extern_cblas! {
+            pub fn $fhemm(
+                side: *const c_char,
+                uplo: *const c_char,
+                m: *const blasint,
+                n: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *const $t,
+                ldb: *const blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: *const blasint,
+                side_len: Length,
+                uplo_len: Length,
+            );
+            pub fn $fherk(
+                uplo: *const c_char,
+                trans: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $real,
+                a: *const $t,
+                lda: *const blasint,
+                beta: *const $real,
+                c: *mut $t,
+                ldc: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+            );
+            pub fn $fher2k(
+                uplo: *const c_char,
+                trans: *const c_char,
+                n: *const blasint,
+                k: *const blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: *const blasint,
+                b: *const $t,
+                ldb: *const blasint,
+                beta: *const $real,
+                c: *mut $t,
+                ldc: *const blasint,
+                uplo_len: Length,
+                trans_len: Length,
+            );
+        }
+
+        export_cblas! {
+            pub unsafe extern "C" fn $hemm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                sided(
+                    concat!(stringify!($hemm), "\0").as_bytes(),
+                    layout,
+                    side,
+                    uplo,
+                    m,
+                    n,
+                    |side, uplo, m, n| {
+                        $fhemm(
+                            &side, &uplo, &m, &n, alpha, a, &lda, b, &ldb, beta, c, &ldc, 1, 1,
+                        )
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $herk(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: $real,
+                a: *const $t,
+                lda: blasint,
+                beta: $real,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                rank::<$t, _>(
+                    concat!(stringify!($herk), "\0").as_bytes(),
+                    true,
+                    layout,
+                    uplo,
+                    trans,
+                    |uplo, trans, _| {
+                        $fherk(&uplo, &trans, &n, &k, &alpha, a, &lda, &beta, c, &ldc, 1, 1)
+                    },
+                )
+            }
+
+            pub unsafe extern "C" fn $her2k(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: $real,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                rank::<$t, _>(
+                    concat!(stringify!($her2k), "\0").as_bytes(),
+                    true,
+                    layout,
+                    uplo,
+                    trans,
+                    // C^T := conj(alpha) * A^H * B + alpha * B^H * A + beta * C^T.
+                    |uplo, trans, row| {
+                        let alpha = if row { (*alpha).conj() } else { *alpha };
+                        $fher2k(
+                            &uplo, &trans, &n, &k, &alpha, a, &lda, b, &ldb, &beta, c, &ldc, 1,
+                            1,
+                        )
+                    },
+                )
+            }
+        }
+    };
+}
+
+hermitian!(
+    c_float_complex, c_float,
+    cblas_chemm => chemm_,
+    cblas_cherk => cherk_,
+    cblas_cher2k => cher2k_,
+);
+
+hermitian!(
+    c_double_complex, c_double,
+    cblas_zhemm => zhemm_,
+    cblas_zherk => zherk_,
+    cblas_zher2k => zher2k_,
+);
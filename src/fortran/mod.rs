@@ -0,0 +1,565 @@
+//! An implementation of CBLAS on top of Fortran BLAS.
+//!
+//! Every function declared by the package is implemented by calling the
+//! corresponding Fortran routine, e.g., `cblas_dgemm` calls `dgemm_`, so that
+//! only a Fortran BLAS library has to be linked. Fortran routines take every
+//! argument by pointer and only support the column-major layout; a row-major
+//! call is translated into a column-major one on the transposed matrices by
+//! swapping the operands and the dimensions and flipping `uplo`, `side`, and
+//! `trans`, as in the reference CBLAS. The complex routines for which the
+//! translation involves a conjugation conjugate a copy of the vectors.
+//!
+//! The library is assumed to pass the length of every character argument as
+//! `size_t` after the other arguments, and to return real numbers as C
+//! functions returning `float` and `double` would, as gfortran, the Intel
+//! compilers, and libraries written in C such as OpenBLAS and BLIS do, but not
+//! f2c. The functions returning complex numbers, that is, `cdotu_`, `cdotc_`,
+//! `zdotu_`, and `zdotc_`, are called according to the convention selected at
+//! build time: by default, they return the number as C functions returning
+//! `float _Complex` and `double _Complex` would, which is the convention of
+//! gfortran, OpenBLAS, and BLIS, unless MKL is selected, whose Intel interface
+//! writes the number to a hidden pointer passed before the other arguments, as
+//! the Intel compilers, f2c, and g77 do. The `CBLAS_FORTRAN_COMPLEX`
+//! environment variable set to `value` or `pointer` overrides the choice at
+//! build time. Without a backend feature, the reference BLAS, `libblas`, is
+//! linked. The symbols are looked up with the suffix selected via the
+//! `suffix-*` features, if any, e.g., `dgemm_64_`.
+
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use std::vec::Vec;
+
+use libc::c_char;
+
+use blasint;
+
+mod level1;
+mod level2;
+mod level3;
+
+use scalar::Scalar;
+
+/// The length of a character argument.
+type Length = usize;
+
+/// A complex number returned by a Fortran function by value.
+#[cfg(not(cblas_fortran_complex_pointer))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Complex<T> {
+    re: T,
+    im: T,
+}
+
+#[cfg(not(cblas_fortran_complex_pointer))]
+impl<T> From<Complex<T>> for [T; 2] {
+    #[inline]
+    fn from(value: Complex<T>) -> [T; 2] {
+        [value.re, value.im]
+    }
+}
+
+extern_cblas! {
+    #[cfg(not(feature = "xerbla"))]
+    pub fn xerbla_(srname: *const c_char, info: *const blasint, srname_len: Length);
+}
+
+define_xerbla! {
+    // The error is passed to the handler of the library, unless the handler
+    // of the `xerbla` feature is enabled, which takes precedence.
+    #[cfg(not(feature = "xerbla"))]
+    |p, rout| {
+        use std::ffi::CStr;
+
+        let routine = CStr::from_ptr(rout).to_bytes();
+        xerbla_(routine.as_ptr() as *const c_char, &p, routine.len());
+    }
+}
+
+/// The character code of an option.
+#[inline]
+fn code<T: Into<char>>(value: T) -> c_char {
+    value.into() as c_char
+}
+
+/// Conjugate the elements of a vector in place.
+unsafe fn conjugate<T: Scalar>(n: blasint, x: *mut T, inc: blasint) {
+    let inc = (inc as isize).abs();
+    for i in 0..n.max(0) as isize {
+        let element = x.offset(i * inc);
+        *element = (*element).conj();
+    }
+}
+
+/// Copy the conjugate of a vector into a contiguous one.
+unsafe fn conjugated<T: Scalar>(n: blasint, x: *const T, inc: blasint) -> Vec<T> {
+    let (n, inc) = (n.max(0) as isize, inc as isize);
+    let first = if inc < 0 { (1 - n) * inc } else { 0 };
+    (0..n)
+        .map(|i| (*x.offset(first + i * inc)).conj())
+        .collect()
+}
+
+// The routines of Fortran BLAS are mocked, reading and writing only what the
+// real ones do, e.g., the stored triangle, so that the translations of
+// row-major calls are checked without a library.
+#[cfg(all(test, not(any(feature = "suffix-64_", feature = "suffix-_64"))))]
+mod tests {
+    use std::vec::Vec;
+
+    use libc::{c_char, c_float};
+
+    use super::Length;
+    use CBLAS_UPLO;
+    use {blasint, c_float_complex};
+    use {CblasConjTrans, CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans, CblasUpper};
+
+    type C = c_float_complex;
+
+    // A value in the triangle that is not stored, which has to be ignored.
+    const IGNORED: C = [100.0, -100.0];
+
+    fn add(a: C, b: C) -> C {
+        [a[0] + b[0], a[1] + b[1]]
+    }
+
+    fn mul(a: C, b: C) -> C {
+        [a[0] * b[0] - a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+    }
+
+    fn conj(a: C) -> C {
+        [a[0], -a[1]]
+    }
+
+    fn real(a: C) -> C {
+        [a[0], 0.0]
+    }
+
+    /// Return an element of a Hermitian matrix, dropping the imaginary part
+    /// if it is on the diagonal.
+    fn diagonal(i: usize, j: usize, element: C) -> C {
+        if i == j {
+            real(element)
+        } else {
+            element
+        }
+    }
+
+    /// Return whether the element (i, j) of a matrix is in the triangle given
+    /// by `uplo`.
+    fn stored(uplo: u8, i: usize, j: usize) -> bool {
+        if uplo == b'U' {
+            i <= j
+        } else {
+            i >= j
+        }
+    }
+
+    /// Return the element (i, j) of a Hermitian matrix given the triangle
+    /// stored at the given strides between rows and columns, ignoring the
+    /// imaginary part of the diagonal.
+    unsafe fn hermitian(uplo: u8, a: *const C, strides: (usize, usize), i: usize, j: usize) -> C {
+        let element = |i: usize, j: usize| *a.add(i * strides.0 + j * strides.1);
+        if i == j {
+            real(element(i, j))
+        } else if stored(uplo, i, j) {
+            element(i, j)
+        } else {
+            conj(element(j, i))
+        }
+    }
+
+    // No argument is invalid, so that this aborts.
+    #[cfg(not(feature = "xerbla"))]
+    #[no_mangle]
+    pub unsafe extern "C" fn xerbla_(_srname: *const c_char, info: *const blasint, _len: Length) {
+        panic!("argument {} is invalid", *info);
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn cgemv_(
+        trans: *const c_char,
+        m: *const blasint,
+        n: *const blasint,
+        alpha: *const C,
+        a: *const C,
+        lda: *const blasint,
+        x: *const C,
+        incx: *const blasint,
+        beta: *const C,
+        y: *mut C,
+        incy: *const blasint,
+        _trans_len: Length,
+    ) {
+        assert_eq!((*incx, *incy), (1, 1));
+        let (m, n, lda) = (*m as usize, *n as usize, *lda as usize);
+        let trans = *trans as u8;
+        let (rows, columns) = if trans == b'N' { (m, n) } else { (n, m) };
+        for i in 0..rows {
+            let mut sum = [0.0, 0.0];
+            for j in 0..columns {
+                let element = match trans {
+                    b'N' => *a.add(i + j * lda),
+                    b'T' => *a.add(j + i * lda),
+                    _ => conj(*a.add(j + i * lda)),
+                };
+                sum = add(sum, mul(element, *x.add(j)));
+            }
+            *y.add(i) = add(mul(*beta, *y.add(i)), mul(*alpha, sum));
+        }
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn chemv_(
+        uplo: *const c_char,
+        n: *const blasint,
+        alpha: *const C,
+        a: *const C,
+        lda: *const blasint,
+        x: *const C,
+        incx: *const blasint,
+        beta: *const C,
+        y: *mut C,
+        incy: *const blasint,
+        _uplo_len: Length,
+    ) {
+        assert_eq!((*incx, *incy), (1, 1));
+        let (n, lda) = (*n as usize, *lda as usize);
+        for i in 0..n {
+            let mut sum = [0.0, 0.0];
+            for j in 0..n {
+                let element = hermitian(*uplo as u8, a, (1, lda), i, j);
+                sum = add(sum, mul(element, *x.add(j)));
+            }
+            *y.add(i) = add(mul(*beta, *y.add(i)), mul(*alpha, sum));
+        }
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn cher_(
+        uplo: *const c_char,
+        n: *const blasint,
+        alpha: *const c_float,
+        x: *const C,
+        incx: *const blasint,
+        a: *mut C,
+        lda: *const blasint,
+        _uplo_len: Length,
+    ) {
+        assert_eq!(*incx, 1);
+        let (n, lda) = (*n as usize, *lda as usize);
+        for j in 0..n {
+            for i in (0..n).filter(|&i| stored(*uplo as u8, i, j)) {
+                let update = mul([*alpha, 0.0], mul(*x.add(i), conj(*x.add(j))));
+                let element = a.add(i + j * lda);
+                *element = diagonal(i, j, add(*element, update));
+            }
+        }
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn cherk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const blasint,
+        k: *const blasint,
+        alpha: *const c_float,
+        a: *const C,
+        lda: *const blasint,
+        beta: *const c_float,
+        c: *mut C,
+        ldc: *const blasint,
+        _uplo_len: Length,
+        _trans_len: Length,
+    ) {
+        let (n, k, lda, ldc) = (*n as usize, *k as usize, *lda as usize, *ldc as usize);
+        // The element (i, l) of op(A), of which C gets op(A) * op(A)^H.
+        let element = |i: usize, l: usize| match *trans as u8 {
+            b'N' => *a.add(i + l * lda),
+            _ => conj(*a.add(l + i * lda)),
+        };
+        for j in 0..n {
+            for i in (0..n).filter(|&i| stored(*uplo as u8, i, j)) {
+                let mut sum = [0.0, 0.0];
+                for l in 0..k {
+                    sum = add(sum, mul(element(i, l), conj(element(j, l))));
+                }
+                let element = c.add(i + j * ldc);
+                let scaled = mul([*beta, 0.0], *element);
+                *element = diagonal(i, j, add(mul([*alpha, 0.0], sum), scaled));
+            }
+        }
+    }
+
+    unsafe fn dot(n: *const blasint, x: *const C, y: *const C, conjugate: bool) -> C {
+        let mut sum = [0.0, 0.0];
+        for i in 0..*n as usize {
+            let x = if conjugate {
+                conj(*x.add(i))
+            } else {
+                *x.add(i)
+            };
+            sum = add(sum, mul(x, *y.add(i)));
+        }
+        sum
+    }
+
+    #[cfg(not(cblas_fortran_complex_pointer))]
+    #[no_mangle]
+    pub unsafe extern "C" fn cdotu_(
+        n: *const blasint,
+        x: *const C,
+        _incx: *const blasint,
+        y: *const C,
+        _incy: *const blasint,
+    ) -> super::Complex<c_float> {
+        let [re, im] = dot(n, x, y, false);
+        super::Complex { re, im }
+    }
+
+    #[cfg(not(cblas_fortran_complex_pointer))]
+    #[no_mangle]
+    pub unsafe extern "C" fn cdotc_(
+        n: *const blasint,
+        x: *const C,
+        _incx: *const blasint,
+        y: *const C,
+        _incy: *const blasint,
+    ) -> super::Complex<c_float> {
+        let [re, im] = dot(n, x, y, true);
+        super::Complex { re, im }
+    }
+
+    #[cfg(cblas_fortran_complex_pointer)]
+    #[no_mangle]
+    pub unsafe extern "C" fn cdotu_(
+        dotu: *mut C,
+        n: *const blasint,
+        x: *const C,
+        _incx: *const blasint,
+        y: *const C,
+        _incy: *const blasint,
+    ) {
+        *dotu = dot(n, x, y, false);
+    }
+
+    #[cfg(cblas_fortran_complex_pointer)]
+    #[no_mangle]
+    pub unsafe extern "C" fn cdotc_(
+        dotc: *mut C,
+        n: *const blasint,
+        x: *const C,
+        _incx: *const blasint,
+        y: *const C,
+        _incy: *const blasint,
+    ) {
+        *dotc = dot(n, x, y, true);
+    }
+
+    /// Return a row-major Hermitian matrix of order 3 of which only the
+    /// triangle given by `uplo` and the real part of the diagonal are set.
+    fn triangle(uplo: CBLAS_UPLO) -> Vec<C> {
+        let full = [
+            [[1.0, 7.0], [2.0, 1.0], [0.0, -3.0]],
+            [[2.0, -1.0], [4.0, 8.0], [5.0, 2.0]],
+            [[0.0, 3.0], [5.0, -2.0], [-6.0, 9.0]],
+        ];
+        let uplo = if uplo == CblasUpper { b'U' } else { b'L' };
+        let mut a = Vec::new();
+        for (i, row) in full.iter().enumerate() {
+            for (j, &element) in row.iter().enumerate() {
+                a.push(if stored(uplo, i, j) { element } else { IGNORED });
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn general_matrix_vector() {
+        // A is 2 x 3 with a leading dimension of 4.
+        let a = [
+            [1.0, 2.0],
+            [3.0, -1.0],
+            [0.0, 4.0],
+            IGNORED,
+            [-2.0, 1.0],
+            [5.0, 0.0],
+            [1.0, -3.0],
+            IGNORED,
+        ];
+        let (alpha, beta) = ([2.0, 1.0], [0.0, -1.0]);
+        for &trans in [CblasNoTrans, CblasTrans, CblasConjTrans].iter() {
+            let (rows, columns) = if trans == CblasNoTrans {
+                (2, 3)
+            } else {
+                (3, 2)
+            };
+            let x = [[1.0, -1.0], [2.0, 3.0], [-1.0, 2.0]];
+            let mut y = [[3.0, 1.0], [-2.0, 2.0], [1.0, 1.0]];
+            let mut expected = y;
+            for i in 0..rows {
+                let mut sum = [0.0, 0.0];
+                for j in 0..columns {
+                    let element = match trans {
+                        CblasNoTrans => a[i * 4 + j],
+                        CblasTrans => a[j * 4 + i],
+                        _ => conj(a[j * 4 + i]),
+                    };
+                    sum = add(sum, mul(element, x[j]));
+                }
+                expected[i] = add(mul(beta, y[i]), mul(alpha, sum));
+            }
+            unsafe {
+                ::cblas_cgemv(
+                    CblasRowMajor,
+                    trans,
+                    2,
+                    3,
+                    &alpha,
+                    a.as_ptr(),
+                    4,
+                    x.as_ptr(),
+                    1,
+                    &beta,
+                    y.as_mut_ptr(),
+                    1,
+                );
+            }
+            assert_eq!(y, expected, "{:?}", trans);
+        }
+    }
+
+    #[test]
+    fn hermitian_matrix_vector() {
+        let (alpha, beta) = ([1.0, -2.0], [2.0, 0.0]);
+        let x = [[1.0, 2.0], [-1.0, 0.0], [3.0, -1.0]];
+        for &uplo in [CblasUpper, CblasLower].iter() {
+            let a = triangle(uplo);
+            let code = if uplo == CblasUpper { b'U' } else { b'L' };
+            let mut y = [[1.0, 1.0], [0.0, -2.0], [4.0, 0.0]];
+            let mut expected = y;
+            for i in 0..3 {
+                let mut sum = [0.0, 0.0];
+                for (j, &x) in x.iter().enumerate() {
+                    let element = unsafe { hermitian(code, a.as_ptr(), (3, 1), i, j) };
+                    sum = add(sum, mul(element, x));
+                }
+                expected[i] = add(mul(beta, y[i]), mul(alpha, sum));
+            }
+            unsafe {
+                ::cblas_chemv(
+                    CblasRowMajor,
+                    uplo,
+                    3,
+                    &alpha,
+                    a.as_ptr(),
+                    3,
+                    x.as_ptr(),
+                    1,
+                    &beta,
+                    y.as_mut_ptr(),
+                    1,
+                );
+            }
+            assert_eq!(y, expected, "{:?}", uplo);
+        }
+    }
+
+    #[test]
+    fn hermitian_rank_1() {
+        let alpha = 2.0;
+        let x = [[1.0, 2.0], [-1.0, 0.0], [3.0, -1.0]];
+        for &uplo in [CblasUpper, CblasLower].iter() {
+            let mut a = triangle(uplo);
+            let code = if uplo == CblasUpper { b'U' } else { b'L' };
+            let mut expected = a.clone();
+            for i in 0..3 {
+                for j in (0..3).filter(|&j| stored(code, i, j)) {
+                    let update = mul([alpha, 0.0], mul(x[i], conj(x[j])));
+                    expected[i * 3 + j] = diagonal(i, j, add(a[i * 3 + j], update));
+                }
+            }
+            unsafe {
+                ::cblas_cher(
+                    CblasRowMajor,
+                    uplo,
+                    3,
+                    alpha,
+                    x.as_ptr(),
+                    1,
+                    a.as_mut_ptr(),
+                    3,
+                );
+            }
+            assert_eq!(a, expected, "{:?}", uplo);
+        }
+    }
+
+    #[test]
+    fn hermitian_rank_k() {
+        let (alpha, beta) = (2.0, -1.0);
+        // A is 3 x 2 for `NoTrans` and 2 x 3 for `ConjTrans`.
+        let a = [
+            [1.0, 2.0],
+            [-1.0, 0.0],
+            [3.0, -1.0],
+            [0.0, 1.0],
+            [2.0, 2.0],
+            [1.0, -2.0],
+        ];
+        let cases = [(CblasNoTrans, 2), (CblasConjTrans, 3)];
+        for &uplo in [CblasUpper, CblasLower].iter() {
+            for &(trans, lda) in cases.iter() {
+                let mut c = triangle(uplo);
+                let code = if uplo == CblasUpper { b'U' } else { b'L' };
+                let element = |i: usize, l: usize| {
+                    if trans == CblasNoTrans {
+                        a[i * 2 + l]
+                    } else {
+                        conj(a[l * 3 + i])
+                    }
+                };
+                let mut expected = c.clone();
+                for i in 0..3 {
+                    for j in (0..3).filter(|&j| stored(code, i, j)) {
+                        let mut sum = [0.0, 0.0];
+                        for l in 0..2 {
+                            sum = add(sum, mul(element(i, l), conj(element(j, l))));
+                        }
+                        let scaled = mul([beta, 0.0], c[i * 3 + j]);
+                        expected[i * 3 + j] = diagonal(i, j, add(mul([alpha, 0.0], sum), scaled));
+                    }
+                }
+                unsafe {
+                    ::cblas_cherk(
+                        CblasRowMajor,
+                        uplo,
+                        trans,
+                        3,
+                        2,
+                        alpha,
+                        a.as_ptr(),
+                        lda,
+                        beta,
+                        c.as_mut_ptr(),
+                        3,
+                    );
+                }
+                assert_eq!(c, expected, "{:?} {:?}", uplo, trans);
+            }
+        }
+    }
+
+    #[test]
+    fn complex_dot() {
+        let x = [[1.0, 2.0], [-1.0, 3.0]];
+        let y = [[2.0, -1.0], [0.0, 1.0]];
+        let (mut dotu, mut dotc) = ([0.0; 2], [0.0; 2]);
+        unsafe {
+            ::cblas_cdotu_sub(2, x.as_ptr(), 1, y.as_ptr(), 1, &mut dotu);
+            ::cblas_cdotc_sub(2, x.as_ptr(), 1, y.as_ptr(), 1, &mut dotc);
+        }
+        assert_eq!(dotu, [1.0, 2.0]);
+        assert_eq!(dotc, [3.0, -6.0]);
+    }
+}
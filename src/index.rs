@@ -0,0 +1,42 @@
+//! Indexing of vectors and matrices and validation of their dimensions, which
+//! the implementations written in Rust share.
+
+use {blasint, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use {CblasColMajor, CblasConjTrans, CblasNoTrans, CblasRowMajor, CblasTrans};
+
+/// The offset of the first element of a vector with n elements, which is the
+/// last one in memory if the increment is negative.
+#[inline]
+pub fn first(n: blasint, inc: blasint) -> isize {
+    if inc > 0 {
+        0
+    } else {
+        (1 - n as isize) * inc as isize
+    }
+}
+
+/// Whether a leading dimension is valid for a matrix with the given size.
+pub fn ld_valid(layout: CBLAS_LAYOUT, ld: blasint, rows: blasint, cols: blasint) -> bool {
+    match layout {
+        CblasColMajor => ld >= rows.max(1),
+        CblasRowMajor => ld >= cols.max(1),
+    }
+}
+
+/// The size of op(A) for a matrix A with the given size.
+#[inline]
+pub fn op_size<T>(trans: CBLAS_TRANSPOSE, rows: T, cols: T) -> (T, T) {
+    match trans {
+        CblasNoTrans => (rows, cols),
+        CblasTrans | CblasConjTrans => (cols, rows),
+    }
+}
+
+/// The offset of element (i, j) of a matrix.
+#[inline]
+pub fn offset(layout: CBLAS_LAYOUT, ld: isize, i: isize, j: isize) -> isize {
+    match layout {
+        CblasColMajor => i + j * ld,
+        CblasRowMajor => i * ld + j,
+    }
+}
@@ -2,6 +2,76 @@
 //!
 //! The usage of the package is explained [here][usage].
 //!
+//! ## Linking
+//!
+//! By default, the package does not link to any library, and a CBLAS provider
+//! has to be linked by other means, e.g., via `openblas-src`. Alternatively,
+//! one of the features `openblas`, `netlib`, `blis`, and `mkl` can be enabled,
+//! in which case the build script looks for the library using pkg-config,
+//! the `CBLAS_LIB_DIR` environment variable, and the standard library paths.
+//! The libraries to link can also be given explicitly as a comma-separated
+//! list in `CBLAS_LIB`. The `static` feature switches to static linking.
+//! With `ilp64` or a `suffix-*` feature, OpenBLAS is looked up under the names
+//! of its 64-bit integer builds, e.g., `openblas64_` for `suffix-64_`.
+//! With `openblas`, the [`openblas`] module provides the service functions of
+//! OpenBLAS, e.g., for setting the number of threads, and with `mkl`, the
+//! [`mkl`] module provides those of MKL, e.g., for conditional numerical
+//! reproducibility. Whichever library is linked, [`threads::ThreadLimit`]
+//! limits the number of threads used by OpenBLAS, MKL, or BLIS for a scope,
+//! and with `std`, [`backend::detect`] reports which library is linked and
+//! what it supports.
+//!
+//! The `vendored` feature compiles the reference implementations of CBLAS and
+//! BLAS from Netlib, which are bundled with the package as distributed with
+//! LAPACK 3.12.0 and require a C compiler and a Fortran compiler, `gfortran`
+//! unless `FC` says otherwise. Their license is in `vendor/lapack/LICENSE`.
+//! The feature does not support the `suffix-*` features.
+//!
+//! The `pure-rust` feature provides every function declared here by a
+//! reference implementation written in Rust, which requires neither a C
+//! compiler nor a library and is meant for testing and portability rather
+//! than speed. It implies `std`.
+//!
+//! The `fortran` feature provides every function declared here on top of a
+//! Fortran BLAS library, e.g., the reference one, which is then the only
+//! library to link. Row-major calls are translated into column-major ones as
+//! in the reference CBLAS. The feature implies `std`. Unless a backend is
+//! selected, the reference BLAS, `libblas`, is linked. The functions returning
+//! complex numbers are assumed to return them by value, as with gfortran,
+//! except for MKL, which writes them to a hidden pointer, and the convention
+//! can be chosen explicitly by setting `CBLAS_FORTRAN_COMPLEX` to `value` or
+//! `pointer` at build time.
+//!
+//! The `dynamic` feature provides [`CblasLibrary`], which loads a library at
+//! run time, e.g., `libopenblas.so`, `libmkl_rt.so`, or `libblis.so`, so that
+//! the choice can be made by the application rather than at build time.
+//!
+//! The [`safe`] module provides wrappers that take slices and check the
+//! arguments, returning an error instead of invoking undefined behavior.
+//!
+//! ## Features
+//!
+//! * `extensions` provides the [`extensions`] module with routines that are
+//!   not part of the standard but are provided by some libraries, e.g.,
+//!   `cblas_daxpby` and `cblas_domatcopy`, falling back to implementations
+//!   written in Rust if the linked library lacks them, as well as batched
+//!   wrappers in [`safe::batch`]. It implies `std`.
+//! * `ilp64` makes [`blasint`] a 64-bit integer for ILP64 builds of CBLAS.
+//! * `num-complex` provides conversions between `num_complex::Complex` and
+//!   the complex types of the package in the [`complex`] module.
+//! * `rayon` makes the fallbacks of the batched routines in the [`extensions`]
+//!   module solve the problems of a batch in parallel.
+//! * `serde` implements `Serialize` and `Deserialize` for the enumerations,
+//!   which are represented by the names of the variants without the `Cblas`
+//!   prefix, e.g., `"NoTrans"` and `"Upper"`.
+//! * `suffix-64_` and `suffix-_64` bind every function to the symbol with the
+//!   corresponding suffix (e.g., `cblas_dgemm64_`), which is how ILP64 builds
+//!   are usually distributed alongside LP64 ones. They imply `ilp64`.
+//! * `std` links the standard library, which the package otherwise does
+//!   without.
+//! * `xerbla` defines `cblas_xerbla` so that invalid arguments are reported
+//!   via the [`xerbla`] module instead of terminating the process.
+//!
 //! [cblas]: https://en.wikipedia.org/wiki/BLAS
 //! [usage]: https://blas-lapack-rs.github.io/usage
 
@@ -10,7 +80,54 @@
 
 extern crate libc;
 
-use libc::{c_char, c_double, c_float, c_int};
+#[cfg(feature = "dynamic")]
+extern crate libloading;
+
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
+
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(feature = "std")]
+#[macro_use]
+extern crate std;
+
+#[cfg(all(feature = "suffix-64_", feature = "suffix-_64"))]
+compile_error!("the features `suffix-64_` and `suffix-_64` are mutually exclusive");
+
+#[macro_use]
+mod macros;
+
+// Which of the functions are used depends on the features.
+#[allow(dead_code)]
+mod lookup;
+
+// Which of the operations are used depends on the features.
+#[cfg(any(feature = "pure-rust", feature = "extensions", feature = "fortran"))]
+#[allow(dead_code)]
+mod scalar;
+
+#[cfg(any(feature = "pure-rust", feature = "extensions"))]
+mod index;
+
+#[cfg(any(feature = "pure-rust", feature = "extensions"))]
+mod rotation;
+
+#[cfg(any(feature = "pure-rust", feature = "fortran", feature = "xerbla"))]
+#[macro_use]
+mod error;
+
+#[cfg(feature = "pure-rust")]
+mod pure;
+
+#[cfg(feature = "fortran")]
+mod fortran;
+
+use libc::{c_char, c_double, c_float};
 
 /// A complex number with 64-bit parts.
 #[allow(bad_style)]
@@ -20,10 +137,26 @@ pub type c_double_complex = [libc::c_double; 2];
 #[allow(bad_style)]
 pub type c_float_complex = [libc::c_float; 2];
 
-pub type CBLAS_INDEX = c_int;
+/// An integer used for dimensions, leading dimensions, and increments.
+///
+/// It is `c_int` by default and a 64-bit integer if the `ilp64` feature is
+/// enabled, which is needed to link against ILP64 builds of CBLAS.
+#[cfg(not(feature = "ilp64"))]
+#[allow(bad_style)]
+pub type blasint = libc::c_int;
+
+/// An integer used for dimensions, leading dimensions, and increments.
+///
+/// It is `c_int` by default and a 64-bit integer if the `ilp64` feature is
+/// enabled, which is needed to link against ILP64 builds of CBLAS.
+#[cfg(feature = "ilp64")]
+#[allow(bad_style)]
+pub type blasint = i64;
+
+pub type CBLAS_INDEX = blasint;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CBLAS_LAYOUT {
     CblasRowMajor = 101,
     CblasColMajor = 102,
@@ -31,7 +164,7 @@ pub enum CBLAS_LAYOUT {
 pub use self::CBLAS_LAYOUT::*;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CBLAS_TRANSPOSE {
     CblasNoTrans = 111,
     CblasTrans = 112,
@@ -40,7 +173,7 @@ pub enum CBLAS_TRANSPOSE {
 pub use self::CBLAS_TRANSPOSE::*;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CBLAS_UPLO {
     CblasUpper = 121,
     CblasLower = 122,
@@ -48,7 +181,7 @@ pub enum CBLAS_UPLO {
 pub use self::CBLAS_UPLO::*;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CBLAS_DIAG {
     CblasNonUnit = 131,
     CblasUnit = 132,
@@ -56,7 +189,7 @@ pub enum CBLAS_DIAG {
 pub use self::CBLAS_DIAG::*;
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CBLAS_SIDE {
     CblasLeft = 141,
     CblasRight = 142,
@@ -65,1478 +198,1560 @@ pub use self::CBLAS_SIDE::*;
 
 pub type CBLAS_ORDER = CBLAS_LAYOUT;
 
-// Level 1 (functions except for complex)
-extern "C" {
-    pub fn cblas_dcabs1(z: *const c_double_complex) -> c_double;
-    pub fn cblas_scabs1(c: *const c_float_complex) -> c_float;
-
-    pub fn cblas_sdsdot(
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-    ) -> c_float;
-    pub fn cblas_dsdot(
-        n: c_int,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-    ) -> c_double;
-    pub fn cblas_sdot(
-        n: c_int,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-    ) -> c_float;
-    pub fn cblas_ddot(
-        n: c_int,
-        x: *const c_double,
-        incx: c_int,
-        y: *const c_double,
-        incy: c_int,
-    ) -> c_double;
-
-    // Prefixes Z and C only
-    pub fn cblas_cdotu_sub(
-        n: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        dotu: *mut c_float_complex,
-    );
-    pub fn cblas_cdotc_sub(
-        n: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        dotc: *mut c_float_complex,
-    );
-
-    pub fn cblas_zdotu_sub(
-        n: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        dotu: *mut c_double_complex,
-    );
-    pub fn cblas_zdotc_sub(
-        n: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        dotc: *mut c_double_complex,
-    );
-
-    // Prefixes S, D, SC, and DZ
-    pub fn cblas_snrm2(n: c_int, x: *const c_float, incx: c_int) -> c_float;
-    pub fn cblas_sasum(n: c_int, x: *const c_float, incx: c_int) -> c_float;
-
-    pub fn cblas_dnrm2(n: c_int, x: *const c_double, incx: c_int) -> c_double;
-    pub fn cblas_dasum(n: c_int, x: *const c_double, incx: c_int) -> c_double;
-
-    pub fn cblas_scnrm2(n: c_int, x: *const c_float_complex, incx: c_int) -> c_float;
-    pub fn cblas_scasum(n: c_int, x: *const c_float_complex, incx: c_int) -> c_float;
-
-    pub fn cblas_dznrm2(n: c_int, x: *const c_double_complex, incx: c_int) -> c_double;
-    pub fn cblas_dzasum(n: c_int, x: *const c_double_complex, incx: c_int) -> c_double;
-
-    // Standard prefixes (S, D, C, and Z)
-    pub fn cblas_isamax(n: c_int, x: *const c_float, incx: c_int) -> CBLAS_INDEX;
-    pub fn cblas_idamax(n: c_int, x: *const c_double, incx: c_int) -> CBLAS_INDEX;
-    pub fn cblas_icamax(n: c_int, x: *const c_float_complex, incx: c_int) -> CBLAS_INDEX;
-    pub fn cblas_izamax(n: c_int, x: *const c_double_complex, incx: c_int) -> CBLAS_INDEX;
-}
+mod enums;
 
-// Level 1 (routines)
-extern "C" {
-    // Standard prefixes (S, D, C, and Z)
-    pub fn cblas_sswap(n: c_int, x: *mut c_float, incx: c_int, y: *mut c_float, incy: c_int);
-    pub fn cblas_scopy(n: c_int, x: *const c_float, incx: c_int, y: *mut c_float, incy: c_int);
-    pub fn cblas_saxpy(
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        y: *mut c_float,
-        incy: c_int,
-    );
-
-    pub fn cblas_dswap(n: c_int, x: *mut c_double, incx: c_int, y: *mut c_double, incy: c_int);
-    pub fn cblas_dcopy(n: c_int, x: *const c_double, incx: c_int, y: *mut c_double, incy: c_int);
-    pub fn cblas_daxpy(
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        y: *mut c_double,
-        incy: c_int,
-    );
-
-    pub fn cblas_cswap(
-        n: c_int,
-        x: *mut c_float_complex,
-        incx: c_int,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_ccopy(
-        n: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_caxpy(
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-
-    pub fn cblas_zswap(
-        n: c_int,
-        x: *mut c_double_complex,
-        incx: c_int,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zcopy(
-        n: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zaxpy(
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-
-    // Prefixes S and D only
-    pub fn cblas_srotg(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
-    pub fn cblas_srotmg(
-        d1: *mut c_float,
-        d2: *mut c_float,
-        b1: *mut c_float,
-        b2: c_float,
-        p: *mut c_float,
-    );
-    pub fn cblas_srot(
-        n: c_int,
-        x: *mut c_float,
-        incx: c_int,
-        y: *mut c_float,
-        incy: c_int,
-        c: c_float,
-        s: c_float,
-    );
-    pub fn cblas_srotm(
-        n: c_int,
-        x: *mut c_float,
-        incx: c_int,
-        y: *mut c_float,
-        incy: c_int,
-        p: *const c_float,
-    );
-
-    pub fn cblas_drotg(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
-    pub fn cblas_drotmg(
-        d1: *mut c_double,
-        d2: *mut c_double,
-        b1: *mut c_double,
-        b2: c_double,
-        p: *mut c_double,
-    );
-    pub fn cblas_drot(
-        n: c_int,
-        x: *mut c_double,
-        incx: c_int,
-        y: *mut c_double,
-        incy: c_int,
-        c: c_double,
-        s: c_double,
-    );
-    pub fn cblas_drotm(
-        n: c_int,
-        x: *mut c_double,
-        incx: c_int,
-        y: *mut c_double,
-        incy: c_int,
-        p: *const c_double,
-    );
-
-    // Prefixes S, D, C, Z, CS, and ZD
-    pub fn cblas_sscal(n: c_int, alpha: c_float, x: *mut c_float, incx: c_int);
-    pub fn cblas_dscal(n: c_int, alpha: c_double, x: *mut c_double, incx: c_int);
-    pub fn cblas_cscal(
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_zscal(
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_csscal(n: c_int, alpha: c_float, x: *mut c_float_complex, incx: c_int);
-    pub fn cblas_zdscal(n: c_int, alpha: c_double, x: *mut c_double_complex, incx: c_int);
-}
+pub use enums::InvalidEnumValue;
 
-// Level 2
-extern "C" {
-    // Standard prefixes (S, D, C, and Z)
-    pub fn cblas_sgemv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        x: *const c_float,
-        incx: c_int,
-        beta: c_float,
-        y: *mut c_float,
-        incy: c_int,
-    );
-    pub fn cblas_sgbmv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        kl: c_int,
-        ku: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        x: *const c_float,
-        incx: c_int,
-        beta: c_float,
-        y: *mut c_float,
-        incy: c_int,
-    );
-    pub fn cblas_strmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_float,
-        lda: c_int,
-        x: *mut c_float,
-        incx: c_int,
-    );
-    pub fn cblas_stbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_float,
-        lda: c_int,
-        x: *mut c_float,
-        incx: c_int,
-    );
-    pub fn cblas_stpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_float,
-        x: *mut c_float,
-        incx: c_int,
-    );
-    pub fn cblas_strsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_float,
-        lda: c_int,
-        x: *mut c_float,
-        incx: c_int,
-    );
-    pub fn cblas_stbsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_float,
-        lda: c_int,
-        x: *mut c_float,
-        incx: c_int,
-    );
-    pub fn cblas_stpsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_float,
-        x: *mut c_float,
-        incx: c_int,
-    );
-
-    pub fn cblas_dgemv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        x: *const c_double,
-        incx: c_int,
-        beta: c_double,
-        y: *mut c_double,
-        incy: c_int,
-    );
-    pub fn cblas_dgbmv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        kl: c_int,
-        ku: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        x: *const c_double,
-        incx: c_int,
-        beta: c_double,
-        y: *mut c_double,
-        incy: c_int,
-    );
-    pub fn cblas_dtrmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_double,
-        lda: c_int,
-        x: *mut c_double,
-        incx: c_int,
-    );
-    pub fn cblas_dtbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_double,
-        lda: c_int,
-        x: *mut c_double,
-        incx: c_int,
-    );
-    pub fn cblas_dtpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_double,
-        x: *mut c_double,
-        incx: c_int,
-    );
-    pub fn cblas_dtrsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_double,
-        lda: c_int,
-        x: *mut c_double,
-        incx: c_int,
-    );
-    pub fn cblas_dtbsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_double,
-        lda: c_int,
-        x: *mut c_double,
-        incx: c_int,
-    );
-    pub fn cblas_dtpsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_double,
-        x: *mut c_double,
-        incx: c_int,
-    );
-
-    pub fn cblas_cgemv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        beta: *const c_float_complex,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_cgbmv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        kl: c_int,
-        ku: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        beta: *const c_float_complex,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_ctrmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ctbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ctpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_float_complex,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ctrsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ctbsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ctpsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_float_complex,
-        x: *mut c_float_complex,
-        incx: c_int,
-    );
-
-    pub fn cblas_zgemv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        beta: *const c_double_complex,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zgbmv(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        kl: c_int,
-        ku: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        beta: *const c_double_complex,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_ztrmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ztbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ztpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_double_complex,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ztrsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ztbsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        k: c_int,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-    pub fn cblas_ztpsv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        n: c_int,
-        ap: *const c_double_complex,
-        x: *mut c_double_complex,
-        incx: c_int,
-    );
-
-    // Prefixes S and D only
-    pub fn cblas_ssymv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        x: *const c_float,
-        incx: c_int,
-        beta: c_float,
-        y: *mut c_float,
-        incy: c_int,
-    );
-    pub fn cblas_ssbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        k: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        x: *const c_float,
-        incx: c_int,
-        beta: c_float,
-        y: *mut c_float,
-        incy: c_int,
-    );
-    pub fn cblas_sspmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        ap: *const c_float,
-        x: *const c_float,
-        incx: c_int,
-        beta: c_float,
-        y: *mut c_float,
-        incy: c_int,
-    );
-    pub fn cblas_sger(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-        a: *mut c_float,
-        lda: c_int,
-    );
-    pub fn cblas_ssyr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        a: *mut c_float,
-        lda: c_int,
-    );
-    pub fn cblas_sspr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        ap: *mut c_float,
-    );
-    pub fn cblas_ssyr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-        a: *mut c_float,
-        lda: c_int,
-    );
-    pub fn cblas_sspr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float,
-        incx: c_int,
-        y: *const c_float,
-        incy: c_int,
-        a: *mut c_float,
-    );
-
-    pub fn cblas_dsymv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        x: *const c_double,
-        incx: c_int,
-        beta: c_double,
-        y: *mut c_double,
-        incy: c_int,
-    );
-    pub fn cblas_dsbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        k: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        x: *const c_double,
-        incx: c_int,
-        beta: c_double,
-        y: *mut c_double,
-        incy: c_int,
-    );
-    pub fn cblas_dspmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        ap: *const c_double,
-        x: *const c_double,
-        incx: c_int,
-        beta: c_double,
-        y: *mut c_double,
-        incy: c_int,
-    );
-    pub fn cblas_dger(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        y: *const c_double,
-        incy: c_int,
-        a: *mut c_double,
-        lda: c_int,
-    );
-    pub fn cblas_dsyr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        a: *mut c_double,
-        lda: c_int,
-    );
-    pub fn cblas_dspr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        ap: *mut c_double,
-    );
-    pub fn cblas_dsyr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        y: *const c_double,
-        incy: c_int,
-        a: *mut c_double,
-        lda: c_int,
-    );
-    pub fn cblas_dspr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double,
-        incx: c_int,
-        y: *const c_double,
-        incy: c_int,
-        a: *mut c_double,
-    );
-
-    // Prefixes C and Z only
-    pub fn cblas_chemv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        beta: *const c_float_complex,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_chbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        x: *const c_float_complex,
-        incx: c_int,
-        beta: *const c_float_complex,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_chpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_float_complex,
-        ap: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        beta: *const c_float_complex,
-        y: *mut c_float_complex,
-        incy: c_int,
-    );
-    pub fn cblas_cgeru(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        a: *mut c_float_complex,
-        lda: c_int,
-    );
-    pub fn cblas_cgerc(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        a: *mut c_float_complex,
-        lda: c_int,
-    );
-    pub fn cblas_cher(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float_complex,
-        incx: c_int,
-        a: *mut c_float_complex,
-        lda: c_int,
-    );
-    pub fn cblas_chpr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_float,
-        x: *const c_float_complex,
-        incx: c_int,
-        a: *mut c_float_complex,
-    );
-    pub fn cblas_cher2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        a: *mut c_float_complex,
-        lda: c_int,
-    );
-    pub fn cblas_chpr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_float_complex,
-        x: *const c_float_complex,
-        incx: c_int,
-        y: *const c_float_complex,
-        incy: c_int,
-        ap: *mut c_float_complex,
-    );
-
-    pub fn cblas_zhemv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        beta: *const c_double_complex,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zhbmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        x: *const c_double_complex,
-        incx: c_int,
-        beta: *const c_double_complex,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zhpmv(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_double_complex,
-        ap: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        beta: *const c_double_complex,
-        y: *mut c_double_complex,
-        incy: c_int,
-    );
-    pub fn cblas_zgeru(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        a: *mut c_double_complex,
-        lda: c_int,
-    );
-    pub fn cblas_zgerc(
-        layout: CBLAS_LAYOUT,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        a: *mut c_double_complex,
-        lda: c_int,
-    );
-    pub fn cblas_zher(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double_complex,
-        incx: c_int,
-        a: *mut c_double_complex,
-        lda: c_int,
-    );
-    pub fn cblas_zhpr(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: c_double,
-        x: *const c_double_complex,
-        incx: c_int,
-        a: *mut c_double_complex,
-    );
-    pub fn cblas_zher2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        a: *mut c_double_complex,
-        lda: c_int,
-    );
-    pub fn cblas_zhpr2(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        n: c_int,
-        alpha: *const c_double_complex,
-        x: *const c_double_complex,
-        incx: c_int,
-        y: *const c_double_complex,
-        incy: c_int,
-        ap: *mut c_double_complex,
-    );
-}
+// The declarations are passed to a callback macro, so that they can be expanded
+// both into the bindings and into other tables of functions.
+macro_rules! cblas_functions {
+    ($callback:ident) => {
+        $callback! {
+            // Level 1 (functions except for complex)
+            pub fn cblas_dcabs1(z: *const c_double_complex) -> c_double;
+            pub fn cblas_scabs1(c: *const c_float_complex) -> c_float;
 
-// Level 3
-extern "C" {
-    // Standard prefixes (S, D, C, and Z)
-    pub fn cblas_sgemm(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        transb: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        k: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        b: *const c_float,
-        ldb: c_int,
-        beta: c_float,
-        c: *mut c_float,
-        ldc: c_int,
-    );
-    pub fn cblas_ssymm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        b: *const c_float,
-        ldb: c_int,
-        beta: c_float,
-        c: *mut c_float,
-        ldc: c_int,
-    );
-    pub fn cblas_ssyrk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        beta: c_float,
-        c: *mut c_float,
-        ldc: c_int,
-    );
-    pub fn cblas_ssyr2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        b: *const c_float,
-        ldb: c_int,
-        beta: c_float,
-        c: *mut c_float,
-        ldc: c_int,
-    );
-    pub fn cblas_strmm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        b: *mut c_float,
-        ldb: c_int,
-    );
-    pub fn cblas_strsm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: c_float,
-        a: *const c_float,
-        lda: c_int,
-        b: *mut c_float,
-        ldb: c_int,
-    );
-
-    pub fn cblas_dgemm(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        transb: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        k: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        b: *const c_double,
-        ldb: c_int,
-        beta: c_double,
-        c: *mut c_double,
-        ldc: c_int,
-    );
-    pub fn cblas_dsymm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        b: *const c_double,
-        ldb: c_int,
-        beta: c_double,
-        c: *mut c_double,
-        ldc: c_int,
-    );
-    pub fn cblas_dsyrk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        beta: c_double,
-        c: *mut c_double,
-        ldc: c_int,
-    );
-    pub fn cblas_dsyr2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        b: *const c_double,
-        ldb: c_int,
-        beta: c_double,
-        c: *mut c_double,
-        ldc: c_int,
-    );
-    pub fn cblas_dtrmm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        b: *mut c_double,
-        ldb: c_int,
-    );
-    pub fn cblas_dtrsm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: c_double,
-        a: *const c_double,
-        lda: c_int,
-        b: *mut c_double,
-        ldb: c_int,
-    );
-
-    pub fn cblas_cgemm(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        transb: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *const c_float_complex,
-        ldb: c_int,
-        beta: *const c_float_complex,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_csymm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *const c_float_complex,
-        ldb: c_int,
-        beta: *const c_float_complex,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_csyrk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        beta: *const c_float_complex,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_csyr2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *const c_float_complex,
-        ldb: c_int,
-        beta: *const c_float_complex,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_ctrmm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *mut c_float_complex,
-        ldb: c_int,
-    );
-    pub fn cblas_ctrsm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *mut c_float_complex,
-        ldb: c_int,
-    );
-
-    pub fn cblas_zgemm(
-        layout: CBLAS_LAYOUT,
-        transa: CBLAS_TRANSPOSE,
-        transb: CBLAS_TRANSPOSE,
-        m: c_int,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *const c_double_complex,
-        ldb: c_int,
-        beta: *const c_double_complex,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_zsymm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *const c_double_complex,
-        ldb: c_int,
-        beta: *const c_double_complex,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_zsyrk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        beta: *const c_double_complex,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_zsyr2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *const c_double_complex,
-        ldb: c_int,
-        beta: *const c_double_complex,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_ztrmm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *mut c_double_complex,
-        ldb: c_int,
-    );
-    pub fn cblas_ztrsm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        transa: CBLAS_TRANSPOSE,
-        diag: CBLAS_DIAG,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *mut c_double_complex,
-        ldb: c_int,
-    );
-
-    // Prefixes C and Z only
-    pub fn cblas_chemm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *const c_float_complex,
-        ldb: c_int,
-        beta: *const c_float_complex,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_cherk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_float,
-        a: *const c_float_complex,
-        lda: c_int,
-        beta: c_float,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_cher2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_float_complex,
-        a: *const c_float_complex,
-        lda: c_int,
-        b: *const c_float_complex,
-        ldb: c_int,
-        beta: c_float,
-        c: *mut c_float_complex,
-        ldc: c_int,
-    );
-
-    pub fn cblas_zhemm(
-        layout: CBLAS_LAYOUT,
-        side: CBLAS_SIDE,
-        uplo: CBLAS_UPLO,
-        m: c_int,
-        n: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *const c_double_complex,
-        ldb: c_int,
-        beta: *const c_double_complex,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_zherk(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: c_double,
-        a: *const c_double_complex,
-        lda: c_int,
-        beta: c_double,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
-    pub fn cblas_zher2k(
-        layout: CBLAS_LAYOUT,
-        uplo: CBLAS_UPLO,
-        trans: CBLAS_TRANSPOSE,
-        n: c_int,
-        k: c_int,
-        alpha: *const c_double_complex,
-        a: *const c_double_complex,
-        lda: c_int,
-        b: *const c_double_complex,
-        ldb: c_int,
-        beta: c_double,
-        c: *mut c_double_complex,
-        ldc: c_int,
-    );
+            pub fn cblas_sdsdot(
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+            ) -> c_float;
+            pub fn cblas_dsdot(
+                n: blasint,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+            ) -> c_double;
+            pub fn cblas_sdot(
+                n: blasint,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+            ) -> c_float;
+            pub fn cblas_ddot(
+                n: blasint,
+                x: *const c_double,
+                incx: blasint,
+                y: *const c_double,
+                incy: blasint,
+            ) -> c_double;
+
+            // Prefixes Z and C only
+            pub fn cblas_cdotu_sub(
+                n: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                dotu: *mut c_float_complex,
+            );
+            pub fn cblas_cdotc_sub(
+                n: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                dotc: *mut c_float_complex,
+            );
+
+            pub fn cblas_zdotu_sub(
+                n: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                dotu: *mut c_double_complex,
+            );
+            pub fn cblas_zdotc_sub(
+                n: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                dotc: *mut c_double_complex,
+            );
+
+            // Prefixes S, D, SC, and DZ
+            pub fn cblas_snrm2(n: blasint, x: *const c_float, incx: blasint) -> c_float;
+            pub fn cblas_sasum(n: blasint, x: *const c_float, incx: blasint) -> c_float;
+
+            pub fn cblas_dnrm2(n: blasint, x: *const c_double, incx: blasint) -> c_double;
+            pub fn cblas_dasum(n: blasint, x: *const c_double, incx: blasint) -> c_double;
+
+            pub fn cblas_scnrm2(n: blasint, x: *const c_float_complex, incx: blasint) -> c_float;
+            pub fn cblas_scasum(n: blasint, x: *const c_float_complex, incx: blasint) -> c_float;
+
+            pub fn cblas_dznrm2(n: blasint, x: *const c_double_complex, incx: blasint) -> c_double;
+            pub fn cblas_dzasum(n: blasint, x: *const c_double_complex, incx: blasint) -> c_double;
+
+            // Standard prefixes (S, D, C, and Z)
+            pub fn cblas_isamax(n: blasint, x: *const c_float, incx: blasint) -> CBLAS_INDEX;
+            pub fn cblas_idamax(n: blasint, x: *const c_double, incx: blasint) -> CBLAS_INDEX;
+            pub fn cblas_icamax(
+                n: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+            ) -> CBLAS_INDEX;
+            pub fn cblas_izamax(
+                n: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+            ) -> CBLAS_INDEX;
+
+            // Level 1 (routines)
+            // Standard prefixes (S, D, C, and Z)
+            pub fn cblas_sswap(
+                n: blasint,
+                x: *mut c_float,
+                incx: blasint,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_scopy(
+                n: blasint,
+                x: *const c_float,
+                incx: blasint,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_saxpy(
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                y: *mut c_float,
+                incy: blasint,
+            );
+
+            pub fn cblas_dswap(
+                n: blasint,
+                x: *mut c_double,
+                incx: blasint,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dcopy(
+                n: blasint,
+                x: *const c_double,
+                incx: blasint,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_daxpy(
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                y: *mut c_double,
+                incy: blasint,
+            );
+
+            pub fn cblas_cswap(
+                n: blasint,
+                x: *mut c_float_complex,
+                incx: blasint,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_ccopy(
+                n: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_caxpy(
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+
+            pub fn cblas_zswap(
+                n: blasint,
+                x: *mut c_double_complex,
+                incx: blasint,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zcopy(
+                n: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zaxpy(
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+
+            // Prefixes S and D only
+            pub fn cblas_srotg(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+            pub fn cblas_srotmg(
+                d1: *mut c_float,
+                d2: *mut c_float,
+                b1: *mut c_float,
+                b2: c_float,
+                p: *mut c_float,
+            );
+            pub fn cblas_srot(
+                n: blasint,
+                x: *mut c_float,
+                incx: blasint,
+                y: *mut c_float,
+                incy: blasint,
+                c: c_float,
+                s: c_float,
+            );
+            pub fn cblas_srotm(
+                n: blasint,
+                x: *mut c_float,
+                incx: blasint,
+                y: *mut c_float,
+                incy: blasint,
+                p: *const c_float,
+            );
+
+            pub fn cblas_drotg(
+                a: *mut c_double,
+                b: *mut c_double,
+                c: *mut c_double,
+                s: *mut c_double,
+            );
+            pub fn cblas_drotmg(
+                d1: *mut c_double,
+                d2: *mut c_double,
+                b1: *mut c_double,
+                b2: c_double,
+                p: *mut c_double,
+            );
+            pub fn cblas_drot(
+                n: blasint,
+                x: *mut c_double,
+                incx: blasint,
+                y: *mut c_double,
+                incy: blasint,
+                c: c_double,
+                s: c_double,
+            );
+            pub fn cblas_drotm(
+                n: blasint,
+                x: *mut c_double,
+                incx: blasint,
+                y: *mut c_double,
+                incy: blasint,
+                p: *const c_double,
+            );
+
+            // Prefixes S, D, C, Z, CS, and ZD
+            pub fn cblas_sscal(n: blasint, alpha: c_float, x: *mut c_float, incx: blasint);
+            pub fn cblas_dscal(n: blasint, alpha: c_double, x: *mut c_double, incx: blasint);
+            pub fn cblas_cscal(
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_zscal(
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_csscal(n: blasint, alpha: c_float, x: *mut c_float_complex, incx: blasint);
+            pub fn cblas_zdscal(
+                n: blasint,
+                alpha: c_double,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+
+            // Level 2
+            // Standard prefixes (S, D, C, and Z)
+            pub fn cblas_sgemv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                x: *const c_float,
+                incx: blasint,
+                beta: c_float,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_sgbmv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                x: *const c_float,
+                incx: blasint,
+                beta: c_float,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_strmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_float,
+                lda: blasint,
+                x: *mut c_float,
+                incx: blasint,
+            );
+            pub fn cblas_stbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_float,
+                lda: blasint,
+                x: *mut c_float,
+                incx: blasint,
+            );
+            pub fn cblas_stpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_float,
+                x: *mut c_float,
+                incx: blasint,
+            );
+            pub fn cblas_strsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_float,
+                lda: blasint,
+                x: *mut c_float,
+                incx: blasint,
+            );
+            pub fn cblas_stbsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_float,
+                lda: blasint,
+                x: *mut c_float,
+                incx: blasint,
+            );
+            pub fn cblas_stpsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_float,
+                x: *mut c_float,
+                incx: blasint,
+            );
+
+            pub fn cblas_dgemv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                x: *const c_double,
+                incx: blasint,
+                beta: c_double,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dgbmv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                x: *const c_double,
+                incx: blasint,
+                beta: c_double,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dtrmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_double,
+                lda: blasint,
+                x: *mut c_double,
+                incx: blasint,
+            );
+            pub fn cblas_dtbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_double,
+                lda: blasint,
+                x: *mut c_double,
+                incx: blasint,
+            );
+            pub fn cblas_dtpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_double,
+                x: *mut c_double,
+                incx: blasint,
+            );
+            pub fn cblas_dtrsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_double,
+                lda: blasint,
+                x: *mut c_double,
+                incx: blasint,
+            );
+            pub fn cblas_dtbsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_double,
+                lda: blasint,
+                x: *mut c_double,
+                incx: blasint,
+            );
+            pub fn cblas_dtpsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_double,
+                x: *mut c_double,
+                incx: blasint,
+            );
+
+            pub fn cblas_cgemv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                beta: *const c_float_complex,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_cgbmv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                beta: *const c_float_complex,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_ctrmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ctbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ctpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_float_complex,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ctrsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ctbsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ctpsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_float_complex,
+                x: *mut c_float_complex,
+                incx: blasint,
+            );
+
+            pub fn cblas_zgemv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                beta: *const c_double_complex,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zgbmv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                beta: *const c_double_complex,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_ztrmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ztbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ztpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_double_complex,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ztrsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ztbsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                k: blasint,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+            pub fn cblas_ztpsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: blasint,
+                ap: *const c_double_complex,
+                x: *mut c_double_complex,
+                incx: blasint,
+            );
+
+            // Prefixes S and D only
+            pub fn cblas_ssymv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                x: *const c_float,
+                incx: blasint,
+                beta: c_float,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_ssbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                k: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                x: *const c_float,
+                incx: blasint,
+                beta: c_float,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_sspmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                ap: *const c_float,
+                x: *const c_float,
+                incx: blasint,
+                beta: c_float,
+                y: *mut c_float,
+                incy: blasint,
+            );
+            pub fn cblas_sger(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+                a: *mut c_float,
+                lda: blasint,
+            );
+            pub fn cblas_ssyr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                a: *mut c_float,
+                lda: blasint,
+            );
+            pub fn cblas_sspr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                ap: *mut c_float,
+            );
+            pub fn cblas_ssyr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+                a: *mut c_float,
+                lda: blasint,
+            );
+            pub fn cblas_sspr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float,
+                incx: blasint,
+                y: *const c_float,
+                incy: blasint,
+                a: *mut c_float,
+            );
+
+            pub fn cblas_dsymv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                x: *const c_double,
+                incx: blasint,
+                beta: c_double,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dsbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                k: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                x: *const c_double,
+                incx: blasint,
+                beta: c_double,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dspmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                ap: *const c_double,
+                x: *const c_double,
+                incx: blasint,
+                beta: c_double,
+                y: *mut c_double,
+                incy: blasint,
+            );
+            pub fn cblas_dger(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                y: *const c_double,
+                incy: blasint,
+                a: *mut c_double,
+                lda: blasint,
+            );
+            pub fn cblas_dsyr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                a: *mut c_double,
+                lda: blasint,
+            );
+            pub fn cblas_dspr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                ap: *mut c_double,
+            );
+            pub fn cblas_dsyr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                y: *const c_double,
+                incy: blasint,
+                a: *mut c_double,
+                lda: blasint,
+            );
+            pub fn cblas_dspr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double,
+                incx: blasint,
+                y: *const c_double,
+                incy: blasint,
+                a: *mut c_double,
+            );
+
+            // Prefixes C and Z only
+            pub fn cblas_chemv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                beta: *const c_float_complex,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_chbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                x: *const c_float_complex,
+                incx: blasint,
+                beta: *const c_float_complex,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_chpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_float_complex,
+                ap: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                beta: *const c_float_complex,
+                y: *mut c_float_complex,
+                incy: blasint,
+            );
+            pub fn cblas_cgeru(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                a: *mut c_float_complex,
+                lda: blasint,
+            );
+            pub fn cblas_cgerc(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                a: *mut c_float_complex,
+                lda: blasint,
+            );
+            pub fn cblas_cher(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float_complex,
+                incx: blasint,
+                a: *mut c_float_complex,
+                lda: blasint,
+            );
+            pub fn cblas_chpr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_float,
+                x: *const c_float_complex,
+                incx: blasint,
+                a: *mut c_float_complex,
+            );
+            pub fn cblas_cher2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                a: *mut c_float_complex,
+                lda: blasint,
+            );
+            pub fn cblas_chpr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_float_complex,
+                x: *const c_float_complex,
+                incx: blasint,
+                y: *const c_float_complex,
+                incy: blasint,
+                ap: *mut c_float_complex,
+            );
+
+            pub fn cblas_zhemv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                beta: *const c_double_complex,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zhbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                x: *const c_double_complex,
+                incx: blasint,
+                beta: *const c_double_complex,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zhpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_double_complex,
+                ap: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                beta: *const c_double_complex,
+                y: *mut c_double_complex,
+                incy: blasint,
+            );
+            pub fn cblas_zgeru(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                a: *mut c_double_complex,
+                lda: blasint,
+            );
+            pub fn cblas_zgerc(
+                layout: CBLAS_LAYOUT,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                a: *mut c_double_complex,
+                lda: blasint,
+            );
+            pub fn cblas_zher(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double_complex,
+                incx: blasint,
+                a: *mut c_double_complex,
+                lda: blasint,
+            );
+            pub fn cblas_zhpr(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: c_double,
+                x: *const c_double_complex,
+                incx: blasint,
+                a: *mut c_double_complex,
+            );
+            pub fn cblas_zher2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                a: *mut c_double_complex,
+                lda: blasint,
+            );
+            pub fn cblas_zhpr2(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: blasint,
+                alpha: *const c_double_complex,
+                x: *const c_double_complex,
+                incx: blasint,
+                y: *const c_double_complex,
+                incy: blasint,
+                ap: *mut c_double_complex,
+            );
+
+            // Level 3
+            // Standard prefixes (S, D, C, and Z)
+            pub fn cblas_sgemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                b: *const c_float,
+                ldb: blasint,
+                beta: c_float,
+                c: *mut c_float,
+                ldc: blasint,
+            );
+            pub fn cblas_ssymm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                b: *const c_float,
+                ldb: blasint,
+                beta: c_float,
+                c: *mut c_float,
+                ldc: blasint,
+            );
+            pub fn cblas_ssyrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                beta: c_float,
+                c: *mut c_float,
+                ldc: blasint,
+            );
+            pub fn cblas_ssyr2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                b: *const c_float,
+                ldb: blasint,
+                beta: c_float,
+                c: *mut c_float,
+                ldc: blasint,
+            );
+            pub fn cblas_strmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                b: *mut c_float,
+                ldb: blasint,
+            );
+            pub fn cblas_strsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: c_float,
+                a: *const c_float,
+                lda: blasint,
+                b: *mut c_float,
+                ldb: blasint,
+            );
+
+            pub fn cblas_dgemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                b: *const c_double,
+                ldb: blasint,
+                beta: c_double,
+                c: *mut c_double,
+                ldc: blasint,
+            );
+            pub fn cblas_dsymm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                b: *const c_double,
+                ldb: blasint,
+                beta: c_double,
+                c: *mut c_double,
+                ldc: blasint,
+            );
+            pub fn cblas_dsyrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                beta: c_double,
+                c: *mut c_double,
+                ldc: blasint,
+            );
+            pub fn cblas_dsyr2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                b: *const c_double,
+                ldb: blasint,
+                beta: c_double,
+                c: *mut c_double,
+                ldc: blasint,
+            );
+            pub fn cblas_dtrmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                b: *mut c_double,
+                ldb: blasint,
+            );
+            pub fn cblas_dtrsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: c_double,
+                a: *const c_double,
+                lda: blasint,
+                b: *mut c_double,
+                ldb: blasint,
+            );
+
+            pub fn cblas_cgemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *const c_float_complex,
+                ldb: blasint,
+                beta: *const c_float_complex,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_csymm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *const c_float_complex,
+                ldb: blasint,
+                beta: *const c_float_complex,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_csyrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                beta: *const c_float_complex,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_csyr2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *const c_float_complex,
+                ldb: blasint,
+                beta: *const c_float_complex,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_ctrmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *mut c_float_complex,
+                ldb: blasint,
+            );
+            pub fn cblas_ctrsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *mut c_float_complex,
+                ldb: blasint,
+            );
+
+            pub fn cblas_zgemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *const c_double_complex,
+                ldb: blasint,
+                beta: *const c_double_complex,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_zsymm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *const c_double_complex,
+                ldb: blasint,
+                beta: *const c_double_complex,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_zsyrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                beta: *const c_double_complex,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_zsyr2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *const c_double_complex,
+                ldb: blasint,
+                beta: *const c_double_complex,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_ztrmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *mut c_double_complex,
+                ldb: blasint,
+            );
+            pub fn cblas_ztrsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *mut c_double_complex,
+                ldb: blasint,
+            );
+
+            // Prefixes C and Z only
+            pub fn cblas_chemm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *const c_float_complex,
+                ldb: blasint,
+                beta: *const c_float_complex,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_cherk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_float,
+                a: *const c_float_complex,
+                lda: blasint,
+                beta: c_float,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_cher2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_float_complex,
+                a: *const c_float_complex,
+                lda: blasint,
+                b: *const c_float_complex,
+                ldb: blasint,
+                beta: c_float,
+                c: *mut c_float_complex,
+                ldc: blasint,
+            );
+
+            pub fn cblas_zhemm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: blasint,
+                n: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *const c_double_complex,
+                ldb: blasint,
+                beta: *const c_double_complex,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_zherk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: c_double,
+                a: *const c_double_complex,
+                lda: blasint,
+                beta: c_double,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+            pub fn cblas_zher2k(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: blasint,
+                k: blasint,
+                alpha: *const c_double_complex,
+                a: *const c_double_complex,
+                lda: blasint,
+                b: *const c_double_complex,
+                ldb: blasint,
+                beta: c_double,
+                c: *mut c_double_complex,
+                ldc: blasint,
+            );
+        }
+    };
 }
 
+cblas_functions!(extern_cblas);
+
+mod api;
+
+pub use api::CblasApi;
+
+#[cfg(feature = "dynamic")]
+mod dynamic;
+
+#[cfg(feature = "dynamic")]
+pub use dynamic::CblasLibrary;
+
+pub mod safe;
+
+#[cfg(feature = "std")]
+pub mod backend;
+
+#[cfg(feature = "num-complex")]
+pub mod complex;
+
+#[cfg(feature = "extensions")]
+pub mod extensions;
+
+#[cfg(feature = "mkl")]
+pub mod mkl;
+
+#[cfg(feature = "openblas")]
+pub mod openblas;
+
+pub mod threads;
+
+#[cfg(feature = "xerbla")]
+pub mod xerbla;
+
 extern "C" {
-    pub fn cblas_xerbla(p: c_int, rout: *const c_char, form: *const c_char, ...);
+    #[cfg_attr(feature = "suffix-64_", link_name = "cblas_xerbla64_")]
+    #[cfg_attr(feature = "suffix-_64", link_name = "cblas_xerbla_64")]
+    pub fn cblas_xerbla(p: blasint, rout: *const c_char, form: *const c_char, ...);
 }
@@ -0,0 +1,87 @@
+//! Lookup of functions among the loaded libraries at run time.
+
+#[cfg(all(unix, feature = "extensions"))]
+use core::mem;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The address of a function looked up at run time.
+pub struct Function {
+    symbol: &'static str,
+    address: AtomicUsize,
+}
+
+/// The address of a function that has not been looked up yet.
+const UNKNOWN: usize = 0;
+
+/// The address of a function that is not provided by any library.
+const MISSING: usize = 1;
+
+impl Function {
+    /// Create a function for a null-terminated symbol.
+    pub const fn new(symbol: &'static str) -> Function {
+        Function {
+            symbol,
+            address: AtomicUsize::new(UNKNOWN),
+        }
+    }
+
+    /// Return the address of the function if it is provided by a library.
+    ///
+    /// Concurrent callers may look the symbol up more than once, which is
+    /// harmless since they find the same address.
+    #[inline]
+    pub fn address(&self) -> Option<usize> {
+        self.checked_address(|_| true)
+    }
+
+    /// Return the address of the function if it is provided by a library and
+    /// passes the check, which is run after the lookup.
+    #[inline]
+    pub fn checked_address(&self, check: unsafe fn(usize) -> bool) -> Option<usize> {
+        let mut address = self.address.load(Ordering::Relaxed);
+        if address == UNKNOWN {
+            address = match lookup(self.symbol) {
+                0 => MISSING,
+                address if unsafe { check(address) } => address,
+                _ => MISSING,
+            };
+            self.address.store(address, Ordering::Relaxed);
+        }
+        match address {
+            MISSING => None,
+            address => Some(address),
+        }
+    }
+}
+
+/// Return the address of a null-terminated symbol among the loaded libraries,
+/// or zero if none provides it.
+#[cfg(unix)]
+pub fn lookup(symbol: &str) -> usize {
+    unsafe { libc::dlsym(libc::RTLD_DEFAULT, symbol.as_ptr() as *const libc::c_char) as usize }
+}
+
+#[cfg(not(unix))]
+pub fn lookup(_: &str) -> usize {
+    0
+}
+
+/// Return whether two addresses belong to the same loaded library.
+#[cfg(all(unix, feature = "extensions"))]
+pub fn same_library(a: usize, b: usize) -> bool {
+    unsafe {
+        let (mut info_a, mut info_b) = (
+            mem::zeroed::<libc::Dl_info>(),
+            mem::zeroed::<libc::Dl_info>(),
+        );
+        libc::dladdr(a as *const libc::c_void, &mut info_a) != 0
+            && libc::dladdr(b as *const libc::c_void, &mut info_b) != 0
+            && !info_a.dli_fbase.is_null()
+            && info_a.dli_fbase == info_b.dli_fbase
+    }
+}
+
+#[cfg(all(not(unix), feature = "extensions"))]
+pub fn same_library(_: usize, _: usize) -> bool {
+    false
+}
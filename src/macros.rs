@@ -0,0 +1,66 @@
+// Declares CBLAS functions in an `extern "C"` block, binding each of them to
+// the symbol with the suffix selected via the `suffix-*` features, if any.
+macro_rules! extern_cblas {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $ty:ty),* $(,)*) $(-> $ret:ty)*;
+    )*) => {
+        extern "C" {
+            $(
+                $(#[$attr])*
+                #[cfg_attr(feature = "suffix-64_", link_name = concat!(stringify!($name), "64_"))]
+                #[cfg_attr(feature = "suffix-_64", link_name = concat!(stringify!($name), "_64"))]
+                pub fn $name($($arg: $ty),*) $(-> $ret)*;
+            )*
+        }
+    };
+}
+
+// Defines functions exported under their CBLAS names, taking into account the
+// suffix selected via the `suffix-*` features, if any.
+#[allow(unused_macros)]
+macro_rules! export_cblas {
+    ($(
+        $(#[$attr:meta])*
+        pub unsafe extern "C" fn $name:ident(
+            $($(#[$arg_attr:meta])* $arg:ident: $ty:ty),* $(,)*
+        ) $(-> $ret:ty)* $body:block
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[cfg_attr(
+                not(any(feature = "suffix-64_", feature = "suffix-_64")),
+                no_mangle
+            )]
+            #[cfg_attr(feature = "suffix-64_", export_name = concat!(stringify!($name), "64_"))]
+            #[cfg_attr(feature = "suffix-_64", export_name = concat!(stringify!($name), "_64"))]
+            pub unsafe extern "C" fn $name($($(#[$arg_attr])* $arg: $ty),*) $(-> $ret)* $body
+        )*
+    };
+}
+
+// The name of the symbol of a function, which has the suffix selected via the
+// `suffix-*` features, if any, and is terminated by a null character.
+#[cfg(not(any(feature = "suffix-64_", feature = "suffix-_64")))]
+#[allow(unused_macros)]
+macro_rules! symbol {
+    ($name:ident) => {
+        concat!(stringify!($name), "\0")
+    };
+}
+
+#[cfg(feature = "suffix-64_")]
+#[allow(unused_macros)]
+macro_rules! symbol {
+    ($name:ident) => {
+        concat!(stringify!($name), "64_\0")
+    };
+}
+
+#[cfg(feature = "suffix-_64")]
+#[allow(unused_macros)]
+macro_rules! symbol {
+    ($name:ident) => {
+        concat!(stringify!($name), "_64\0")
+    };
+}
@@ -0,0 +1,277 @@
+//! Service functions of MKL.
+//!
+//! The module declares the functions of MKL controlling threading, verbose
+//! mode, conditional numerical reproducibility (CNR), and memory, and the one
+//! reporting the version, which the `mkl` feature links, and wraps them in
+//! safe functions. The C header defines the lowercase names as macros for the
+//! mixed-case symbols, e.g., `MKL_Set_Num_Threads_Local`, to which the
+//! functions are bound, since the lowercase symbols belong to the Fortran
+//! interface.
+//!
+//! CNR has to be set before any other function of MKL is called:
+//!
+//! ```no_run
+//! # extern crate cblas_sys;
+//! # fn main() {
+//! use cblas_sys::mkl::{self, Branch};
+//!
+//! mkl::cbwr_set(Branch::Compatible, true).unwrap();
+//! # }
+//! ```
+
+#[cfg(feature = "std")]
+use core::ffi::CStr;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::string::String;
+
+use libc::{c_char, c_int};
+
+extern "C" {
+    #[link_name = "MKL_Set_Num_Threads_Local"]
+    pub fn mkl_set_num_threads_local(nth: c_int) -> c_int;
+    #[link_name = "MKL_Get_Max_Threads"]
+    pub fn mkl_get_max_threads() -> c_int;
+    #[link_name = "MKL_Verbose"]
+    pub fn mkl_verbose(enable: c_int) -> c_int;
+    #[link_name = "MKL_Set_Dynamic"]
+    pub fn mkl_set_dynamic(flag: c_int);
+    #[link_name = "MKL_CBWR_Set"]
+    pub fn mkl_cbwr_set(settings: c_int) -> c_int;
+    #[link_name = "MKL_Free_Buffers"]
+    pub fn mkl_free_buffers();
+    #[link_name = "MKL_Get_Version_String"]
+    pub fn mkl_get_version_string(buffer: *mut c_char, len: c_int);
+}
+
+pub const MKL_CBWR_BRANCH_OFF: c_int = 1;
+pub const MKL_CBWR_AUTO: c_int = 2;
+pub const MKL_CBWR_COMPATIBLE: c_int = 3;
+pub const MKL_CBWR_SSE2: c_int = 4;
+pub const MKL_CBWR_SSSE3: c_int = 6;
+pub const MKL_CBWR_SSE4_1: c_int = 7;
+pub const MKL_CBWR_SSE4_2: c_int = 8;
+pub const MKL_CBWR_AVX: c_int = 9;
+pub const MKL_CBWR_AVX2: c_int = 10;
+pub const MKL_CBWR_AVX512_MIC: c_int = 11;
+pub const MKL_CBWR_AVX512: c_int = 12;
+pub const MKL_CBWR_AVX512_MIC_E1: c_int = 13;
+pub const MKL_CBWR_AVX512_E1: c_int = 14;
+pub const MKL_CBWR_STRICT: c_int = 0x10000;
+
+pub const MKL_CBWR_SUCCESS: c_int = 0;
+pub const MKL_CBWR_ERR_INVALID_SETTINGS: c_int = -1;
+pub const MKL_CBWR_ERR_INVALID_INPUT: c_int = -2;
+pub const MKL_CBWR_ERR_UNSUPPORTED_BRANCH: c_int = -3;
+pub const MKL_CBWR_ERR_UNKNOWN_BRANCH: c_int = -4;
+pub const MKL_CBWR_ERR_MODE_CHANGE_FAILURE: c_int = -8;
+
+/// The code path used for conditional numerical reproducibility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Branch {
+    /// No reproducibility.
+    Off,
+    /// The best path for the processor, reproducible on it.
+    Auto,
+    /// A path reproducible on all processors supported by MKL.
+    Compatible,
+    Sse2,
+    Ssse3,
+    Sse4_1,
+    Sse4_2,
+    Avx,
+    Avx2,
+    Avx512Mic,
+    Avx512,
+    Avx512MicE1,
+    Avx512E1,
+}
+
+impl Branch {
+    fn code(self) -> c_int {
+        match self {
+            Branch::Off => MKL_CBWR_BRANCH_OFF,
+            Branch::Auto => MKL_CBWR_AUTO,
+            Branch::Compatible => MKL_CBWR_COMPATIBLE,
+            Branch::Sse2 => MKL_CBWR_SSE2,
+            Branch::Ssse3 => MKL_CBWR_SSSE3,
+            Branch::Sse4_1 => MKL_CBWR_SSE4_1,
+            Branch::Sse4_2 => MKL_CBWR_SSE4_2,
+            Branch::Avx => MKL_CBWR_AVX,
+            Branch::Avx2 => MKL_CBWR_AVX2,
+            Branch::Avx512Mic => MKL_CBWR_AVX512_MIC,
+            Branch::Avx512 => MKL_CBWR_AVX512,
+            Branch::Avx512MicE1 => MKL_CBWR_AVX512_MIC_E1,
+            Branch::Avx512E1 => MKL_CBWR_AVX512_E1,
+        }
+    }
+}
+
+/// An error reported by `mkl_cbwr_set`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CbwrError {
+    /// The settings are invalid.
+    InvalidSettings,
+    /// The input is invalid.
+    InvalidInput,
+    /// The branch is not supported by the processor.
+    UnsupportedBranch,
+    /// The branch is unknown.
+    UnknownBranch,
+    /// The settings cannot be changed, e.g., since MKL has been used.
+    ModeChangeFailure,
+    /// Another error with the given code.
+    Other(c_int),
+}
+
+impl fmt::Display for CbwrError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CbwrError::InvalidSettings => write!(formatter, "the CNR settings are invalid"),
+            CbwrError::InvalidInput => write!(formatter, "the CNR input is invalid"),
+            CbwrError::UnsupportedBranch => {
+                write!(
+                    formatter,
+                    "the CNR branch is not supported by the processor"
+                )
+            }
+            CbwrError::UnknownBranch => write!(formatter, "the CNR branch is unknown"),
+            CbwrError::ModeChangeFailure => {
+                write!(
+                    formatter,
+                    "the CNR mode cannot be changed after MKL has been used"
+                )
+            }
+            CbwrError::Other(code) => write!(formatter, "CNR error {}", code),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for CbwrError {}
+
+/// The verbose mode, in which MKL prints information about every call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Verbose {
+    /// No information.
+    Off,
+    /// Information about the calls.
+    On,
+    /// Information about the calls, including the time that they took.
+    Timing,
+}
+
+/// Set the number of threads used by the calling thread, returning the
+/// previous number. Zero means that the global setting is used.
+pub fn set_num_threads_local(num_threads: usize) -> usize {
+    let num_threads = num_threads.min(c_int::MAX as usize) as c_int;
+    unsafe { mkl_set_num_threads_local(num_threads) }.max(0) as usize
+}
+
+/// Return the maximum number of threads used by the routines.
+pub fn max_threads() -> usize {
+    unsafe { mkl_get_max_threads() }.max(0) as usize
+}
+
+/// Set the verbose mode, returning the previous one, or `None` if MKL reports
+/// an unknown one.
+pub fn verbose(mode: Verbose) -> Option<Verbose> {
+    let code = match mode {
+        Verbose::Off => 0,
+        Verbose::On => 1,
+        Verbose::Timing => 2,
+    };
+    match unsafe { mkl_verbose(code) } {
+        0 => Some(Verbose::Off),
+        1 => Some(Verbose::On),
+        2 => Some(Verbose::Timing),
+        _ => None,
+    }
+}
+
+/// Set whether MKL may use fewer threads than requested.
+pub fn set_dynamic(dynamic: bool) {
+    unsafe { mkl_set_dynamic(dynamic as c_int) }
+}
+
+/// Set the code path for conditional numerical reproducibility, which has to
+/// be done before any other function of MKL is called. In the strict mode,
+/// reproducibility is also ensured for routines that do not ensure it by
+/// default.
+pub fn cbwr_set(branch: Branch, strict: bool) -> Result<(), CbwrError> {
+    let settings = if strict {
+        branch.code() | MKL_CBWR_STRICT
+    } else {
+        branch.code()
+    };
+    status(unsafe { mkl_cbwr_set(settings) })
+}
+
+/// Convert the status returned by `mkl_cbwr_set`.
+fn status(code: c_int) -> Result<(), CbwrError> {
+    match code {
+        MKL_CBWR_SUCCESS => Ok(()),
+        MKL_CBWR_ERR_INVALID_SETTINGS => Err(CbwrError::InvalidSettings),
+        MKL_CBWR_ERR_INVALID_INPUT => Err(CbwrError::InvalidInput),
+        MKL_CBWR_ERR_UNSUPPORTED_BRANCH => Err(CbwrError::UnsupportedBranch),
+        MKL_CBWR_ERR_UNKNOWN_BRANCH => Err(CbwrError::UnknownBranch),
+        MKL_CBWR_ERR_MODE_CHANGE_FAILURE => Err(CbwrError::ModeChangeFailure),
+        code => Err(CbwrError::Other(code)),
+    }
+}
+
+/// Free the memory that MKL allocated for internal buffers.
+pub fn free_buffers() {
+    unsafe { mkl_free_buffers() }
+}
+
+/// Return the description of MKL, which contains the version, e.g., `Intel(R)
+/// oneAPI Math Kernel Library Version 2024.0-Product Build 20231011 for
+/// Intel(R) 64 architecture applications`.
+#[cfg(feature = "std")]
+pub fn version_string() -> String {
+    let mut buffer = [0 as c_char; 256];
+    unsafe { mkl_get_version_string(buffer.as_mut_ptr(), buffer.len() as c_int - 1) };
+    let string = unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_string_lossy();
+    String::from(string.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{status, Branch, CbwrError, MKL_CBWR_STRICT, MKL_CBWR_SUCCESS};
+    use super::{MKL_CBWR_AUTO, MKL_CBWR_BRANCH_OFF, MKL_CBWR_COMPATIBLE};
+
+    #[test]
+    fn branch() {
+        let branches = [
+            (Branch::Off, MKL_CBWR_BRANCH_OFF),
+            (Branch::Auto, MKL_CBWR_AUTO),
+            (Branch::Compatible, MKL_CBWR_COMPATIBLE),
+            (Branch::Sse2, 4),
+            (Branch::Ssse3, 6),
+            (Branch::Sse4_1, 7),
+            (Branch::Sse4_2, 8),
+            (Branch::Avx, 9),
+            (Branch::Avx2, 10),
+            (Branch::Avx512Mic, 11),
+            (Branch::Avx512, 12),
+            (Branch::Avx512MicE1, 13),
+            (Branch::Avx512E1, 14),
+        ];
+        for &(branch, code) in branches.iter() {
+            assert_eq!(branch.code(), code, "{:?}", branch);
+            assert_eq!(branch.code() & MKL_CBWR_STRICT, 0, "{:?}", branch);
+        }
+    }
+
+    #[test]
+    fn cbwr_status() {
+        assert_eq!(status(MKL_CBWR_SUCCESS), Ok(()));
+        assert_eq!(status(-1), Err(CbwrError::InvalidSettings));
+        assert_eq!(status(-2), Err(CbwrError::InvalidInput));
+        assert_eq!(status(-3), Err(CbwrError::UnsupportedBranch));
+        assert_eq!(status(-4), Err(CbwrError::UnknownBranch));
+        assert_eq!(status(-8), Err(CbwrError::ModeChangeFailure));
+        assert_eq!(status(-5), Err(CbwrError::Other(-5)));
+    }
+}
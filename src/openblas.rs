@@ -0,0 +1,107 @@
+//! Runtime control of OpenBLAS.
+//!
+//! The module declares the service functions of OpenBLAS, which the
+//! `openblas` feature links, and wraps them in safe functions. For instance,
+//! the kernels selected for the processor can be logged, and the number of
+//! threads can be pinned around a hot loop:
+//!
+//! ```no_run
+//! # extern crate cblas_sys;
+//! # fn main() {
+//! use cblas_sys::openblas;
+//!
+//! println!("OpenBLAS kernels for {}", openblas::corename());
+//! let threads = openblas::num_threads();
+//! openblas::set_num_threads(1);
+//! // ...
+//! openblas::set_num_threads(threads);
+//! # }
+//! ```
+
+use core::ffi::CStr;
+use core::str;
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use libc::{c_char, c_int};
+
+extern_cblas! {
+    pub fn openblas_set_num_threads(num_threads: c_int);
+    pub fn openblas_get_num_threads() -> c_int;
+    pub fn openblas_get_num_procs() -> c_int;
+    pub fn openblas_get_config() -> *mut c_char;
+    pub fn openblas_get_corename() -> *mut c_char;
+    pub fn openblas_get_parallel() -> c_int;
+}
+
+/// The threading model that OpenBLAS was built with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Parallel {
+    /// A single thread.
+    Sequential,
+    /// Threads managed by OpenBLAS, e.g., POSIX threads.
+    Threads,
+    /// OpenMP.
+    OpenMp,
+}
+
+/// Set the number of threads used by the routines.
+pub fn set_num_threads(num_threads: usize) {
+    let num_threads = num_threads.min(c_int::MAX as usize) as c_int;
+    unsafe { openblas_set_num_threads(num_threads) }
+}
+
+/// Return the number of threads used by the routines.
+pub fn num_threads() -> usize {
+    unsafe { openblas_get_num_threads() }.max(0) as usize
+}
+
+/// Return the number of processors that OpenBLAS detected.
+pub fn num_procs() -> usize {
+    unsafe { openblas_get_num_procs() }.max(0) as usize
+}
+
+/// Return the configuration that OpenBLAS was built with, which starts with
+/// the version, e.g., `OpenBLAS 0.3.26 DYNAMIC_ARCH Haswell MAX_THREADS=64`.
+///
+/// OpenBLAS assembles the string in a static buffer on every call, which is
+/// hence copied while holding a lock that serializes the calls made via this
+/// function. Calls of `openblas_get_config` made otherwise may still race.
+#[cfg(feature = "std")]
+pub fn config() -> String {
+    static LOCK: Mutex<()> = Mutex::new(());
+    let _guard = LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    unsafe { String::from(string(openblas_get_config())) }
+}
+
+/// Return the name of the processor whose kernels OpenBLAS selected, e.g.,
+/// `Haswell`.
+pub fn corename() -> &'static str {
+    unsafe { string(openblas_get_corename()) }
+}
+
+/// Return the threading model that OpenBLAS was built with, or `None` if it
+/// reports an unknown one.
+pub fn parallel() -> Option<Parallel> {
+    match unsafe { openblas_get_parallel() } {
+        0 => Some(Parallel::Sequential),
+        1 => Some(Parallel::Threads),
+        2 => Some(Parallel::OpenMp),
+        _ => None,
+    }
+}
+
+/// Convert a string owned by OpenBLAS, stopping before the first invalid UTF-8
+/// sequence.
+unsafe fn string<'a>(pointer: *const c_char) -> &'a str {
+    if pointer.is_null() {
+        return "";
+    }
+    let bytes = CStr::from_ptr(pointer).to_bytes();
+    match str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(error) => str::from_utf8_unchecked(&bytes[..error.valid_up_to()]),
+    }
+}
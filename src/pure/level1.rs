@@ -0,0 +1,559 @@
+use libc::{c_double, c_float};
+
+use super::Vector;
+use rotation;
+use scalar::{Real, Scalar};
+use {blasint, c_double_complex, c_float_complex, CBLAS_INDEX};
+
+unsafe fn swap<T: Scalar>(n: blasint, x: *mut T, incx: blasint, y: *mut T, incy: blasint) {
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n as isize {
+        let t = x.get(i);
+        x.set(i, y.get(i));
+        y.set(i, t);
+    }
+}
+
+unsafe fn copy<T: Scalar>(n: blasint, x: *const T, incx: blasint, y: *mut T, incy: blasint) {
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n as isize {
+        y.set(i, x.get(i));
+    }
+}
+
+unsafe fn axpy<T: Scalar>(
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    y: *mut T,
+    incy: blasint,
+) {
+    if alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n as isize {
+        y.set(i, y.get(i).add(alpha.mul(x.get(i))));
+    }
+}
+
+unsafe fn scal<T: Scalar, F: Fn(T) -> T>(n: blasint, x: *mut T, incx: blasint, f: F) {
+    if incx <= 0 {
+        return;
+    }
+    let x = Vector::new(x, n, incx);
+    for i in 0..n as isize {
+        x.set(i, f(x.get(i)));
+    }
+}
+
+unsafe fn dot<T: Scalar>(
+    n: blasint,
+    x: *const T,
+    incx: blasint,
+    y: *const T,
+    incy: blasint,
+    conj: bool,
+) -> T {
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    let mut sum = T::zero();
+    for i in 0..n as isize {
+        sum = sum.add(x.get(i).conj_if(conj).mul(y.get(i)));
+    }
+    sum
+}
+
+// The Euclidean norm computed with scaling to avoid overflow and underflow.
+unsafe fn nrm2<T: Scalar>(n: blasint, x: *const T, incx: blasint) -> T::Real {
+    if n <= 0 || incx <= 0 {
+        return T::Real::ZERO;
+    }
+    let x = Vector::new(x, n, incx);
+    let (mut scale, mut ssq) = (T::Real::ZERO, T::Real::ONE);
+    for i in 0..n as isize {
+        for &value in x.get(i).parts().iter() {
+            if value == T::Real::ZERO {
+                continue;
+            }
+            let value = value.abs();
+            if scale < value {
+                ssq = T::Real::ONE + ssq * (scale / value) * (scale / value);
+                scale = value;
+            } else {
+                ssq = ssq + (value / scale) * (value / scale);
+            }
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+unsafe fn asum<T: Scalar>(n: blasint, x: *const T, incx: blasint) -> T::Real {
+    if n <= 0 || incx <= 0 {
+        return T::Real::ZERO;
+    }
+    let x = Vector::new(x, n, incx);
+    let mut sum = T::Real::ZERO;
+    for i in 0..n as isize {
+        sum = sum + x.get(i).abs1();
+    }
+    sum
+}
+
+unsafe fn iamax<T: Scalar>(n: blasint, x: *const T, incx: blasint) -> CBLAS_INDEX {
+    if n <= 0 || incx <= 0 {
+        return 0;
+    }
+    let x = Vector::new(x, n, incx);
+    let (mut index, mut max) = (0, x.get(0).abs1());
+    for i in 1..n as isize {
+        if x.get(i).abs1() > max {
+            index = i;
+            max = x.get(i).abs1();
+        }
+    }
+    index as CBLAS_INDEX
+}
+
+unsafe fn rot<T: Real>(n: blasint, x: *mut T, incx: blasint, y: *mut T, incy: blasint, c: T, s: T) {
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n as isize {
+        let (u, v) = (x.get(i), y.get(i));
+        x.set(i, c * u + s * v);
+        y.set(i, c * v - s * u);
+    }
+}
+
+unsafe fn rotm<T: Real>(
+    n: blasint,
+    x: *mut T,
+    incx: blasint,
+    y: *mut T,
+    incy: blasint,
+    p: *const T,
+) {
+    let flag = *p;
+    if n <= 0 || flag == -(T::ONE + T::ONE) {
+        return;
+    }
+    let (mut h11, mut h21, mut h12, mut h22) =
+        (*p.offset(1), *p.offset(2), *p.offset(3), *p.offset(4));
+    if flag == T::ZERO {
+        h11 = T::ONE;
+        h22 = T::ONE;
+    } else if flag > T::ZERO {
+        h21 = -T::ONE;
+        h12 = T::ONE;
+    }
+    let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+    for i in 0..n as isize {
+        let (w, z) = (x.get(i), y.get(i));
+        x.set(i, w * h11 + z * h12);
+        y.set(i, w * h21 + z * h22);
+    }
+}
+
+unsafe fn rotg<T: Real>(a: *mut T, b: *mut T, c: *mut T, s: *mut T) {
+    let roe = if (*a).abs() > (*b).abs() { *a } else { *b };
+    let scale = (*a).abs() + (*b).abs();
+    let (r, z);
+    if scale == T::ZERO {
+        *c = T::ONE;
+        *s = T::ZERO;
+        r = T::ZERO;
+        z = T::ZERO;
+    } else {
+        let (p, q) = (*a / scale, *b / scale);
+        let mut value = scale * (p * p + q * q).sqrt();
+        if roe < T::ZERO {
+            value = -value;
+        }
+        r = value;
+        *c = *a / r;
+        *s = *b / r;
+        z = if (*a).abs() > (*b).abs() {
+            *s
+        } else if *c != T::ZERO {
+            T::ONE / *c
+        } else {
+            T::ONE
+        };
+    }
+    *a = r;
+    *b = z;
+}
+
+unsafe fn rotmg<T: Real>(d1: *mut T, d2: *mut T, b1: *mut T, b2: T, p: *mut T, gam: T, rgamsq: T) {
+    let gamsq = gam * gam;
+    let (mut h11, mut h12, mut h21, mut h22) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+    let mut flag;
+    if *d1 < T::ZERO {
+        flag = -T::ONE;
+        *d1 = T::ZERO;
+        *d2 = T::ZERO;
+        *b1 = T::ZERO;
+    } else {
+        let p2 = *d2 * b2;
+        if p2 == T::ZERO {
+            *p = -(T::ONE + T::ONE);
+            return;
+        }
+        let p1 = *d1 * *b1;
+        let q2 = p2 * b2;
+        let q1 = p1 * *b1;
+        if q1.abs() > q2.abs() {
+            h21 = -b2 / *b1;
+            h12 = p2 / p1;
+            let u = T::ONE - h12 * h21;
+            if u > T::ZERO {
+                flag = T::ZERO;
+                *d1 = *d1 / u;
+                *d2 = *d2 / u;
+                *b1 = *b1 * u;
+            } else {
+                flag = -T::ONE;
+                h11 = T::ZERO;
+                h12 = T::ZERO;
+                h21 = T::ZERO;
+                h22 = T::ZERO;
+                *d1 = T::ZERO;
+                *d2 = T::ZERO;
+                *b1 = T::ZERO;
+            }
+        } else if q2 < T::ZERO {
+            flag = -T::ONE;
+            *d1 = T::ZERO;
+            *d2 = T::ZERO;
+            *b1 = T::ZERO;
+        } else {
+            flag = T::ONE;
+            h11 = p1 / p2;
+            h22 = *b1 / b2;
+            let u = T::ONE + h11 * h22;
+            let t = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = t;
+            *b1 = b2 * u;
+        }
+        if *d1 != T::ZERO {
+            while *d1 <= rgamsq || *d1 >= gamsq {
+                if flag == T::ZERO {
+                    h11 = T::ONE;
+                    h22 = T::ONE;
+                } else if flag > T::ZERO {
+                    h21 = -T::ONE;
+                    h12 = T::ONE;
+                }
+                flag = -T::ONE;
+                if *d1 <= rgamsq {
+                    *d1 = *d1 * gamsq;
+                    *b1 = *b1 / gam;
+                    h11 = h11 / gam;
+                    h12 = h12 / gam;
+                } else {
+                    *d1 = *d1 / gamsq;
+                    *b1 = *b1 * gam;
+                    h11 = h11 * gam;
+                    h12 = h12 * gam;
+                }
+            }
+        }
+        if *d2 != T::ZERO {
+            while (*d2).abs() <= rgamsq || (*d2).abs() >= gamsq {
+                if flag == T::ZERO {
+                    h11 = T::ONE;
+                    h22 = T::ONE;
+                } else if flag > T::ZERO {
+                    h21 = -T::ONE;
+                    h12 = T::ONE;
+                }
+                flag = -T::ONE;
+                if (*d2).abs() <= rgamsq {
+                    *d2 = *d2 * gamsq;
+                    h21 = h21 / gam;
+                    h22 = h22 / gam;
+                } else {
+                    *d2 = *d2 / gamsq;
+                    h21 = h21 * gam;
+                    h22 = h22 * gam;
+                }
+            }
+        }
+    }
+    if flag < T::ZERO {
+        *p.offset(1) = h11;
+        *p.offset(2) = h21;
+        *p.offset(3) = h12;
+        *p.offset(4) = h22;
+    } else if flag == T::ZERO {
+        *p.offset(2) = h21;
+        *p.offset(3) = h12;
+    } else {
+        *p.offset(1) = h11;
+        *p.offset(4) = h22;
+    }
+    *p = flag;
+}
+
+macro_rules! standard {
+    ($t:ty, $real:ty, $swap:ident, $copy:ident, $axpy:ident, $scal:ident, $nrm2:ident, $asum:ident, $iamax:ident) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $swap(n: blasint, x: *mut $t, incx: blasint, y: *mut $t, incy: blasint) {
+                swap(n, x, incx, y, incy)
+            }
+
+            pub unsafe extern "C" fn $copy(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                copy(n, x, incx, y, incy)
+            }
+
+            pub unsafe extern "C" fn $axpy(
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                axpy(n, <$t>::load(alpha), x, incx, y, incy)
+            }
+
+            pub unsafe extern "C" fn $scal(n: blasint, alpha: <$t as Scalar>::Arg, x: *mut $t, incx: blasint) {
+                let alpha = <$t>::load(alpha);
+                scal(n, x, incx, |value| alpha.mul(value))
+            }
+
+            pub unsafe extern "C" fn $nrm2(n: blasint, x: *const $t, incx: blasint) -> $real {
+                nrm2(n, x, incx)
+            }
+
+            pub unsafe extern "C" fn $asum(n: blasint, x: *const $t, incx: blasint) -> $real {
+                asum(n, x, incx)
+            }
+
+            pub unsafe extern "C" fn $iamax(n: blasint, x: *const $t, incx: blasint) -> CBLAS_INDEX {
+                iamax(n, x, incx)
+            }
+        }
+    };
+}
+
+standard!(
+    c_float,
+    c_float,
+    cblas_sswap,
+    cblas_scopy,
+    cblas_saxpy,
+    cblas_sscal,
+    cblas_snrm2,
+    cblas_sasum,
+    cblas_isamax
+);
+standard!(
+    c_double,
+    c_double,
+    cblas_dswap,
+    cblas_dcopy,
+    cblas_daxpy,
+    cblas_dscal,
+    cblas_dnrm2,
+    cblas_dasum,
+    cblas_idamax
+);
+standard!(
+    c_float_complex,
+    c_float,
+    cblas_cswap,
+    cblas_ccopy,
+    cblas_caxpy,
+    cblas_cscal,
+    cblas_scnrm2,
+    cblas_scasum,
+    cblas_icamax
+);
+standard!(
+    c_double_complex,
+    c_double,
+    cblas_zswap,
+    cblas_zcopy,
+    cblas_zaxpy,
+    cblas_zscal,
+    cblas_dznrm2,
+    cblas_dzasum,
+    cblas_izamax
+);
+
+macro_rules! real {
+    ($t:ty, $dot:ident, $rotg:ident, $rotmg:ident, $rot:ident, $rotm:ident, $rgamsq:expr) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $dot(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+                dot(n, x, incx, y, incy, false)
+            }
+
+            pub unsafe extern "C" fn $rotg(a: *mut $t, b: *mut $t, c: *mut $t, s: *mut $t) {
+                rotg(a, b, c, s)
+            }
+
+            pub unsafe extern "C" fn $rotmg(d1: *mut $t, d2: *mut $t, b1: *mut $t, b2: $t, p: *mut $t) {
+                rotmg(d1, d2, b1, b2, p, 4096.0, $rgamsq)
+            }
+
+            pub unsafe extern "C" fn $rot(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                c: $t,
+                s: $t,
+            ) {
+                rot(n, x, incx, y, incy, c, s)
+            }
+
+            pub unsafe extern "C" fn $rotm(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                p: *const $t,
+            ) {
+                rotm(n, x, incx, y, incy, p)
+            }
+        }
+    };
+}
+
+real!(
+    c_float,
+    cblas_sdot,
+    cblas_srotg,
+    cblas_srotmg,
+    cblas_srot,
+    cblas_srotm,
+    5.960_464_5e-8
+);
+real!(
+    c_double,
+    cblas_ddot,
+    cblas_drotg,
+    cblas_drotmg,
+    cblas_drot,
+    cblas_drotm,
+    5.960_464_5e-8
+);
+
+macro_rules! complex {
+    (
+        $t:ty,
+        $real:ty,
+        $dotu:ident,
+        $dotc:ident,
+        $scal:ident,
+        $abs1:ident,
+        $rotg:ident,
+        $rot:ident
+    ) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $dotu(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                dotu: *mut $t,
+            ) {
+                *dotu = dot(n, x, incx, y, incy, false);
+            }
+
+            pub unsafe extern "C" fn $dotc(
+                n: blasint,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                dotc: *mut $t,
+            ) {
+                *dotc = dot(n, x, incx, y, incy, true);
+            }
+
+            pub unsafe extern "C" fn $scal(n: blasint, alpha: $real, x: *mut $t, incx: blasint) {
+                scal(n, x, incx, |value: $t| value.scale(alpha))
+            }
+
+            pub unsafe extern "C" fn $abs1(z: *const $t) -> $real {
+                (*z).abs1()
+            }
+
+            pub unsafe extern "C" fn $rotg(a: *mut $t, b: *mut $t, c: *mut $real, s: *mut $t) {
+                rotation::rotg(a, b, c, s)
+            }
+
+            pub unsafe extern "C" fn $rot(
+                n: blasint,
+                x: *mut $t,
+                incx: blasint,
+                y: *mut $t,
+                incy: blasint,
+                c: $real,
+                s: $real,
+            ) {
+                rotation::rot(n, x, incx, y, incy, c, s)
+            }
+        }
+    };
+}
+
+complex!(
+    c_float_complex,
+    c_float,
+    cblas_cdotu_sub,
+    cblas_cdotc_sub,
+    cblas_csscal,
+    cblas_scabs1,
+    cblas_crotg,
+    cblas_csrot
+);
+complex!(
+    c_double_complex,
+    c_double,
+    cblas_zdotu_sub,
+    cblas_zdotc_sub,
+    cblas_zdscal,
+    cblas_dcabs1,
+    cblas_zrotg,
+    cblas_zdrot
+);
+
+export_cblas! {
+    pub unsafe extern "C" fn cblas_dsdot(
+        n: blasint,
+        x: *const c_float,
+        incx: blasint,
+        y: *const c_float,
+        incy: blasint,
+    ) -> c_double {
+        let (x, y) = (Vector::new(x, n, incx), Vector::new(y, n, incy));
+        let mut sum = 0.0;
+        for i in 0..n as isize {
+            sum += x.get(i) as c_double * y.get(i) as c_double;
+        }
+        sum
+    }
+
+    pub unsafe extern "C" fn cblas_sdsdot(
+        n: blasint,
+        alpha: c_float,
+        x: *const c_float,
+        incx: blasint,
+        y: *const c_float,
+        incy: blasint,
+    ) -> c_float {
+        (alpha as c_double + cblas_dsdot(n, x, incx, y, incy)) as c_float
+    }
+}
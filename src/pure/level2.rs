@@ -0,0 +1,868 @@
+use libc::{c_double, c_float, c_int};
+
+use super::{Matrix, Operator, Storage, Triangle, Vector};
+use index::op_size;
+use scalar::Scalar;
+use {blasint, c_double_complex, c_float_complex, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+use {CblasColMajor, CblasNoTrans, CblasRowMajor};
+
+/// y := beta * y, where y is not read if beta is zero.
+pub(super) unsafe fn scale<T: Scalar>(n: isize, beta: T, y: Vector<T>) {
+    if beta.is_one() {
+        return;
+    }
+    for i in 0..n {
+        let value = if beta.is_zero() {
+            T::zero()
+        } else {
+            beta.mul(y.get(i))
+        };
+        y.set(i, value);
+    }
+}
+
+/// y := alpha * op(A) * x + beta * y for a general matrix A or a general
+/// band matrix A with kl subdiagonals and ku superdiagonals.
+unsafe fn gemv<T: Scalar>(
+    layout: CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    m: blasint,
+    n: blasint,
+    band: Option<(blasint, blasint)>,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+) {
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+    let (rows, cols) = op_size(trans, m, n);
+    let (kl, ku) = band.unwrap_or((m - 1, n - 1));
+    let (below, above) = match trans {
+        CblasNoTrans => (kl as isize, ku as isize),
+        _ => (ku as isize, kl as isize),
+    };
+    let (x, y) = (Vector::new(x, cols, incx), Vector::new(y, rows, incy));
+    let (rows, cols) = (rows as isize, cols as isize);
+    scale(rows, beta, y);
+    if alpha.is_zero() {
+        return;
+    }
+    let get = |i: isize, j: isize| {
+        let (i, j) = match trans {
+            CblasNoTrans => (i, j),
+            _ => (j, i),
+        };
+        let offset = match (band, layout) {
+            (None, CblasColMajor) => i + j * lda as isize,
+            (None, CblasRowMajor) => i * lda as isize + j,
+            (Some((_, ku)), CblasColMajor) => ku as isize + i - j + j * lda as isize,
+            (Some((kl, _)), CblasRowMajor) => i * lda as isize + kl as isize + j - i,
+        };
+        let value = *a.offset(offset);
+        value.conj_if(matches!(trans, ::CblasConjTrans))
+    };
+    for i in 0..rows {
+        let mut t = T::zero();
+        for j in (i - below).max(0)..(i + above + 1).min(cols) {
+            t = t.add(get(i, j).mul(x.get(j)));
+        }
+        y.set(i, y.get(i).add(alpha.mul(t)));
+    }
+}
+
+/// y := alpha * A * x + beta * y for a symmetric or Hermitian matrix A.
+unsafe fn symv<T: Scalar>(
+    a: Triangle<T>,
+    herm: bool,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+) {
+    let n = a.n;
+    if n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+    let (x, y) = (
+        Vector::new(x, n as blasint, incx),
+        Vector::new(y, n as blasint, incy),
+    );
+    scale(n, beta, y);
+    if alpha.is_zero() {
+        return;
+    }
+    let w = a.width();
+    for i in 0..n {
+        let mut t = T::zero();
+        for j in (i - w).max(0)..(i + w + 1).min(n) {
+            t = t.add(a.get_symmetric(herm, i, j).mul(x.get(j)));
+        }
+        y.set(i, y.get(i).add(alpha.mul(t)));
+    }
+}
+
+/// x := op(A) * x for a triangular matrix A.
+pub(super) unsafe fn trmv<T: Scalar>(a: Operator<T>, x: Vector<T>) {
+    let (n, w) = (a.matrix.n, a.matrix.width());
+    if a.upper() {
+        for i in 0..n {
+            let mut t = T::zero();
+            for j in i..(i + w + 1).min(n) {
+                t = t.add(a.get(i, j).mul(x.get(j)));
+            }
+            x.set(i, t);
+        }
+    } else {
+        for i in (0..n).rev() {
+            let mut t = T::zero();
+            for j in (i - w).max(0)..i + 1 {
+                t = t.add(a.get(i, j).mul(x.get(j)));
+            }
+            x.set(i, t);
+        }
+    }
+}
+
+/// x := inv(op(A)) * x for a triangular matrix A.
+pub(super) unsafe fn trsv<T: Scalar>(a: Operator<T>, x: Vector<T>) {
+    let (n, w) = (a.matrix.n, a.matrix.width());
+    let solve = |i: isize, mut t: T| {
+        if !a.unit {
+            t = t.div(a.get(i, i));
+        }
+        x.set(i, t);
+    };
+    if a.upper() {
+        for i in (0..n).rev() {
+            let mut t = x.get(i);
+            for j in i + 1..(i + w + 1).min(n) {
+                t = t.sub(a.get(i, j).mul(x.get(j)));
+            }
+            solve(i, t);
+        }
+    } else {
+        for i in 0..n {
+            let mut t = x.get(i);
+            for j in (i - w).max(0)..i {
+                t = t.sub(a.get(i, j).mul(x.get(j)));
+            }
+            solve(i, t);
+        }
+    }
+}
+
+/// A := alpha * x * y' + A, where y' is conjugated if requested.
+unsafe fn ger<T: Scalar>(
+    layout: CBLAS_LAYOUT,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    y: *const T,
+    incy: blasint,
+    conj: bool,
+    a: *mut T,
+    lda: blasint,
+) {
+    if m == 0 || n == 0 || alpha.is_zero() {
+        return;
+    }
+    let (x, y) = (Vector::new(x, m, incx), Vector::new(y, n, incy));
+    let a = Matrix::new(layout, a, lda);
+    for j in 0..n as isize {
+        let t = alpha.mul(y.get(j).conj_if(conj));
+        for i in 0..m as isize {
+            a.set(i, j, a.get(i, j).add(x.get(i).mul(t)));
+        }
+    }
+}
+
+/// A := alpha * x * y' + alpha' * y * x' + A for a symmetric or Hermitian
+/// matrix A, where the second term is omitted if y is absent.
+unsafe fn syr2<T: Scalar>(
+    a: Triangle<T>,
+    herm: bool,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    y: Option<(*const T, blasint)>,
+) {
+    let n = a.n;
+    if n == 0 || alpha.is_zero() {
+        return;
+    }
+    let x = Vector::new(x, n as blasint, incx);
+    let y = y.map(|(y, incy)| Vector::new(y, n as blasint, incy));
+    let beta = alpha.conj_if(herm);
+    for j in 0..n {
+        for i in 0..n {
+            if !a.stored(i, j) {
+                continue;
+            }
+            let mut value = a.get(i, j);
+            match y {
+                Some(y) => {
+                    value = value.add(alpha.mul(x.get(i).mul(y.get(j).conj_if(herm))));
+                    value = value.add(beta.mul(y.get(i).mul(x.get(j).conj_if(herm))));
+                }
+                None => value = value.add(alpha.mul(x.get(i).mul(x.get(j).conj_if(herm)))),
+            }
+            if herm && i == j {
+                value = T::from_real(value.re());
+            }
+            a.set(i, j, value);
+        }
+    }
+}
+
+macro_rules! standard {
+    ($t:ty, $gemv:ident, $gbmv:ident, $trmv:ident, $tbmv:ident, $tpmv:ident, $trsv:ident, $tbsv:ident, $tpsv:ident) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $gemv(
+                layout: c_int,
+                trans: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                let routine = concat!(stringify!($gemv), "\0").as_bytes();
+                convert!(routine, 1 => layout: CBLAS_LAYOUT, 2 => trans: CBLAS_TRANSPOSE,);
+                check!(
+                    routine,
+                    3 => m >= 0,
+                    4 => n >= 0,
+                    7 => ::index::ld_valid(layout, lda, m, n),
+                    9 => incx != 0,
+                    12 => incy != 0,
+                );
+                gemv(
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    None,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                )
+            }
+
+            pub unsafe extern "C" fn $gbmv(
+                layout: c_int,
+                trans: c_int,
+                m: blasint,
+                n: blasint,
+                kl: blasint,
+                ku: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                let routine = concat!(stringify!($gbmv), "\0").as_bytes();
+                convert!(routine, 1 => layout: CBLAS_LAYOUT, 2 => trans: CBLAS_TRANSPOSE,);
+                check!(
+                    routine,
+                    3 => m >= 0,
+                    4 => n >= 0,
+                    5 => kl >= 0,
+                    6 => ku >= 0,
+                    9 => lda > kl + ku,
+                    11 => incx != 0,
+                    14 => incy != 0,
+                );
+                gemv(
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    Some((kl, ku)),
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                )
+            }
+
+            pub unsafe extern "C" fn $trmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($trmv), "\0").as_bytes(), false, layout, uplo, trans, diag, n, None, a, Some(lda), x, incx)
+            }
+
+            pub unsafe extern "C" fn $tbmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                k: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($tbmv), "\0").as_bytes(), false, layout, uplo, trans, diag, n, Some(k), a, Some(lda), x, incx)
+            }
+
+            pub unsafe extern "C" fn $tpmv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($tpmv), "\0").as_bytes(), false, layout, uplo, trans, diag, n, None, ap, None, x, incx)
+            }
+
+            pub unsafe extern "C" fn $trsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($trsv), "\0").as_bytes(), true, layout, uplo, trans, diag, n, None, a, Some(lda), x, incx)
+            }
+
+            pub unsafe extern "C" fn $tbsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                k: blasint,
+                a: *const $t,
+                lda: blasint,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($tbsv), "\0").as_bytes(), true, layout, uplo, trans, diag, n, Some(k), a, Some(lda), x, incx)
+            }
+
+            pub unsafe extern "C" fn $tpsv(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                diag: c_int,
+                n: blasint,
+                ap: *const $t,
+                x: *mut $t,
+                incx: blasint,
+            ) {
+                triangular(concat!(stringify!($tpsv), "\0").as_bytes(), true, layout, uplo, trans, diag, n, None, ap, None, x, incx)
+            }
+        }
+    };
+}
+
+/// The triangular matrix-vector routines, which only differ in the storage
+/// and operation: band storage is used if k is present and packed storage if
+/// lda is absent.
+unsafe fn triangular<T: Scalar>(
+    routine: &[u8],
+    solve: bool,
+    layout: c_int,
+    uplo: c_int,
+    trans: c_int,
+    diag: c_int,
+    n: blasint,
+    k: Option<blasint>,
+    a: *const T,
+    lda: Option<blasint>,
+    x: *mut T,
+    incx: blasint,
+) {
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+        3 => trans: CBLAS_TRANSPOSE,
+        4 => diag: CBLAS_DIAG,
+    );
+    let (storage, lda_position, incx_position) = match (k, lda) {
+        (Some(k), _) => (Storage::Band(k as isize), 8, 10),
+        (None, Some(_)) => (Storage::Full, 7, 9),
+        (None, None) => (Storage::Packed, 0, 8),
+    };
+    check!(
+        routine,
+        5 => n >= 0,
+        6 => k.is_none_or(|k| k >= 0),
+        lda_position => lda.is_none_or(|lda| lda >= k.map_or(n, |k| k + 1).max(1)),
+        incx_position => incx != 0,
+    );
+    let a = Operator::new(
+        Triangle::new(layout, uplo, storage, n, a, lda.unwrap_or(0)),
+        trans,
+        diag,
+    );
+    let x = Vector::new(x, n, incx);
+    if solve {
+        trsv(a, x)
+    } else {
+        trmv(a, x)
+    }
+}
+
+/// The symmetric or Hermitian matrix-vector routines, which only differ in
+/// the storage: band storage is used if k is present and packed storage if
+/// lda is absent.
+unsafe fn symmetric<T: Scalar>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    n: blasint,
+    k: Option<blasint>,
+    alpha: T,
+    a: *const T,
+    lda: Option<blasint>,
+    x: *const T,
+    incx: blasint,
+    beta: T,
+    y: *mut T,
+    incy: blasint,
+) {
+    convert!(routine, 1 => layout: CBLAS_LAYOUT, 2 => uplo: CBLAS_UPLO,);
+    let (storage, lda_position, incx_position) = match (k, lda) {
+        (Some(k), _) => (Storage::Band(k as isize), 7, 9),
+        (None, Some(_)) => (Storage::Full, 6, 8),
+        (None, None) => (Storage::Packed, 0, 7),
+    };
+    check!(
+        routine,
+        3 => n >= 0,
+        4 => k.is_none_or(|k| k >= 0),
+        lda_position => lda.is_none_or(|lda| lda >= k.map_or(n, |k| k + 1).max(1)),
+        incx_position => incx != 0,
+        incx_position + 3 => incy != 0,
+    );
+    let a = Triangle::new(layout, uplo, storage, n, a, lda.unwrap_or(0));
+    symv(a, T::COMPLEX, alpha, x, incx, beta, y, incy)
+}
+
+/// The rank-one and rank-two updates of a symmetric or Hermitian matrix,
+/// which use packed storage if lda is absent.
+unsafe fn update<T: Scalar>(
+    routine: &[u8],
+    layout: c_int,
+    uplo: c_int,
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    y: Option<(*const T, blasint)>,
+    a: *mut T,
+    lda: Option<blasint>,
+) {
+    convert!(routine, 1 => layout: CBLAS_LAYOUT, 2 => uplo: CBLAS_UPLO,);
+    let storage = match lda {
+        Some(_) => Storage::Full,
+        None => Storage::Packed,
+    };
+    check!(
+        routine,
+        3 => n >= 0,
+        6 => incx != 0,
+        8 => y.is_none_or(|(_, incy)| incy != 0),
+        if y.is_some() { 10 } else { 8 } => lda.is_none_or(|lda| lda >= n.max(1)),
+    );
+    let a = Triangle::new(layout, uplo, storage, n, a, lda.unwrap_or(0));
+    syr2(a, T::COMPLEX, alpha, x, incx, y)
+}
+
+/// The rank-one update of a general matrix.
+unsafe fn general<T: Scalar>(
+    routine: &[u8],
+    layout: c_int,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    x: *const T,
+    incx: blasint,
+    y: *const T,
+    incy: blasint,
+    conj: bool,
+    a: *mut T,
+    lda: blasint,
+) {
+    convert!(routine, 1 => layout: CBLAS_LAYOUT,);
+    check!(
+        routine,
+        2 => m >= 0,
+        3 => n >= 0,
+        6 => incx != 0,
+        8 => incy != 0,
+        10 => ::index::ld_valid(layout, lda, m, n),
+    );
+    ger(layout, m, n, alpha, x, incx, y, incy, conj, a, lda)
+}
+
+// The symmetric and Hermitian routines, whose names and the types of alpha
+// and beta depend on whether the type is real or complex.
+macro_rules! symmetric {
+    ($t:ty, $real:ty, $symv:ident, $sbmv:ident, $spmv:ident, $syr:ident, $spr:ident, $syr2:ident, $spr2:ident) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $symv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($symv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    None,
+                    <$t>::load(alpha),
+                    a,
+                    Some(lda),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                )
+            }
+
+            pub unsafe extern "C" fn $sbmv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($sbmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    Some(k),
+                    <$t>::load(alpha),
+                    a,
+                    Some(lda),
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                )
+            }
+
+            pub unsafe extern "C" fn $spmv(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                ap: *const $t,
+                x: *const $t,
+                incx: blasint,
+                beta: <$t as Scalar>::Arg,
+                y: *mut $t,
+                incy: blasint,
+            ) {
+                symmetric(
+                    concat!(stringify!($spmv), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    None,
+                    <$t>::load(alpha),
+                    ap,
+                    None,
+                    x,
+                    incx,
+                    <$t>::load(beta),
+                    y,
+                    incy,
+                )
+            }
+
+            pub unsafe extern "C" fn $syr(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: $real,
+                x: *const $t,
+                incx: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                update(
+                    concat!(stringify!($syr), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::from_real(alpha),
+                    x,
+                    incx,
+                    None,
+                    a,
+                    Some(lda),
+                )
+            }
+
+            pub unsafe extern "C" fn $spr(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: $real,
+                x: *const $t,
+                incx: blasint,
+                ap: *mut $t,
+            ) {
+                update(
+                    concat!(stringify!($spr), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::from_real(alpha),
+                    x,
+                    incx,
+                    None,
+                    ap,
+                    None,
+                )
+            }
+
+            pub unsafe extern "C" fn $syr2(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                update(
+                    concat!(stringify!($syr2), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    Some((y, incy)),
+                    a,
+                    Some(lda),
+                )
+            }
+
+            pub unsafe extern "C" fn $spr2(
+                layout: c_int,
+                uplo: c_int,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                ap: *mut $t,
+            ) {
+                update(
+                    concat!(stringify!($spr2), "\0").as_bytes(),
+                    layout,
+                    uplo,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    Some((y, incy)),
+                    ap,
+                    None,
+                )
+            }
+        }
+    };
+}
+
+macro_rules! ger {
+    ($t:ty, $($name:ident => $conj:expr),*) => {
+        export_cblas! {$(
+            pub unsafe extern "C" fn $name(
+                layout: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                x: *const $t,
+                incx: blasint,
+                y: *const $t,
+                incy: blasint,
+                a: *mut $t,
+                lda: blasint,
+            ) {
+                general(
+                    concat!(stringify!($name), "\0").as_bytes(),
+                    layout,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    x,
+                    incx,
+                    y,
+                    incy,
+                    $conj,
+                    a,
+                    lda,
+                )
+            }
+        )*}
+    };
+}
+
+standard!(
+    c_float,
+    cblas_sgemv,
+    cblas_sgbmv,
+    cblas_strmv,
+    cblas_stbmv,
+    cblas_stpmv,
+    cblas_strsv,
+    cblas_stbsv,
+    cblas_stpsv
+);
+standard!(
+    c_double,
+    cblas_dgemv,
+    cblas_dgbmv,
+    cblas_dtrmv,
+    cblas_dtbmv,
+    cblas_dtpmv,
+    cblas_dtrsv,
+    cblas_dtbsv,
+    cblas_dtpsv
+);
+standard!(
+    c_float_complex,
+    cblas_cgemv,
+    cblas_cgbmv,
+    cblas_ctrmv,
+    cblas_ctbmv,
+    cblas_ctpmv,
+    cblas_ctrsv,
+    cblas_ctbsv,
+    cblas_ctpsv
+);
+standard!(
+    c_double_complex,
+    cblas_zgemv,
+    cblas_zgbmv,
+    cblas_ztrmv,
+    cblas_ztbmv,
+    cblas_ztpmv,
+    cblas_ztrsv,
+    cblas_ztbsv,
+    cblas_ztpsv
+);
+
+symmetric!(
+    c_float,
+    c_float,
+    cblas_ssymv,
+    cblas_ssbmv,
+    cblas_sspmv,
+    cblas_ssyr,
+    cblas_sspr,
+    cblas_ssyr2,
+    cblas_sspr2
+);
+symmetric!(
+    c_double,
+    c_double,
+    cblas_dsymv,
+    cblas_dsbmv,
+    cblas_dspmv,
+    cblas_dsyr,
+    cblas_dspr,
+    cblas_dsyr2,
+    cblas_dspr2
+);
+symmetric!(
+    c_float_complex,
+    c_float,
+    cblas_chemv,
+    cblas_chbmv,
+    cblas_chpmv,
+    cblas_cher,
+    cblas_chpr,
+    cblas_cher2,
+    cblas_chpr2
+);
+symmetric!(
+    c_double_complex,
+    c_double,
+    cblas_zhemv,
+    cblas_zhbmv,
+    cblas_zhpmv,
+    cblas_zher,
+    cblas_zhpr,
+    cblas_zher2,
+    cblas_zhpr2
+);
+
+ger!(c_float, cblas_sger => false);
+ger!(c_double, cblas_dger => false);
+ger!(c_float_complex, cblas_cgeru => false, cblas_cgerc => true);
+ger!(c_double_complex, cblas_zgeru => false, cblas_zgerc => true);
@@ -0,0 +1,653 @@
+use libc::{c_double, c_float, c_int};
+
+use super::level2::{scale, trmv, trsv};
+use super::{Matrix, Operator, Storage, Triangle};
+use index::{ld_valid, op_size};
+use scalar::Scalar;
+use {blasint, c_double_complex, c_float_complex, CBLAS_TRANSPOSE};
+use {CblasConjTrans, CblasLeft, CblasNoTrans, CblasTrans, CblasUpper};
+
+/// alpha * t + beta * c, where c is not read if beta is zero.
+#[inline]
+fn update<T: Scalar>(alpha: T, t: T, beta: T, c: T) -> T {
+    if beta.is_zero() {
+        alpha.mul(t)
+    } else {
+        alpha.mul(t).add(beta.mul(c))
+    }
+}
+
+/// Whether a leading dimension is valid for op(A), where op(A) is rows by
+/// cols.
+fn ld_valid_op(
+    layout: ::CBLAS_LAYOUT,
+    trans: CBLAS_TRANSPOSE,
+    ld: blasint,
+    rows: blasint,
+    cols: blasint,
+) -> bool {
+    let (rows, cols) = op_size(trans, rows, cols);
+    ld_valid(layout, ld, rows, cols)
+}
+
+unsafe fn gemm<T: Scalar>(
+    routine: &[u8],
+    layout: c_int,
+    transa: c_int,
+    transb: c_int,
+    m: blasint,
+    n: blasint,
+    k: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: *const T,
+    ldb: blasint,
+    beta: T,
+    c: *mut T,
+    ldc: blasint,
+) {
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => transa: CBLAS_TRANSPOSE,
+        3 => transb: CBLAS_TRANSPOSE,
+    );
+    check!(
+        routine,
+        4 => m >= 0,
+        5 => n >= 0,
+        6 => k >= 0,
+        9 => ld_valid_op(layout, transa, lda, m, k),
+        11 => ld_valid_op(layout, transb, ldb, k, n),
+        14 => ld_valid(layout, ldc, m, n),
+    );
+    if m == 0 || n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one()) {
+        return;
+    }
+    let (a, b, c) = (
+        Matrix::new(layout, a, lda),
+        Matrix::new(layout, b, ldb),
+        Matrix::new(layout, c, ldc),
+    );
+    for j in 0..n as isize {
+        for i in 0..m as isize {
+            let mut t = T::zero();
+            if !alpha.is_zero() {
+                for l in 0..k as isize {
+                    t = t.add(a.get_op(transa, i, l).mul(b.get_op(transb, l, j)));
+                }
+            }
+            c.set(i, j, update(alpha, t, beta, c.get(i, j)));
+        }
+    }
+}
+
+/// C := alpha * A * B + beta * C or C := alpha * B * A + beta * C for a
+/// symmetric or Hermitian matrix A.
+unsafe fn symm<T: Scalar>(
+    routine: &[u8],
+    herm: bool,
+    layout: c_int,
+    side: c_int,
+    uplo: c_int,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: *const T,
+    ldb: blasint,
+    beta: T,
+    c: *mut T,
+    ldc: blasint,
+) {
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => side: CBLAS_SIDE,
+        3 => uplo: CBLAS_UPLO,
+    );
+    let left = matches!(side, CblasLeft);
+    let order = if left { m } else { n };
+    check!(
+        routine,
+        4 => m >= 0,
+        5 => n >= 0,
+        8 => lda >= order.max(1),
+        10 => ld_valid(layout, ldb, m, n),
+        13 => ld_valid(layout, ldc, m, n),
+    );
+    if m == 0 || n == 0 || (alpha.is_zero() && beta.is_one()) {
+        return;
+    }
+    let a = Triangle::new(layout, uplo, Storage::Full, order, a, lda);
+    let (b, c) = (Matrix::new(layout, b, ldb), Matrix::new(layout, c, ldc));
+    for j in 0..n as isize {
+        for i in 0..m as isize {
+            let mut t = T::zero();
+            if !alpha.is_zero() {
+                if left {
+                    for l in 0..m as isize {
+                        t = t.add(a.get_symmetric(herm, i, l).mul(b.get(l, j)));
+                    }
+                } else {
+                    for l in 0..n as isize {
+                        t = t.add(b.get(i, l).mul(a.get_symmetric(herm, l, j)));
+                    }
+                }
+            }
+            c.set(i, j, update(alpha, t, beta, c.get(i, j)));
+        }
+    }
+}
+
+/// C := alpha * A * B' + alpha' * B * A' + beta * C or
+/// C := alpha * A' * B + alpha' * B' * A + beta * C for a symmetric or
+/// Hermitian matrix C, where ' is the transpose or conjugate transpose. The
+/// rank-k update C := alpha * A * A' + beta * C is computed if B is absent.
+unsafe fn syr2k<T: Scalar>(
+    routine: &[u8],
+    herm: bool,
+    layout: c_int,
+    uplo: c_int,
+    trans: c_int,
+    n: blasint,
+    k: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: Option<(*const T, blasint)>,
+    beta: T,
+    c: *mut T,
+    ldc: blasint,
+) {
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => uplo: CBLAS_UPLO,
+        3 => trans: CBLAS_TRANSPOSE,
+    );
+    // The conjugate transpose is the transpose for real symmetric matrices,
+    // but it is invalid for complex symmetric matrices and vice versa.
+    let valid = match trans {
+        CblasNoTrans => true,
+        CblasTrans => !herm,
+        CblasConjTrans => herm || !T::COMPLEX,
+    };
+    let ldc_position = if b.is_some() { 13 } else { 11 };
+    check!(
+        routine,
+        3 => valid,
+        4 => n >= 0,
+        5 => k >= 0,
+        8 => ld_valid_op(layout, trans, lda, n, k),
+        10 => b.is_none_or(|(_, ldb)| ld_valid_op(layout, trans, ldb, n, k)),
+        ldc_position => ld_valid(layout, ldc, n, n),
+    );
+    if n == 0 || ((alpha.is_zero() || k == 0) && beta.is_one() && !herm) {
+        return;
+    }
+    let a = Matrix::new(layout, a, lda);
+    let b = b.map(|(b, ldb)| Matrix::new(layout, b, ldb));
+    let c = Matrix::new(layout, c, ldc);
+    let gamma = alpha.conj_if(herm);
+    // Element (i, l) of op(A), which is conjugated for the right factor.
+    let get = |a: &Matrix<T>, i: isize, l: isize, right: bool| match trans {
+        CblasNoTrans => a.get(i, l).conj_if(right && herm),
+        _ => a.get(l, i).conj_if(!right && herm),
+    };
+    let n = n as isize;
+    for j in 0..n {
+        let rows = if matches!(uplo, CblasUpper) {
+            0..j + 1
+        } else {
+            j..n
+        };
+        for i in rows {
+            let (mut t, mut u) = (T::zero(), T::zero());
+            if !alpha.is_zero() {
+                for l in 0..k as isize {
+                    match b {
+                        Some(ref b) => {
+                            t = t.add(get(&a, i, l, false).mul(get(b, j, l, true)));
+                            u = u.add(get(b, i, l, false).mul(get(&a, j, l, true)));
+                        }
+                        None => t = t.add(get(&a, i, l, false).mul(get(&a, j, l, true))),
+                    }
+                }
+            }
+            let mut value = update(alpha, t, beta, c.get(i, j));
+            if b.is_some() {
+                value = value.add(gamma.mul(u));
+            }
+            if herm && i == j {
+                value = T::from_real(value.re());
+            }
+            c.set(i, j, value);
+        }
+    }
+}
+
+/// B := alpha * op(A) * B, B := alpha * B * op(A), or the same with
+/// inv(op(A)) for a triangular matrix A, which is applied to the columns or
+/// the rows of B one at a time.
+unsafe fn trxm<T: Scalar>(
+    routine: &[u8],
+    solve: bool,
+    layout: c_int,
+    side: c_int,
+    uplo: c_int,
+    transa: c_int,
+    diag: c_int,
+    m: blasint,
+    n: blasint,
+    alpha: T,
+    a: *const T,
+    lda: blasint,
+    b: *mut T,
+    ldb: blasint,
+) {
+    convert!(
+        routine,
+        1 => layout: CBLAS_LAYOUT,
+        2 => side: CBLAS_SIDE,
+        3 => uplo: CBLAS_UPLO,
+        4 => transa: CBLAS_TRANSPOSE,
+        5 => diag: CBLAS_DIAG,
+    );
+    let left = matches!(side, CblasLeft);
+    let order = if left { m } else { n };
+    check!(
+        routine,
+        6 => m >= 0,
+        7 => n >= 0,
+        10 => lda >= order.max(1),
+        12 => ld_valid(layout, ldb, m, n),
+    );
+    if m == 0 || n == 0 {
+        return;
+    }
+    let a = Operator::new(
+        Triangle::new(layout, uplo, Storage::Full, order, a, lda),
+        transa,
+        diag,
+    );
+    // A row x of B is multiplied as x * op(A) = (op(A)^T * x^T)^T.
+    let a = if left { a } else { a.transpose() };
+    let b = Matrix::new(layout, b, ldb);
+    let count = if left { n } else { m };
+    for j in 0..count as isize {
+        let x = b.vector(!left, j);
+        if solve {
+            scale(order as isize, alpha, x);
+            if !alpha.is_zero() {
+                trsv(a, x);
+            }
+        } else {
+            if !alpha.is_zero() {
+                trmv(a, x);
+            }
+            scale(order as isize, alpha, x);
+        }
+    }
+}
+
+macro_rules! standard {
+    ($t:ty, $gemm:ident, $symm:ident, $syrk:ident, $syr2k:ident, $trmm:ident, $trsm:ident) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $gemm(
+                layout: c_int,
+                transa: c_int,
+                transb: c_int,
+                m: blasint,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                gemm(
+                    concat!(stringify!($gemm), "\0").as_bytes(),
+                    layout,
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    <$t>::load(beta),
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $symm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                symm(
+                    concat!(stringify!($symm), "\0").as_bytes(),
+                    false,
+                    layout,
+                    side,
+                    uplo,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    <$t>::load(beta),
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $syrk(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                syr2k(
+                    concat!(stringify!($syrk), "\0").as_bytes(),
+                    false,
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    None,
+                    <$t>::load(beta),
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $syr2k(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: <$t as Scalar>::Arg,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                syr2k(
+                    concat!(stringify!($syr2k), "\0").as_bytes(),
+                    false,
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    Some((b, ldb)),
+                    <$t>::load(beta),
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $trmm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                transa: c_int,
+                diag: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *mut $t,
+                ldb: blasint,
+            ) {
+                trxm(
+                    concat!(stringify!($trmm), "\0").as_bytes(),
+                    false,
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                )
+            }
+
+            pub unsafe extern "C" fn $trsm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                transa: c_int,
+                diag: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: <$t as Scalar>::Arg,
+                a: *const $t,
+                lda: blasint,
+                b: *mut $t,
+                ldb: blasint,
+            ) {
+                trxm(
+                    concat!(stringify!($trsm), "\0").as_bytes(),
+                    true,
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    <$t>::load(alpha),
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                )
+            }
+        }
+    };
+}
+
+macro_rules! hermitian {
+    ($t:ty, $real:ty, $hemm:ident, $herk:ident, $her2k:ident) => {
+        export_cblas! {
+            pub unsafe extern "C" fn $hemm(
+                layout: c_int,
+                side: c_int,
+                uplo: c_int,
+                m: blasint,
+                n: blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: *const $t,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                symm(
+                    concat!(stringify!($hemm), "\0").as_bytes(),
+                    true,
+                    layout,
+                    side,
+                    uplo,
+                    m,
+                    n,
+                    *alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    *beta,
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $herk(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: $real,
+                a: *const $t,
+                lda: blasint,
+                beta: $real,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                syr2k(
+                    concat!(stringify!($herk), "\0").as_bytes(),
+                    true,
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    <$t>::from_real(alpha),
+                    a,
+                    lda,
+                    None,
+                    <$t>::from_real(beta),
+                    c,
+                    ldc,
+                )
+            }
+
+            pub unsafe extern "C" fn $her2k(
+                layout: c_int,
+                uplo: c_int,
+                trans: c_int,
+                n: blasint,
+                k: blasint,
+                alpha: *const $t,
+                a: *const $t,
+                lda: blasint,
+                b: *const $t,
+                ldb: blasint,
+                beta: $real,
+                c: *mut $t,
+                ldc: blasint,
+            ) {
+                syr2k(
+                    concat!(stringify!($her2k), "\0").as_bytes(),
+                    true,
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    *alpha,
+                    a,
+                    lda,
+                    Some((b, ldb)),
+                    <$t>::from_real(beta),
+                    c,
+                    ldc,
+                )
+            }
+        }
+    };
+}
+
+standard!(
+    c_float,
+    cblas_sgemm,
+    cblas_ssymm,
+    cblas_ssyrk,
+    cblas_ssyr2k,
+    cblas_strmm,
+    cblas_strsm
+);
+standard!(
+    c_double,
+    cblas_dgemm,
+    cblas_dsymm,
+    cblas_dsyrk,
+    cblas_dsyr2k,
+    cblas_dtrmm,
+    cblas_dtrsm
+);
+standard!(
+    c_float_complex,
+    cblas_cgemm,
+    cblas_csymm,
+    cblas_csyrk,
+    cblas_csyr2k,
+    cblas_ctrmm,
+    cblas_ctrsm
+);
+standard!(
+    c_double_complex,
+    cblas_zgemm,
+    cblas_zsymm,
+    cblas_zsyrk,
+    cblas_zsyr2k,
+    cblas_ztrmm,
+    cblas_ztrsm
+);
+
+hermitian!(
+    c_float_complex,
+    c_float,
+    cblas_chemm,
+    cblas_cherk,
+    cblas_cher2k
+);
+hermitian!(
+    c_double_complex,
+    c_double,
+    cblas_zhemm,
+    cblas_zherk,
+    cblas_zher2k
+);
@@ -0,0 +1,273 @@
+//! A pure-Rust implementation of the functions declared by the package.
+//!
+//! The functions are exported under their CBLAS names, so that the bindings
+//! resolve to them when the `pure-rust` feature is enabled. They follow the
+//! semantics of the reference implementation but are written as plain loops
+//! over the matrix elements, which favors simplicity over speed.
+//!
+//! Enumerations are received as integers, so that invalid values coming from
+//! C are reported via `cblas_xerbla` as the reference implementation does.
+
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+use {blasint, CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use {CblasColMajor, CblasConjTrans, CblasLower, CblasNoTrans, CblasRowMajor, CblasTrans};
+use {CblasUnit, CblasUpper};
+
+// Reports an invalid argument, unless the condition holds, and returns.
+macro_rules! check {
+    ($routine:expr, $($position:expr => $condition:expr,)*) => {$(
+        if !$condition {
+            return ::error::xerbla($position, $routine);
+        }
+    )*};
+}
+
+mod level1;
+mod level2;
+mod level3;
+
+use index::{first, offset};
+use scalar::Scalar;
+
+define_xerbla! {
+    // The handler of the `xerbla` feature takes precedence if enabled.
+    #[cfg(not(feature = "xerbla"))]
+    |p, rout| {
+        use std::ffi::CStr;
+        use std::io::Write;
+
+        let routine = CStr::from_ptr(rout).to_string_lossy();
+        let _ = writeln!(
+            std::io::stderr(),
+            "Parameter {} to routine {} was incorrect",
+            p,
+            routine,
+        );
+        std::process::exit(-1);
+    }
+}
+
+/// A strided vector.
+#[derive(Clone, Copy)]
+struct Vector<T> {
+    data: *mut T,
+    first: isize,
+    inc: isize,
+}
+
+impl<T: Scalar> Vector<T> {
+    /// Creates a vector with n elements, which start at the end of the memory
+    /// if the increment is negative.
+    fn new(data: *const T, n: blasint, inc: blasint) -> Self {
+        Vector {
+            data: data as *mut T,
+            first: first(n, inc),
+            inc: inc as isize,
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: isize) -> T {
+        *self.data.offset(self.first + i * self.inc)
+    }
+
+    #[inline]
+    unsafe fn set(&self, i: isize, value: T) {
+        *self.data.offset(self.first + i * self.inc) = value;
+    }
+}
+
+/// A general matrix.
+#[derive(Clone, Copy)]
+struct Matrix<T> {
+    data: *mut T,
+    layout: CBLAS_LAYOUT,
+    ld: isize,
+}
+
+impl<T: Scalar> Matrix<T> {
+    fn new(layout: CBLAS_LAYOUT, data: *const T, ld: blasint) -> Self {
+        Matrix {
+            data: data as *mut T,
+            layout,
+            ld: ld as isize,
+        }
+    }
+
+    #[inline]
+    fn offset(&self, i: isize, j: isize) -> isize {
+        offset(self.layout, self.ld, i, j)
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: isize, j: isize) -> T {
+        *self.data.offset(self.offset(i, j))
+    }
+
+    /// Element (i, j) of op(A).
+    #[inline]
+    unsafe fn get_op(&self, trans: CBLAS_TRANSPOSE, i: isize, j: isize) -> T {
+        match trans {
+            CblasNoTrans => self.get(i, j),
+            CblasTrans => self.get(j, i),
+            CblasConjTrans => self.get(j, i).conj(),
+        }
+    }
+
+    #[inline]
+    unsafe fn set(&self, i: isize, j: isize, value: T) {
+        *self.data.offset(self.offset(i, j)) = value;
+    }
+
+    /// Row or column k as a vector.
+    fn vector(&self, row: bool, k: isize) -> Vector<T> {
+        let (start, inc) = match (self.layout, row) {
+            (CblasColMajor, false) | (CblasRowMajor, true) => (k * self.ld, 1),
+            (CblasColMajor, true) | (CblasRowMajor, false) => (k, self.ld),
+        };
+        Vector {
+            data: unsafe { self.data.offset(start) },
+            first: 0,
+            inc,
+        }
+    }
+}
+
+/// The storage of a symmetric, Hermitian, or triangular matrix.
+#[derive(Clone, Copy)]
+enum Storage {
+    Full,
+    Band(isize),
+    Packed,
+}
+
+/// A symmetric, Hermitian, or triangular matrix of order n.
+#[derive(Clone, Copy)]
+struct Triangle<T> {
+    data: *mut T,
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    storage: Storage,
+    n: isize,
+    lda: isize,
+}
+
+impl<T: Scalar> Triangle<T> {
+    fn new(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        storage: Storage,
+        n: blasint,
+        data: *const T,
+        lda: blasint,
+    ) -> Self {
+        Triangle {
+            data: data as *mut T,
+            layout,
+            uplo,
+            storage,
+            n: n as isize,
+            lda: lda as isize,
+        }
+    }
+
+    /// The number of off-diagonals that can be nonzero.
+    fn width(&self) -> isize {
+        match self.storage {
+            Storage::Band(k) => k,
+            _ => self.n - 1,
+        }
+    }
+
+    /// Whether element (i, j) is in the stored triangle.
+    #[inline]
+    fn stored(&self, i: isize, j: isize) -> bool {
+        match self.uplo {
+            CblasUpper => i <= j,
+            CblasLower => i >= j,
+        }
+    }
+
+    /// The offset of element (i, j), which has to be in the stored triangle.
+    #[inline]
+    fn offset(&self, i: isize, j: isize) -> isize {
+        let upper = matches!(self.uplo, CblasUpper);
+        // A row-major matrix is the column-major transpose of the other triangle.
+        let (i, j, upper) = match self.layout {
+            CblasColMajor => (i, j, upper),
+            CblasRowMajor => (j, i, !upper),
+        };
+        match self.storage {
+            Storage::Full => i + j * self.lda,
+            Storage::Band(k) if upper => k + i - j + j * self.lda,
+            Storage::Band(_) => i - j + j * self.lda,
+            Storage::Packed if upper => i + j * (j + 1) / 2,
+            Storage::Packed => i + (2 * self.n - j - 1) * j / 2,
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: isize, j: isize) -> T {
+        *self.data.offset(self.offset(i, j))
+    }
+
+    #[inline]
+    unsafe fn set(&self, i: isize, j: isize, value: T) {
+        *self.data.offset(self.offset(i, j)) = value;
+    }
+
+    /// Element (i, j) of the symmetric or Hermitian matrix.
+    #[inline]
+    unsafe fn get_symmetric(&self, herm: bool, i: isize, j: isize) -> T {
+        if !self.stored(i, j) {
+            return self.get(j, i).conj_if(herm);
+        }
+        let value = self.get(i, j);
+        if herm && i == j {
+            T::from_real(value.re())
+        } else {
+            value
+        }
+    }
+}
+
+/// A triangular matrix used as op(A), where op is a transpose and/or a
+/// conjugation.
+#[derive(Clone, Copy)]
+struct Operator<T> {
+    matrix: Triangle<T>,
+    transposed: bool,
+    conj: bool,
+    unit: bool,
+}
+
+impl<T: Scalar> Operator<T> {
+    fn new(matrix: Triangle<T>, trans: CBLAS_TRANSPOSE, diag: CBLAS_DIAG) -> Self {
+        Operator {
+            matrix,
+            transposed: !matches!(trans, CblasNoTrans),
+            conj: matches!(trans, CblasConjTrans),
+            unit: matches!(diag, CblasUnit),
+        }
+    }
+
+    /// The operator applied from the right, i.e., op(A) as op(A)^T.
+    fn transpose(mut self) -> Self {
+        self.transposed = !self.transposed;
+        self
+    }
+
+    fn upper(&self) -> bool {
+        matches!(self.matrix.uplo, CblasUpper) != self.transposed
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: isize, j: isize) -> T {
+        let (i, j) = if self.transposed { (j, i) } else { (i, j) };
+        if i == j && self.unit {
+            return T::one();
+        }
+        self.matrix.get(i, j).conj_if(self.conj)
+    }
+}
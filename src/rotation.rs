@@ -0,0 +1,61 @@
+//! Givens rotations of complex vectors, which the implementations written in
+//! Rust share.
+
+use blasint;
+use index::first;
+use scalar::{Real, Scalar};
+
+/// The absolute value of a number, scaled to avoid needless overflow.
+fn abs<T: Scalar>(z: T) -> T::Real {
+    let scale = z.abs1();
+    if scale == T::Real::ZERO {
+        return scale;
+    }
+    let [re, im] = z.scale(T::Real::ONE / scale).parts();
+    scale * (re * re + im * im).sqrt()
+}
+
+// The rotation is constructed as in the reference implementation since
+// version 3.10, which makes r real if a is zero.
+pub unsafe fn rotg<T: Scalar>(a: *mut T, b: *mut T, c: *mut T::Real, s: *mut T) {
+    let (f, g) = (*a, *b);
+    if g.is_zero() {
+        *c = T::Real::ONE;
+        *s = T::zero();
+    } else if f.is_zero() {
+        let d = abs(g);
+        *c = T::Real::ZERO;
+        *s = g.conj().scale(T::Real::ONE / d);
+        *a = T::from_real(d);
+    } else {
+        let (f1, g1) = (abs(f), abs(g));
+        let scale = if f1 > g1 { f1 } else { g1 };
+        let (p, q) = (f1 / scale, g1 / scale);
+        let norm = scale * (p * p + q * q).sqrt();
+        let alpha = f.scale(T::Real::ONE / f1);
+        *c = f1 / norm;
+        *s = alpha.mul(g.conj()).scale(T::Real::ONE / norm);
+        *a = alpha.scale(norm);
+    }
+}
+
+pub unsafe fn rot<T: Scalar>(
+    n: blasint,
+    x: *mut T,
+    incx: blasint,
+    y: *mut T,
+    incy: blasint,
+    c: T::Real,
+    s: T::Real,
+) {
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (x.offset(first(n, incx)), y.offset(first(n, incy)));
+    for i in 0..n as isize {
+        let (x, y) = (x.offset(i * incx as isize), y.offset(i * incy as isize));
+        let (u, v) = (*x, *y);
+        *x = u.scale(c).add(v.scale(s));
+        *y = v.scale(c).sub(u.scale(s));
+    }
+}
@@ -0,0 +1,748 @@
+//! Batched routines, which solve many independent problems in a single call.
+//!
+//! A batch is a slice of problems, each of which has its own parameters and
+//! operands. Every problem is checked as the corresponding unbatched wrapper
+//! checks it, and nothing is computed if any of them is invalid. Consecutive
+//! problems with equal parameters are passed to the library as one group.
+//!
+//! The routines call the functions of the [`extensions`] module, which fall
+//! back to calling the unbatched function for every problem if the library
+//! does not provide them.
+//!
+//! [`extensions`]: ../../extensions/index.html
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::vec::Vec;
+
+use libc::{c_double, c_float};
+
+use super::{dimension, input, level2, level3, matrix, vector, CblasError};
+use {blasint, c_double_complex, c_float_complex};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// The parameters of a problem computing y := alpha * x + y for x and y with
+/// n elements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AxpyParams<T> {
+    pub n: usize,
+    pub alpha: T,
+    pub incx: isize,
+    pub incy: isize,
+}
+
+impl<T> AxpyParams<T> {
+    /// Convert `alpha` into another type.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> AxpyParams<U> {
+        AxpyParams {
+            n: self.n,
+            alpha: f(self.alpha),
+            incx: self.incx,
+            incy: self.incy,
+        }
+    }
+}
+
+/// A problem of a batch of vector additions.
+#[derive(Debug)]
+pub struct Axpy<'a, T> {
+    pub params: AxpyParams<T>,
+    pub x: &'a [T],
+    pub y: &'a mut [T],
+}
+
+/// The parameters of a problem computing y := alpha * op(A) * x + beta * y
+/// for an m-by-n A.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GemvParams<T> {
+    pub trans: CBLAS_TRANSPOSE,
+    pub m: usize,
+    pub n: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub incx: isize,
+    pub beta: T,
+    pub incy: isize,
+}
+
+impl<T> GemvParams<T> {
+    /// Convert `alpha` and `beta` into another type.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> GemvParams<U> {
+        GemvParams {
+            trans: self.trans,
+            m: self.m,
+            n: self.n,
+            alpha: f(self.alpha),
+            lda: self.lda,
+            incx: self.incx,
+            beta: f(self.beta),
+            incy: self.incy,
+        }
+    }
+}
+
+/// A problem of a batch of matrix-vector multiplications.
+#[derive(Debug)]
+pub struct Gemv<'a, T> {
+    pub params: GemvParams<T>,
+    pub a: &'a [T],
+    pub x: &'a [T],
+    pub y: &'a mut [T],
+}
+
+/// The parameters of a problem computing C := alpha * op(A) * op(B) + beta * C
+/// for an m-by-k op(A), a k-by-n op(B), and an m-by-n C.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GemmParams<T> {
+    pub transa: CBLAS_TRANSPOSE,
+    pub transb: CBLAS_TRANSPOSE,
+    pub m: usize,
+    pub n: usize,
+    pub k: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub ldb: usize,
+    pub beta: T,
+    pub ldc: usize,
+}
+
+impl<T> GemmParams<T> {
+    /// Convert `alpha` and `beta` into another type.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> GemmParams<U> {
+        GemmParams {
+            transa: self.transa,
+            transb: self.transb,
+            m: self.m,
+            n: self.n,
+            k: self.k,
+            alpha: f(self.alpha),
+            lda: self.lda,
+            ldb: self.ldb,
+            beta: f(self.beta),
+            ldc: self.ldc,
+        }
+    }
+}
+
+/// A problem of a batch of matrix multiplications.
+#[derive(Debug)]
+pub struct Gemm<'a, T> {
+    pub params: GemmParams<T>,
+    pub a: &'a [T],
+    pub b: &'a [T],
+    pub c: &'a mut [T],
+}
+
+/// The parameters of a problem solving op(A) * X = alpha * B or
+/// X * op(A) = alpha * B, depending on `side`, for a triangular A and an
+/// m-by-n B.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrsmParams<T> {
+    pub side: CBLAS_SIDE,
+    pub uplo: CBLAS_UPLO,
+    pub transa: CBLAS_TRANSPOSE,
+    pub diag: CBLAS_DIAG,
+    pub m: usize,
+    pub n: usize,
+    pub alpha: T,
+    pub lda: usize,
+    pub ldb: usize,
+}
+
+impl<T> TrsmParams<T> {
+    /// Convert `alpha` into another type.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> TrsmParams<U> {
+        TrsmParams {
+            side: self.side,
+            uplo: self.uplo,
+            transa: self.transa,
+            diag: self.diag,
+            m: self.m,
+            n: self.n,
+            alpha: f(self.alpha),
+            lda: self.lda,
+            ldb: self.ldb,
+        }
+    }
+}
+
+/// A problem of a batch of triangular solves, which overwrites B with X.
+#[derive(Debug)]
+pub struct Trsm<'a, T> {
+    pub params: TrsmParams<T>,
+    pub a: &'a [T],
+    pub b: &'a mut [T],
+}
+
+/// The arguments of `cblas_?axpy_batch`, with a group for every run of
+/// problems with equal parameters.
+struct AxpyBatch<T> {
+    n: Vec<blasint>,
+    alpha: Vec<T>,
+    x: Vec<*const T>,
+    incx: Vec<blasint>,
+    y: Vec<*mut T>,
+    incy: Vec<blasint>,
+    group_size: Vec<blasint>,
+}
+
+impl<T: Copy + PartialEq> AxpyBatch<T> {
+    /// Check the problems and collect the arguments.
+    fn new(problems: &mut [Axpy<T>]) -> Result<Self, CblasError> {
+        dimension("problems", problems.len())?;
+        let mut batch = AxpyBatch {
+            n: Vec::new(),
+            alpha: Vec::new(),
+            x: Vec::with_capacity(problems.len()),
+            incx: Vec::new(),
+            y: Vec::with_capacity(problems.len()),
+            incy: Vec::new(),
+            group_size: Vec::new(),
+        };
+        let mut last = None;
+        for problem in problems.iter_mut() {
+            let params = problem.params;
+            let incx = input("x", "incx", problem.x.len(), params.n, params.incx)?;
+            let incy = vector("y", "incy", problem.y.len(), params.n, params.incy)?;
+            let n = dimension("n", params.n)?;
+            batch.x.push(problem.x.as_ptr());
+            batch.y.push(problem.y.as_mut_ptr());
+            if last == Some(params) {
+                *batch.group_size.last_mut().unwrap() += 1;
+                continue;
+            }
+            batch.n.push(n);
+            batch.alpha.push(params.alpha);
+            batch.incx.push(incx);
+            batch.incy.push(incy);
+            batch.group_size.push(1);
+            last = Some(params);
+        }
+        Ok(batch)
+    }
+}
+
+/// The arguments of `cblas_?gemv_batch`, with a group for every run of
+/// problems with equal parameters.
+struct GemvBatch<T> {
+    trans: Vec<CBLAS_TRANSPOSE>,
+    m: Vec<blasint>,
+    n: Vec<blasint>,
+    alpha: Vec<T>,
+    a: Vec<*const T>,
+    lda: Vec<blasint>,
+    x: Vec<*const T>,
+    incx: Vec<blasint>,
+    beta: Vec<T>,
+    y: Vec<*mut T>,
+    incy: Vec<blasint>,
+    group_size: Vec<blasint>,
+}
+
+impl<T: Copy + PartialEq> GemvBatch<T> {
+    /// Check the problems and collect the arguments.
+    fn new(layout: CBLAS_LAYOUT, problems: &mut [Gemv<T>]) -> Result<Self, CblasError> {
+        dimension("problems", problems.len())?;
+        let mut batch = GemvBatch {
+            trans: Vec::new(),
+            m: Vec::new(),
+            n: Vec::new(),
+            alpha: Vec::new(),
+            a: Vec::with_capacity(problems.len()),
+            lda: Vec::new(),
+            x: Vec::with_capacity(problems.len()),
+            incx: Vec::new(),
+            beta: Vec::new(),
+            y: Vec::with_capacity(problems.len()),
+            incy: Vec::new(),
+            group_size: Vec::new(),
+        };
+        let mut last = None;
+        for problem in problems.iter_mut() {
+            let params = problem.params;
+            let (lenx, leny) = level2::operands(params.trans, params.m, params.n);
+            let (rows, cols) = (params.m, params.n);
+            let lda = matrix("a", "lda", problem.a.len(), layout, rows, cols, params.lda)?;
+            let incx = vector("x", "incx", problem.x.len(), lenx, params.incx)?;
+            let incy = vector("y", "incy", problem.y.len(), leny, params.incy)?;
+            let (m, n) = (dimension("m", params.m)?, dimension("n", params.n)?);
+            batch.a.push(problem.a.as_ptr());
+            batch.x.push(problem.x.as_ptr());
+            batch.y.push(problem.y.as_mut_ptr());
+            if last == Some(params) {
+                *batch.group_size.last_mut().unwrap() += 1;
+                continue;
+            }
+            batch.trans.push(params.trans);
+            batch.m.push(m);
+            batch.n.push(n);
+            batch.alpha.push(params.alpha);
+            batch.lda.push(lda);
+            batch.incx.push(incx);
+            batch.beta.push(params.beta);
+            batch.incy.push(incy);
+            batch.group_size.push(1);
+            last = Some(params);
+        }
+        Ok(batch)
+    }
+}
+
+/// The arguments of `cblas_?gemm_batch`, with a group for every run of
+/// problems with equal parameters.
+struct GemmBatch<T> {
+    transa: Vec<CBLAS_TRANSPOSE>,
+    transb: Vec<CBLAS_TRANSPOSE>,
+    m: Vec<blasint>,
+    n: Vec<blasint>,
+    k: Vec<blasint>,
+    alpha: Vec<T>,
+    a: Vec<*const T>,
+    lda: Vec<blasint>,
+    b: Vec<*const T>,
+    ldb: Vec<blasint>,
+    beta: Vec<T>,
+    c: Vec<*mut T>,
+    ldc: Vec<blasint>,
+    group_size: Vec<blasint>,
+}
+
+impl<T: Copy + PartialEq> GemmBatch<T> {
+    /// Check the problems and collect the arguments.
+    fn new(layout: CBLAS_LAYOUT, problems: &mut [Gemm<T>]) -> Result<Self, CblasError> {
+        dimension("problems", problems.len())?;
+        let mut batch = GemmBatch {
+            transa: Vec::new(),
+            transb: Vec::new(),
+            m: Vec::new(),
+            n: Vec::new(),
+            k: Vec::new(),
+            alpha: Vec::new(),
+            a: Vec::with_capacity(problems.len()),
+            lda: Vec::new(),
+            b: Vec::with_capacity(problems.len()),
+            ldb: Vec::new(),
+            beta: Vec::new(),
+            c: Vec::with_capacity(problems.len()),
+            ldc: Vec::new(),
+            group_size: Vec::new(),
+        };
+        let mut last = None;
+        for problem in problems.iter_mut() {
+            let params = problem.params;
+            let (rows, cols) = level3::stored(params.transa, params.m, params.k);
+            let lda = matrix("a", "lda", problem.a.len(), layout, rows, cols, params.lda)?;
+            let (rows, cols) = level3::stored(params.transb, params.k, params.n);
+            let ldb = matrix("b", "ldb", problem.b.len(), layout, rows, cols, params.ldb)?;
+            let (rows, cols) = (params.m, params.n);
+            let ldc = matrix("c", "ldc", problem.c.len(), layout, rows, cols, params.ldc)?;
+            let m = dimension("m", params.m)?;
+            let (n, k) = (dimension("n", params.n)?, dimension("k", params.k)?);
+            batch.a.push(problem.a.as_ptr());
+            batch.b.push(problem.b.as_ptr());
+            batch.c.push(problem.c.as_mut_ptr());
+            if last == Some(params) {
+                *batch.group_size.last_mut().unwrap() += 1;
+                continue;
+            }
+            batch.transa.push(params.transa);
+            batch.transb.push(params.transb);
+            batch.m.push(m);
+            batch.n.push(n);
+            batch.k.push(k);
+            batch.alpha.push(params.alpha);
+            batch.lda.push(lda);
+            batch.ldb.push(ldb);
+            batch.beta.push(params.beta);
+            batch.ldc.push(ldc);
+            batch.group_size.push(1);
+            last = Some(params);
+        }
+        Ok(batch)
+    }
+}
+
+/// The arguments of `cblas_?trsm_batch`, with a group for every run of
+/// problems with equal parameters.
+struct TrsmBatch<T> {
+    side: Vec<CBLAS_SIDE>,
+    uplo: Vec<CBLAS_UPLO>,
+    transa: Vec<CBLAS_TRANSPOSE>,
+    diag: Vec<CBLAS_DIAG>,
+    m: Vec<blasint>,
+    n: Vec<blasint>,
+    alpha: Vec<T>,
+    a: Vec<*const T>,
+    lda: Vec<blasint>,
+    b: Vec<*mut T>,
+    ldb: Vec<blasint>,
+    group_size: Vec<blasint>,
+}
+
+impl<T: Copy + PartialEq> TrsmBatch<T> {
+    /// Check the problems and collect the arguments.
+    fn new(layout: CBLAS_LAYOUT, problems: &mut [Trsm<T>]) -> Result<Self, CblasError> {
+        dimension("problems", problems.len())?;
+        let mut batch = TrsmBatch {
+            side: Vec::new(),
+            uplo: Vec::new(),
+            transa: Vec::new(),
+            diag: Vec::new(),
+            m: Vec::new(),
+            n: Vec::new(),
+            alpha: Vec::new(),
+            a: Vec::with_capacity(problems.len()),
+            lda: Vec::new(),
+            b: Vec::with_capacity(problems.len()),
+            ldb: Vec::new(),
+            group_size: Vec::new(),
+        };
+        let mut last = None;
+        for problem in problems.iter_mut() {
+            let params = problem.params;
+            let order = level3::order(params.side, params.m, params.n);
+            let lda = matrix(
+                "a",
+                "lda",
+                problem.a.len(),
+                layout,
+                order,
+                order,
+                params.lda,
+            )?;
+            let (rows, cols) = (params.m, params.n);
+            let ldb = matrix("b", "ldb", problem.b.len(), layout, rows, cols, params.ldb)?;
+            let (m, n) = (dimension("m", params.m)?, dimension("n", params.n)?);
+            batch.a.push(problem.a.as_ptr());
+            batch.b.push(problem.b.as_mut_ptr());
+            if last == Some(params) {
+                *batch.group_size.last_mut().unwrap() += 1;
+                continue;
+            }
+            batch.side.push(params.side);
+            batch.uplo.push(params.uplo);
+            batch.transa.push(params.transa);
+            batch.diag.push(params.diag);
+            batch.m.push(m);
+            batch.n.push(n);
+            batch.alpha.push(params.alpha);
+            batch.lda.push(lda);
+            batch.ldb.push(ldb);
+            batch.group_size.push(1);
+            last = Some(params);
+        }
+        Ok(batch)
+    }
+}
+
+macro_rules! batch {
+    (
+        $t:ty,
+        $axpy_batch:ident => $caxpy_batch:ident,
+        $gemv_batch:ident => $cgemv_batch:ident,
+        $gemm_batch:ident => $cgemm_batch:ident,
+        $trsm_batch:ident => $ctrsm_batch:ident,
+    ) => {
+        /// Compute y := alpha * x + y for every problem of a batch.
+        pub fn $axpy_batch(problems: &mut [Axpy<$t>]) -> Result<(), CblasError> {
+            let batch = AxpyBatch::new(problems)?;
+            unsafe {
+                ::extensions::$caxpy_batch(
+                    batch.n.as_ptr(),
+                    batch.alpha.as_ptr(),
+                    batch.x.as_ptr(),
+                    batch.incx.as_ptr(),
+                    batch.y.as_ptr(),
+                    batch.incy.as_ptr(),
+                    batch.group_size.len() as blasint,
+                    batch.group_size.as_ptr(),
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute y := alpha * op(A) * x + beta * y for every problem of a
+        /// batch.
+        pub fn $gemv_batch(
+            layout: CBLAS_LAYOUT,
+            problems: &mut [Gemv<$t>],
+        ) -> Result<(), CblasError> {
+            let batch = GemvBatch::new(layout, problems)?;
+            unsafe {
+                ::extensions::$cgemv_batch(
+                    layout,
+                    batch.trans.as_ptr(),
+                    batch.m.as_ptr(),
+                    batch.n.as_ptr(),
+                    batch.alpha.as_ptr(),
+                    batch.a.as_ptr(),
+                    batch.lda.as_ptr(),
+                    batch.x.as_ptr(),
+                    batch.incx.as_ptr(),
+                    batch.beta.as_ptr(),
+                    batch.y.as_ptr(),
+                    batch.incy.as_ptr(),
+                    batch.group_size.len() as blasint,
+                    batch.group_size.as_ptr(),
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute C := alpha * op(A) * op(B) + beta * C for every problem of
+        /// a batch.
+        pub fn $gemm_batch(
+            layout: CBLAS_LAYOUT,
+            problems: &mut [Gemm<$t>],
+        ) -> Result<(), CblasError> {
+            let batch = GemmBatch::new(layout, problems)?;
+            unsafe {
+                ::extensions::$cgemm_batch(
+                    layout,
+                    batch.transa.as_ptr(),
+                    batch.transb.as_ptr(),
+                    batch.m.as_ptr(),
+                    batch.n.as_ptr(),
+                    batch.k.as_ptr(),
+                    batch.alpha.as_ptr(),
+                    batch.a.as_ptr(),
+                    batch.lda.as_ptr(),
+                    batch.b.as_ptr(),
+                    batch.ldb.as_ptr(),
+                    batch.beta.as_ptr(),
+                    batch.c.as_ptr(),
+                    batch.ldc.as_ptr(),
+                    batch.group_size.len() as blasint,
+                    batch.group_size.as_ptr(),
+                )
+            };
+            Ok(())
+        }
+
+        /// Solve op(A) * X = alpha * B or X * op(A) = alpha * B for every
+        /// problem of a batch, overwriting B with X.
+        pub fn $trsm_batch(
+            layout: CBLAS_LAYOUT,
+            problems: &mut [Trsm<$t>],
+        ) -> Result<(), CblasError> {
+            let batch = TrsmBatch::new(layout, problems)?;
+            unsafe {
+                ::extensions::$ctrsm_batch(
+                    layout,
+                    batch.side.as_ptr(),
+                    batch.uplo.as_ptr(),
+                    batch.transa.as_ptr(),
+                    batch.diag.as_ptr(),
+                    batch.m.as_ptr(),
+                    batch.n.as_ptr(),
+                    batch.alpha.as_ptr(),
+                    batch.a.as_ptr(),
+                    batch.lda.as_ptr(),
+                    batch.b.as_ptr(),
+                    batch.ldb.as_ptr(),
+                    batch.group_size.len() as blasint,
+                    batch.group_size.as_ptr(),
+                )
+            };
+            Ok(())
+        }
+    };
+}
+
+batch!(
+    c_float,
+    saxpy_batch => cblas_saxpy_batch,
+    sgemv_batch => cblas_sgemv_batch,
+    sgemm_batch => cblas_sgemm_batch,
+    strsm_batch => cblas_strsm_batch,
+);
+
+batch!(
+    c_double,
+    daxpy_batch => cblas_daxpy_batch,
+    dgemv_batch => cblas_dgemv_batch,
+    dgemm_batch => cblas_dgemm_batch,
+    dtrsm_batch => cblas_dtrsm_batch,
+);
+
+batch!(
+    c_float_complex,
+    caxpy_batch => cblas_caxpy_batch,
+    cgemv_batch => cblas_cgemv_batch,
+    cgemm_batch => cblas_cgemm_batch,
+    ctrsm_batch => cblas_ctrsm_batch,
+);
+
+batch!(
+    c_double_complex,
+    zaxpy_batch => cblas_zaxpy_batch,
+    zgemv_batch => cblas_zgemv_batch,
+    zgemm_batch => cblas_zgemm_batch,
+    ztrsm_batch => cblas_ztrsm_batch,
+);
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::{Axpy, AxpyBatch, AxpyParams, Gemv, GemvBatch, GemvParams};
+    use super::{Gemm, GemmBatch, GemmParams, Trsm, TrsmBatch, TrsmParams};
+    use {CblasColMajor, CblasLeft, CblasLower, CblasNoTrans, CblasNonUnit};
+    use {CblasRight, CblasRowMajor, CblasTrans, CblasUnit, CblasUpper};
+
+    #[test]
+    fn axpy_groups() {
+        let params = AxpyParams {
+            n: 2,
+            alpha: 1.0,
+            incx: 1,
+            incy: 1,
+        };
+        let reversed = AxpyParams { incx: -1, ..params };
+        let all = [params, params, reversed, reversed, reversed, params];
+        let x = [1.0; 2];
+        let mut y = [[0.0; 2]; 6];
+        let mut problems = all
+            .iter()
+            .zip(y.iter_mut())
+            .map(|(&params, y)| Axpy { params, x: &x, y })
+            .collect::<Vec<_>>();
+        let batch = AxpyBatch::new(&mut problems).unwrap();
+        assert_eq!(batch.group_size, [2, 3, 1]);
+        assert_eq!(batch.incx, [1, -1, 1]);
+        assert_eq!(batch.y.len(), 6);
+        assert_eq!(batch.y[3], problems[3].y.as_mut_ptr());
+    }
+
+    #[test]
+    fn gemv_groups() {
+        let params = GemvParams {
+            trans: CblasNoTrans,
+            m: 2,
+            n: 2,
+            alpha: 1.0,
+            lda: 2,
+            incx: 1,
+            beta: 0.0,
+            incy: 1,
+        };
+        let transposed = GemvParams {
+            trans: CblasTrans,
+            ..params
+        };
+        let accumulated = GemvParams {
+            beta: 1.0,
+            ..params
+        };
+        let all = [params, transposed, transposed, accumulated, params];
+        let (a, x) = ([1.0; 4], [1.0; 2]);
+        let mut y = [[0.0; 2]; 5];
+        let mut problems = all
+            .iter()
+            .zip(y.iter_mut())
+            .map(|(&params, y)| Gemv {
+                params,
+                a: &a,
+                x: &x,
+                y,
+            })
+            .collect::<Vec<_>>();
+        let batch = GemvBatch::new(CblasRowMajor, &mut problems).unwrap();
+        assert_eq!(batch.group_size, [1, 2, 1, 1]);
+        assert_eq!(
+            batch.trans,
+            [CblasNoTrans, CblasTrans, CblasNoTrans, CblasNoTrans]
+        );
+        assert_eq!(batch.beta, [0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(batch.y.len(), 5);
+    }
+
+    #[test]
+    fn gemm_groups() {
+        let params = GemmParams {
+            transa: CblasNoTrans,
+            transb: CblasNoTrans,
+            m: 2,
+            n: 2,
+            k: 2,
+            alpha: 1.0,
+            lda: 2,
+            ldb: 2,
+            beta: 0.0,
+            ldc: 2,
+        };
+        let transposed = GemmParams {
+            transa: CblasTrans,
+            ..params
+        };
+        let scaled = GemmParams {
+            alpha: 2.0,
+            ..params
+        };
+        // Only consecutive problems with equal parameters are grouped.
+        let all = [params, params, params, transposed, scaled, params, params];
+        let (a, b) = ([1.0; 4], [1.0; 4]);
+        let mut c = [[0.0; 4]; 7];
+        let mut problems = all
+            .iter()
+            .zip(c.iter_mut())
+            .map(|(&params, c)| Gemm {
+                params,
+                a: &a,
+                b: &b,
+                c,
+            })
+            .collect::<Vec<_>>();
+        let batch = GemmBatch::new(CblasColMajor, &mut problems).unwrap();
+        assert_eq!(batch.group_size, [3, 1, 1, 2]);
+        assert_eq!(
+            batch.transa,
+            [CblasNoTrans, CblasTrans, CblasNoTrans, CblasNoTrans]
+        );
+        assert_eq!(batch.alpha, [1.0, 1.0, 2.0, 1.0]);
+        assert_eq!(batch.c.len(), 7);
+        assert_eq!(batch.c[6], problems[6].c.as_mut_ptr());
+    }
+
+    #[test]
+    fn trsm_groups() {
+        let params = TrsmParams {
+            side: CblasLeft,
+            uplo: CblasUpper,
+            transa: CblasNoTrans,
+            diag: CblasNonUnit,
+            m: 2,
+            n: 2,
+            alpha: 1.0,
+            lda: 2,
+            ldb: 2,
+        };
+        let right = TrsmParams {
+            side: CblasRight,
+            ..params
+        };
+        let lower = TrsmParams {
+            uplo: CblasLower,
+            diag: CblasUnit,
+            ..params
+        };
+        let all = [right, params, params, lower, lower, lower];
+        let a = [1.0; 4];
+        let mut b = [[0.0; 4]; 6];
+        let mut problems = all
+            .iter()
+            .zip(b.iter_mut())
+            .map(|(&params, b)| Trsm { params, a: &a, b })
+            .collect::<Vec<_>>();
+        let batch = TrsmBatch::new(CblasColMajor, &mut problems).unwrap();
+        assert_eq!(batch.group_size, [1, 2, 3]);
+        assert_eq!(batch.side, [CblasRight, CblasLeft, CblasLeft]);
+        assert_eq!(batch.uplo, [CblasUpper, CblasUpper, CblasLower]);
+        assert_eq!(batch.diag, [CblasNonUnit, CblasNonUnit, CblasUnit]);
+        assert_eq!(batch.b.len(), 6);
+    }
+}
@@ -0,0 +1,412 @@
+//! Level 1 routines, which operate on vectors.
+
+use libc::{c_double, c_float};
+
+use super::{dimension, input, positive, vector, CblasError};
+use {c_double_complex, c_float_complex};
+
+macro_rules! standard {
+    (
+        $t:ty, $real:ty, $pass:ident,
+        $swap:ident => $cswap:ident,
+        $copy:ident => $ccopy:ident,
+        $axpy:ident => $caxpy:ident,
+        $scal:ident => $cscal:ident,
+        $nrm2:ident => $cnrm2:ident,
+        $asum:ident => $casum:ident,
+        $iamax:ident => $ciamax:ident,
+    ) => {
+        /// Swap x and y.
+        pub fn $swap(
+            n: usize,
+            x: &mut [$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$cswap(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy) };
+            Ok(())
+        }
+
+        /// Copy x into y.
+        pub fn $copy(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let incx = input("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$ccopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy) };
+            Ok(())
+        }
+
+        /// Compute y := alpha * x + y.
+        pub fn $axpy(
+            n: usize,
+            alpha: $t,
+            x: &[$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let incx = input("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$caxpy(
+                    n,
+                    scalar!($pass, alpha),
+                    x.as_ptr(),
+                    incx,
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute x := alpha * x.
+        pub fn $scal(n: usize, alpha: $t, x: &mut [$t], incx: isize) -> Result<(), CblasError> {
+            let incx = positive("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$cscal(n, scalar!($pass, alpha), x.as_mut_ptr(), incx) };
+            Ok(())
+        }
+
+        /// Compute the Euclidean norm of x.
+        pub fn $nrm2(n: usize, x: &[$t], incx: isize) -> Result<$real, CblasError> {
+            let incx = positive("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            Ok(unsafe { ::$cnrm2(n, x.as_ptr(), incx) })
+        }
+
+        /// Compute the sum of the absolute values of the real and imaginary
+        /// parts of the elements of x.
+        pub fn $asum(n: usize, x: &[$t], incx: isize) -> Result<$real, CblasError> {
+            let incx = positive("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            Ok(unsafe { ::$casum(n, x.as_ptr(), incx) })
+        }
+
+        /// Find the index of the first element of x with the largest sum of
+        /// the absolute values of the real and imaginary parts, which is zero
+        /// if n is zero.
+        pub fn $iamax(n: usize, x: &[$t], incx: isize) -> Result<usize, CblasError> {
+            let incx = positive("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            Ok(unsafe { ::$ciamax(n, x.as_ptr(), incx) } as usize)
+        }
+    };
+}
+
+standard!(
+    c_float, c_float, value,
+    sswap => cblas_sswap,
+    scopy => cblas_scopy,
+    saxpy => cblas_saxpy,
+    sscal => cblas_sscal,
+    snrm2 => cblas_snrm2,
+    sasum => cblas_sasum,
+    isamax => cblas_isamax,
+);
+
+standard!(
+    c_double, c_double, value,
+    dswap => cblas_dswap,
+    dcopy => cblas_dcopy,
+    daxpy => cblas_daxpy,
+    dscal => cblas_dscal,
+    dnrm2 => cblas_dnrm2,
+    dasum => cblas_dasum,
+    idamax => cblas_idamax,
+);
+
+standard!(
+    c_float_complex, c_float, pointer,
+    cswap => cblas_cswap,
+    ccopy => cblas_ccopy,
+    caxpy => cblas_caxpy,
+    cscal => cblas_cscal,
+    scnrm2 => cblas_scnrm2,
+    scasum => cblas_scasum,
+    icamax => cblas_icamax,
+);
+
+standard!(
+    c_double_complex, c_double, pointer,
+    zswap => cblas_zswap,
+    zcopy => cblas_zcopy,
+    zaxpy => cblas_zaxpy,
+    zscal => cblas_zscal,
+    dznrm2 => cblas_dznrm2,
+    dzasum => cblas_dzasum,
+    izamax => cblas_izamax,
+);
+
+macro_rules! real {
+    (
+        $t:ty,
+        $dot:ident => $cdot:ident,
+        $rotg:ident => $crotg:ident,
+        $rotmg:ident => $crotmg:ident,
+        $rot:ident => $crot:ident,
+        $rotm:ident => $crotm:ident,
+    ) => {
+        /// Compute the dot product of x and y.
+        pub fn $dot(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+        ) -> Result<$t, CblasError> {
+            let incx = input("x", "incx", x.len(), n, incx)?;
+            let incy = input("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            Ok(unsafe { ::$cdot(n, x.as_ptr(), incx, y.as_ptr(), incy) })
+        }
+
+        /// Construct a Givens rotation zeroing b, replacing a with r and b
+        /// with z.
+        pub fn $rotg(a: &mut $t, b: &mut $t, c: &mut $t, s: &mut $t) {
+            unsafe { ::$crotg(a, b, c, s) }
+        }
+
+        /// Construct a modified Givens rotation.
+        pub fn $rotmg(d1: &mut $t, d2: &mut $t, b1: &mut $t, b2: $t, p: &mut [$t; 5]) {
+            unsafe { ::$crotmg(d1, d2, b1, b2, p.as_mut_ptr()) }
+        }
+
+        /// Apply a Givens rotation to x and y.
+        pub fn $rot(
+            n: usize,
+            x: &mut [$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+            c: $t,
+            s: $t,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$crot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s) };
+            Ok(())
+        }
+
+        /// Apply a modified Givens rotation to x and y.
+        pub fn $rotm(
+            n: usize,
+            x: &mut [$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+            p: &[$t; 5],
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$crotm(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, p.as_ptr()) };
+            Ok(())
+        }
+    };
+}
+
+real!(
+    c_float,
+    sdot => cblas_sdot,
+    srotg => cblas_srotg,
+    srotmg => cblas_srotmg,
+    srot => cblas_srot,
+    srotm => cblas_srotm,
+);
+
+real!(
+    c_double,
+    ddot => cblas_ddot,
+    drotg => cblas_drotg,
+    drotmg => cblas_drotmg,
+    drot => cblas_drot,
+    drotm => cblas_drotm,
+);
+
+macro_rules! complex {
+    (
+        $t:ty, $real:ty,
+        $dotu:ident, $dotu_value:ident => $cdotu_sub:ident,
+        $dotc:ident, $dotc_value:ident => $cdotc_sub:ident,
+        $rscal:ident => $crscal:ident,
+        $abs1:ident => $cabs1:ident,
+    ) => {
+        /// Compute the dot product of x and y, storing it in `dotu`.
+        pub fn $dotu(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            dotu: &mut $t,
+        ) -> Result<(), CblasError> {
+            let incx = input("x", "incx", x.len(), n, incx)?;
+            let incy = input("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$cdotu_sub(n, x.as_ptr(), incx, y.as_ptr(), incy, dotu) };
+            Ok(())
+        }
+
+        /// Compute the dot product of the conjugate of x and y, storing it in
+        /// `dotc`.
+        pub fn $dotc(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            dotc: &mut $t,
+        ) -> Result<(), CblasError> {
+            let incx = input("x", "incx", x.len(), n, incx)?;
+            let incy = input("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$cdotc_sub(n, x.as_ptr(), incx, y.as_ptr(), incy, dotc) };
+            Ok(())
+        }
+
+        /// Compute the dot product of x and y, returning it.
+        pub fn $dotu_value(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+        ) -> Result<$t, CblasError> {
+            let mut dotu = [0.0; 2];
+            $dotu(n, x, incx, y, incy, &mut dotu)?;
+            Ok(dotu)
+        }
+
+        /// Compute the dot product of the conjugate of x and y, returning it.
+        pub fn $dotc_value(
+            n: usize,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+        ) -> Result<$t, CblasError> {
+            let mut dotc = [0.0; 2];
+            $dotc(n, x, incx, y, incy, &mut dotc)?;
+            Ok(dotc)
+        }
+
+        /// Compute x := alpha * x for a real alpha.
+        pub fn $rscal(n: usize, alpha: $real, x: &mut [$t], incx: isize) -> Result<(), CblasError> {
+            let incx = positive("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$crscal(n, alpha, x.as_mut_ptr(), incx) };
+            Ok(())
+        }
+
+        /// Compute the sum of the absolute values of the real and imaginary
+        /// parts of z.
+        pub fn $abs1(z: &$t) -> $real {
+            unsafe { ::$cabs1(z) }
+        }
+    };
+}
+
+complex!(
+    c_float_complex, c_float,
+    cdotu, cdotu_value => cblas_cdotu_sub,
+    cdotc, cdotc_value => cblas_cdotc_sub,
+    csscal => cblas_csscal,
+    scabs1 => cblas_scabs1,
+);
+
+complex!(
+    c_double_complex, c_double,
+    zdotu, zdotu_value => cblas_zdotu_sub,
+    zdotc, zdotc_value => cblas_zdotc_sub,
+    zdscal => cblas_zdscal,
+    dcabs1 => cblas_dcabs1,
+);
+
+// Complex rotations, which are provided via the `extensions` module.
+#[cfg(feature = "extensions")]
+macro_rules! rotation {
+    (
+        $t:ty, $real:ty,
+        $rotg:ident => $crotg:ident,
+        $rot:ident => $crot:ident,
+    ) => {
+        /// Construct a Givens rotation zeroing b, replacing a with r.
+        pub fn $rotg(a: &mut $t, b: &mut $t, c: &mut $real, s: &mut $t) {
+            unsafe { ::extensions::$crotg(a, b, c, s) }
+        }
+
+        /// Apply a Givens rotation with a real cosine and sine to x and y.
+        pub fn $rot(
+            n: usize,
+            x: &mut [$t],
+            incx: isize,
+            y: &mut [$t],
+            incy: isize,
+            c: $real,
+            s: $real,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe { ::extensions::$crot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s) };
+            Ok(())
+        }
+    };
+}
+
+#[cfg(feature = "extensions")]
+rotation!(
+    c_float_complex, c_float,
+    crotg => cblas_crotg,
+    csrot => cblas_csrot,
+);
+
+#[cfg(feature = "extensions")]
+rotation!(
+    c_double_complex, c_double,
+    zrotg => cblas_zrotg,
+    zdrot => cblas_zdrot,
+);
+
+/// Compute the dot product of x and y plus alpha with the accumulation in
+/// double precision.
+pub fn sdsdot(
+    n: usize,
+    alpha: c_float,
+    x: &[c_float],
+    incx: isize,
+    y: &[c_float],
+    incy: isize,
+) -> Result<c_float, CblasError> {
+    let incx = input("x", "incx", x.len(), n, incx)?;
+    let incy = input("y", "incy", y.len(), n, incy)?;
+    let n = dimension("n", n)?;
+    Ok(unsafe { ::cblas_sdsdot(n, alpha, x.as_ptr(), incx, y.as_ptr(), incy) })
+}
+
+/// Compute the dot product of x and y in double precision.
+pub fn dsdot(
+    n: usize,
+    x: &[c_float],
+    incx: isize,
+    y: &[c_float],
+    incy: isize,
+) -> Result<c_double, CblasError> {
+    let incx = input("x", "incx", x.len(), n, incx)?;
+    let incy = input("y", "incy", y.len(), n, incy)?;
+    let n = dimension("n", n)?;
+    Ok(unsafe { ::cblas_dsdot(n, x.as_ptr(), incx, y.as_ptr(), incy) })
+}
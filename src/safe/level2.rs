@@ -0,0 +1,710 @@
+//! Level 2 routines, which operate on matrices and vectors.
+//!
+//! In the descriptions, `op(A)` is `A`, its transpose, or its conjugate
+//! transpose, depending on `trans`, and `'` denotes the transpose for real
+//! types and the conjugate transpose for complex ones.
+
+#![allow(clippy::too_many_arguments)]
+
+use libc::{c_double, c_float};
+
+use super::{band, dimension, matrix, packed, vector, CblasError};
+use {c_double_complex, c_float_complex, CblasNoTrans};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// Return the lengths of x and y in y := op(A) * x for an m-by-n A.
+pub(super) fn operands(trans: CBLAS_TRANSPOSE, m: usize, n: usize) -> (usize, usize) {
+    match trans {
+        CblasNoTrans => (n, m),
+        _ => (m, n),
+    }
+}
+
+macro_rules! general {
+    (
+        $t:ty, $pass:ident,
+        $gemv:ident => $cgemv:ident,
+        $gbmv:ident => $cgbmv:ident,
+        $trmv:ident => $ctrmv:ident,
+        $tbmv:ident => $ctbmv:ident,
+        $tpmv:ident => $ctpmv:ident,
+        $trsv:ident => $ctrsv:ident,
+        $tbsv:ident => $ctbsv:ident,
+        $tpsv:ident => $ctpsv:ident,
+    ) => {
+        /// Compute y := alpha * op(A) * x + beta * y for an m-by-n A.
+        pub fn $gemv(
+            layout: CBLAS_LAYOUT,
+            trans: CBLAS_TRANSPOSE,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let (lenx, leny) = operands(trans, m, n);
+            let lda = matrix("a", "lda", a.len(), layout, m, n, lda)?;
+            let incx = vector("x", "incx", x.len(), lenx, incx)?;
+            let incy = vector("y", "incy", y.len(), leny, incy)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            unsafe {
+                ::$cgemv(
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    x.as_ptr(),
+                    incx,
+                    scalar!($pass, beta),
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute y := alpha * op(A) * x + beta * y for an m-by-n A with kl
+        /// subdiagonals and ku superdiagonals stored in band form.
+        pub fn $gbmv(
+            layout: CBLAS_LAYOUT,
+            trans: CBLAS_TRANSPOSE,
+            m: usize,
+            n: usize,
+            kl: usize,
+            ku: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let (lenx, leny) = operands(trans, m, n);
+            let lda = band("a", "lda", a.len(), layout, m, n, kl, ku, lda)?;
+            let incx = vector("x", "incx", x.len(), lenx, incx)?;
+            let incy = vector("y", "incy", y.len(), leny, incy)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            let (kl, ku) = (dimension("kl", kl)?, dimension("ku", ku)?);
+            unsafe {
+                ::$cgbmv(
+                    layout,
+                    trans,
+                    m,
+                    n,
+                    kl,
+                    ku,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    x.as_ptr(),
+                    incx,
+                    scalar!($pass, beta),
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute x := op(A) * x for a triangular A of order n.
+        pub fn $trmv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            a: &[$t],
+            lda: usize,
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            let lda = matrix("a", "lda", a.len(), layout, n, n, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$ctrmv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    a.as_ptr(),
+                    lda,
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute x := op(A) * x for a triangular A of order n with k
+        /// off-diagonals stored in band form.
+        pub fn $tbmv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            k: usize,
+            a: &[$t],
+            lda: usize,
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            let lda = band("a", "lda", a.len(), layout, n, n, 0, k, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let (n, k) = (dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$ctbmv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    k,
+                    a.as_ptr(),
+                    lda,
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute x := op(A) * x for a triangular A of order n stored in
+        /// packed form.
+        pub fn $tpmv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            ap: &[$t],
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            packed("ap", ap.len(), n)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$ctpmv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    ap.as_ptr(),
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+
+        /// Solve op(A) * x = b for a triangular A of order n, overwriting b
+        /// given in x.
+        pub fn $trsv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            a: &[$t],
+            lda: usize,
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            let lda = matrix("a", "lda", a.len(), layout, n, n, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$ctrsv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    a.as_ptr(),
+                    lda,
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+
+        /// Solve op(A) * x = b for a triangular A of order n with k
+        /// off-diagonals stored in band form, overwriting b given in x.
+        pub fn $tbsv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            k: usize,
+            a: &[$t],
+            lda: usize,
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            let lda = band("a", "lda", a.len(), layout, n, n, 0, k, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let (n, k) = (dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$ctbsv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    k,
+                    a.as_ptr(),
+                    lda,
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+
+        /// Solve op(A) * x = b for a triangular A of order n stored in packed
+        /// form, overwriting b given in x.
+        pub fn $tpsv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            n: usize,
+            ap: &[$t],
+            x: &mut [$t],
+            incx: isize,
+        ) -> Result<(), CblasError> {
+            packed("ap", ap.len(), n)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$ctpsv(
+                    layout,
+                    uplo,
+                    trans,
+                    diag,
+                    n,
+                    ap.as_ptr(),
+                    x.as_mut_ptr(),
+                    incx,
+                )
+            };
+            Ok(())
+        }
+    };
+}
+
+general!(
+    c_float, value,
+    sgemv => cblas_sgemv,
+    sgbmv => cblas_sgbmv,
+    strmv => cblas_strmv,
+    stbmv => cblas_stbmv,
+    stpmv => cblas_stpmv,
+    strsv => cblas_strsv,
+    stbsv => cblas_stbsv,
+    stpsv => cblas_stpsv,
+);
+
+general!(
+    c_double, value,
+    dgemv => cblas_dgemv,
+    dgbmv => cblas_dgbmv,
+    dtrmv => cblas_dtrmv,
+    dtbmv => cblas_dtbmv,
+    dtpmv => cblas_dtpmv,
+    dtrsv => cblas_dtrsv,
+    dtbsv => cblas_dtbsv,
+    dtpsv => cblas_dtpsv,
+);
+
+general!(
+    c_float_complex, pointer,
+    cgemv => cblas_cgemv,
+    cgbmv => cblas_cgbmv,
+    ctrmv => cblas_ctrmv,
+    ctbmv => cblas_ctbmv,
+    ctpmv => cblas_ctpmv,
+    ctrsv => cblas_ctrsv,
+    ctbsv => cblas_ctbsv,
+    ctpsv => cblas_ctpsv,
+);
+
+general!(
+    c_double_complex, pointer,
+    zgemv => cblas_zgemv,
+    zgbmv => cblas_zgbmv,
+    ztrmv => cblas_ztrmv,
+    ztbmv => cblas_ztbmv,
+    ztpmv => cblas_ztpmv,
+    ztrsv => cblas_ztrsv,
+    ztbsv => cblas_ztbsv,
+    ztpsv => cblas_ztpsv,
+);
+
+// The routines for symmetric matrices of real types and for Hermitian ones of
+// complex types, which take the same arguments.
+macro_rules! symmetric {
+    (
+        $t:ty, $real:ty, $pass:ident,
+        $symv:ident => $csymv:ident,
+        $sbmv:ident => $csbmv:ident,
+        $spmv:ident => $cspmv:ident,
+        $syr:ident => $csyr:ident,
+        $spr:ident => $cspr:ident,
+        $syr2:ident => $csyr2:ident,
+        $spr2:ident => $cspr2:ident,
+    ) => {
+        /// Compute y := alpha * A * x + beta * y for a symmetric or Hermitian
+        /// A of order n.
+        pub fn $symv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let lda = matrix("a", "lda", a.len(), layout, n, n, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$csymv(
+                    layout,
+                    uplo,
+                    n,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    x.as_ptr(),
+                    incx,
+                    scalar!($pass, beta),
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute y := alpha * A * x + beta * y for a symmetric or Hermitian
+        /// A of order n with k off-diagonals stored in band form.
+        pub fn $sbmv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            k: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            let lda = band("a", "lda", a.len(), layout, n, n, 0, k, lda)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let (n, k) = (dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$csbmv(
+                    layout,
+                    uplo,
+                    n,
+                    k,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    x.as_ptr(),
+                    incx,
+                    scalar!($pass, beta),
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute y := alpha * A * x + beta * y for a symmetric or Hermitian
+        /// A of order n stored in packed form.
+        pub fn $spmv(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            ap: &[$t],
+            x: &[$t],
+            incx: isize,
+            beta: $t,
+            y: &mut [$t],
+            incy: isize,
+        ) -> Result<(), CblasError> {
+            packed("ap", ap.len(), n)?;
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$cspmv(
+                    layout,
+                    uplo,
+                    n,
+                    scalar!($pass, alpha),
+                    ap.as_ptr(),
+                    x.as_ptr(),
+                    incx,
+                    scalar!($pass, beta),
+                    y.as_mut_ptr(),
+                    incy,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute A := alpha * x * x' + A for a symmetric or Hermitian A of
+        /// order n.
+        pub fn $syr(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $real,
+            x: &[$t],
+            incx: isize,
+            a: &mut [$t],
+            lda: usize,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let lda = matrix("a", "lda", a.len(), layout, n, n, lda)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$csyr(
+                    layout,
+                    uplo,
+                    n,
+                    alpha,
+                    x.as_ptr(),
+                    incx,
+                    a.as_mut_ptr(),
+                    lda,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute A := alpha * x * x' + A for a symmetric or Hermitian A of
+        /// order n stored in packed form.
+        pub fn $spr(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $real,
+            x: &[$t],
+            incx: isize,
+            ap: &mut [$t],
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            packed("ap", ap.len(), n)?;
+            let n = dimension("n", n)?;
+            unsafe { ::$cspr(layout, uplo, n, alpha, x.as_ptr(), incx, ap.as_mut_ptr()) };
+            Ok(())
+        }
+
+        /// Compute A := alpha * x * y' + alpha' * y * x' + A for a symmetric
+        /// or Hermitian A of order n.
+        pub fn $syr2(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            a: &mut [$t],
+            lda: usize,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let lda = matrix("a", "lda", a.len(), layout, n, n, lda)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$csyr2(
+                    layout,
+                    uplo,
+                    n,
+                    scalar!($pass, alpha),
+                    x.as_ptr(),
+                    incx,
+                    y.as_ptr(),
+                    incy,
+                    a.as_mut_ptr(),
+                    lda,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute A := alpha * x * y' + alpha' * y * x' + A for a symmetric
+        /// or Hermitian A of order n stored in packed form.
+        pub fn $spr2(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            n: usize,
+            alpha: $t,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            ap: &mut [$t],
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), n, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            packed("ap", ap.len(), n)?;
+            let n = dimension("n", n)?;
+            unsafe {
+                ::$cspr2(
+                    layout,
+                    uplo,
+                    n,
+                    scalar!($pass, alpha),
+                    x.as_ptr(),
+                    incx,
+                    y.as_ptr(),
+                    incy,
+                    ap.as_mut_ptr(),
+                )
+            };
+            Ok(())
+        }
+    };
+}
+
+symmetric!(
+    c_float, c_float, value,
+    ssymv => cblas_ssymv,
+    ssbmv => cblas_ssbmv,
+    sspmv => cblas_sspmv,
+    ssyr => cblas_ssyr,
+    sspr => cblas_sspr,
+    ssyr2 => cblas_ssyr2,
+    sspr2 => cblas_sspr2,
+);
+
+symmetric!(
+    c_double, c_double, value,
+    dsymv => cblas_dsymv,
+    dsbmv => cblas_dsbmv,
+    dspmv => cblas_dspmv,
+    dsyr => cblas_dsyr,
+    dspr => cblas_dspr,
+    dsyr2 => cblas_dsyr2,
+    dspr2 => cblas_dspr2,
+);
+
+symmetric!(
+    c_float_complex, c_float, pointer,
+    chemv => cblas_chemv,
+    chbmv => cblas_chbmv,
+    chpmv => cblas_chpmv,
+    cher => cblas_cher,
+    chpr => cblas_chpr,
+    cher2 => cblas_cher2,
+    chpr2 => cblas_chpr2,
+);
+
+symmetric!(
+    c_double_complex, c_double, pointer,
+    zhemv => cblas_zhemv,
+    zhbmv => cblas_zhbmv,
+    zhpmv => cblas_zhpmv,
+    zher => cblas_zher,
+    zhpr => cblas_zhpr,
+    zher2 => cblas_zher2,
+    zhpr2 => cblas_zhpr2,
+);
+
+macro_rules! ger {
+    ($t:ty, $pass:ident, $($(#[$attr:meta])* $ger:ident => $cger:ident,)*) => {$(
+        $(#[$attr])*
+        pub fn $ger(
+            layout: CBLAS_LAYOUT,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            x: &[$t],
+            incx: isize,
+            y: &[$t],
+            incy: isize,
+            a: &mut [$t],
+            lda: usize,
+        ) -> Result<(), CblasError> {
+            let incx = vector("x", "incx", x.len(), m, incx)?;
+            let incy = vector("y", "incy", y.len(), n, incy)?;
+            let lda = matrix("a", "lda", a.len(), layout, m, n, lda)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            unsafe {
+                ::$cger(
+                    layout,
+                    m,
+                    n,
+                    scalar!($pass, alpha),
+                    x.as_ptr(),
+                    incx,
+                    y.as_ptr(),
+                    incy,
+                    a.as_mut_ptr(),
+                    lda,
+                )
+            };
+            Ok(())
+        }
+    )*};
+}
+
+ger!(
+    c_float, value,
+    /// Compute A := alpha * x * y' + A for an m-by-n A.
+    sger => cblas_sger,
+);
+
+ger!(
+    c_double, value,
+    /// Compute A := alpha * x * y' + A for an m-by-n A.
+    dger => cblas_dger,
+);
+
+ger!(
+    c_float_complex, pointer,
+    /// Compute A := alpha * x * y^T + A for an m-by-n A.
+    cgeru => cblas_cgeru,
+    /// Compute A := alpha * x * y' + A for an m-by-n A.
+    cgerc => cblas_cgerc,
+);
+
+ger!(
+    c_double_complex, pointer,
+    /// Compute A := alpha * x * y^T + A for an m-by-n A.
+    zgeru => cblas_zgeru,
+    /// Compute A := alpha * x * y' + A for an m-by-n A.
+    zgerc => cblas_zgerc,
+);
@@ -0,0 +1,375 @@
+//! Level 3 routines, which operate on matrices.
+//!
+//! In the descriptions, `op(A)` is `A`, its transpose, or its conjugate
+//! transpose, depending on `trans`, and `'` denotes the transpose for real
+//! types and the conjugate transpose for complex ones.
+
+#![allow(clippy::too_many_arguments)]
+
+use libc::{c_double, c_float};
+
+use super::{dimension, matrix, CblasError};
+use {c_double_complex, c_float_complex, CblasConjTrans, CblasLeft, CblasNoTrans, CblasTrans};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// Return the numbers of rows and columns of A for a rows-by-cols op(A).
+pub(super) fn stored(trans: CBLAS_TRANSPOSE, rows: usize, cols: usize) -> (usize, usize) {
+    match trans {
+        CblasNoTrans => (rows, cols),
+        _ => (cols, rows),
+    }
+}
+
+/// Return the order of A given the side on which it is applied to an m-by-n
+/// matrix.
+pub(super) fn order(side: CBLAS_SIDE, m: usize, n: usize) -> usize {
+    match side {
+        CblasLeft => m,
+        _ => n,
+    }
+}
+
+macro_rules! general {
+    (
+        $t:ty, $pass:ident,
+        $gemm:ident => $cgemm:ident,
+        $trmm:ident => $ctrmm:ident,
+        $trsm:ident => $ctrsm:ident,
+    ) => {
+        /// Compute C := alpha * op(A) * op(B) + beta * C for an m-by-k op(A),
+        /// a k-by-n op(B), and an m-by-n C.
+        pub fn $gemm(
+            layout: CBLAS_LAYOUT,
+            transa: CBLAS_TRANSPOSE,
+            transb: CBLAS_TRANSPOSE,
+            m: usize,
+            n: usize,
+            k: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &[$t],
+            ldb: usize,
+            beta: $t,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            let (rows, cols) = stored(transa, m, k);
+            let lda = matrix("a", "lda", a.len(), layout, rows, cols, lda)?;
+            let (rows, cols) = stored(transb, k, n);
+            let ldb = matrix("b", "ldb", b.len(), layout, rows, cols, ldb)?;
+            let ldc = matrix("c", "ldc", c.len(), layout, m, n, ldc)?;
+            let (m, n, k) = (dimension("m", m)?, dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$cgemm(
+                    layout,
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    b.as_ptr(),
+                    ldb,
+                    scalar!($pass, beta),
+                    c.as_mut_ptr(),
+                    ldc,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute B := alpha * op(A) * B or B := alpha * B * op(A), depending
+        /// on `side`, for a triangular A and an m-by-n B.
+        pub fn $trmm(
+            layout: CBLAS_LAYOUT,
+            side: CBLAS_SIDE,
+            uplo: CBLAS_UPLO,
+            transa: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &mut [$t],
+            ldb: usize,
+        ) -> Result<(), CblasError> {
+            let order = order(side, m, n);
+            let lda = matrix("a", "lda", a.len(), layout, order, order, lda)?;
+            let ldb = matrix("b", "ldb", b.len(), layout, m, n, ldb)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            unsafe {
+                ::$ctrmm(
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    b.as_mut_ptr(),
+                    ldb,
+                )
+            };
+            Ok(())
+        }
+
+        /// Solve op(A) * X = alpha * B or X * op(A) = alpha * B, depending on
+        /// `side`, for a triangular A and an m-by-n B, overwriting B with X.
+        pub fn $trsm(
+            layout: CBLAS_LAYOUT,
+            side: CBLAS_SIDE,
+            uplo: CBLAS_UPLO,
+            transa: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &mut [$t],
+            ldb: usize,
+        ) -> Result<(), CblasError> {
+            let order = order(side, m, n);
+            let lda = matrix("a", "lda", a.len(), layout, order, order, lda)?;
+            let ldb = matrix("b", "ldb", b.len(), layout, m, n, ldb)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            unsafe {
+                ::$ctrsm(
+                    layout,
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m,
+                    n,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    b.as_mut_ptr(),
+                    ldb,
+                )
+            };
+            Ok(())
+        }
+    };
+}
+
+general!(
+    c_float, value,
+    sgemm => cblas_sgemm,
+    strmm => cblas_strmm,
+    strsm => cblas_strsm,
+);
+
+general!(
+    c_double, value,
+    dgemm => cblas_dgemm,
+    dtrmm => cblas_dtrmm,
+    dtrsm => cblas_dtrsm,
+);
+
+general!(
+    c_float_complex, pointer,
+    cgemm => cblas_cgemm,
+    ctrmm => cblas_ctrmm,
+    ctrsm => cblas_ctrsm,
+);
+
+general!(
+    c_double_complex, pointer,
+    zgemm => cblas_zgemm,
+    ztrmm => cblas_ztrmm,
+    ztrsm => cblas_ztrsm,
+);
+
+// The routines for symmetric and Hermitian matrices, which take the same
+// arguments except that the Hermitian rank-k updates take a real beta and, in
+// the case of herk, a real alpha. The valid values of `trans` for the updates
+// are given as patterns, since the conjugate transpose is invalid for complex
+// symmetric matrices and the transpose for Hermitian ones.
+macro_rules! symmetric {
+    (
+        $t:ty, $pass:ident, $k:ty, $kpass:ident, $($valid:pat)|*,
+        $symm:ident => $csymm:ident,
+        $syrk:ident => $csyrk:ident,
+        $syr2k:ident => $csyr2k:ident,
+    ) => {
+        /// Compute C := alpha * A * B + beta * C or
+        /// C := alpha * B * A + beta * C, depending on `side`, for a symmetric
+        /// or Hermitian A and m-by-n B and C.
+        pub fn $symm(
+            layout: CBLAS_LAYOUT,
+            side: CBLAS_SIDE,
+            uplo: CBLAS_UPLO,
+            m: usize,
+            n: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &[$t],
+            ldb: usize,
+            beta: $t,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            let order = order(side, m, n);
+            let lda = matrix("a", "lda", a.len(), layout, order, order, lda)?;
+            let ldb = matrix("b", "ldb", b.len(), layout, m, n, ldb)?;
+            let ldc = matrix("c", "ldc", c.len(), layout, m, n, ldc)?;
+            let (m, n) = (dimension("m", m)?, dimension("n", n)?);
+            unsafe {
+                ::$csymm(
+                    layout,
+                    side,
+                    uplo,
+                    m,
+                    n,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    b.as_ptr(),
+                    ldb,
+                    scalar!($pass, beta),
+                    c.as_mut_ptr(),
+                    ldc,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute C := alpha * op(A) * op(A)' + beta * C for an n-by-k op(A)
+        /// and a symmetric or Hermitian C of order n.
+        pub fn $syrk(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            n: usize,
+            k: usize,
+            alpha: $k,
+            a: &[$t],
+            lda: usize,
+            beta: $k,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            if !matches!(trans, $($valid)|*) {
+                return Err(CblasError::InvalidArgument { name: "trans" });
+            }
+            let (rows, cols) = stored(trans, n, k);
+            let lda = matrix("a", "lda", a.len(), layout, rows, cols, lda)?;
+            let ldc = matrix("c", "ldc", c.len(), layout, n, n, ldc)?;
+            let (n, k) = (dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$csyrk(
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    scalar!($kpass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    scalar!($kpass, beta),
+                    c.as_mut_ptr(),
+                    ldc,
+                )
+            };
+            Ok(())
+        }
+
+        /// Compute C := alpha * op(A) * op(B)' + alpha' * op(B) * op(A)' +
+        /// beta * C for n-by-k op(A) and op(B) and a symmetric or Hermitian C
+        /// of order n.
+        pub fn $syr2k(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            trans: CBLAS_TRANSPOSE,
+            n: usize,
+            k: usize,
+            alpha: $t,
+            a: &[$t],
+            lda: usize,
+            b: &[$t],
+            ldb: usize,
+            beta: $k,
+            c: &mut [$t],
+            ldc: usize,
+        ) -> Result<(), CblasError> {
+            if !matches!(trans, $($valid)|*) {
+                return Err(CblasError::InvalidArgument { name: "trans" });
+            }
+            let (rows, cols) = stored(trans, n, k);
+            let lda = matrix("a", "lda", a.len(), layout, rows, cols, lda)?;
+            let ldb = matrix("b", "ldb", b.len(), layout, rows, cols, ldb)?;
+            let ldc = matrix("c", "ldc", c.len(), layout, n, n, ldc)?;
+            let (n, k) = (dimension("n", n)?, dimension("k", k)?);
+            unsafe {
+                ::$csyr2k(
+                    layout,
+                    uplo,
+                    trans,
+                    n,
+                    k,
+                    scalar!($pass, alpha),
+                    a.as_ptr(),
+                    lda,
+                    b.as_ptr(),
+                    ldb,
+                    scalar!($kpass, beta),
+                    c.as_mut_ptr(),
+                    ldc,
+                )
+            };
+            Ok(())
+        }
+    };
+}
+
+symmetric!(
+    c_float, value, c_float, value, CblasNoTrans | CblasTrans | CblasConjTrans,
+    ssymm => cblas_ssymm,
+    ssyrk => cblas_ssyrk,
+    ssyr2k => cblas_ssyr2k,
+);
+
+symmetric!(
+    c_double, value, c_double, value, CblasNoTrans | CblasTrans | CblasConjTrans,
+    dsymm => cblas_dsymm,
+    dsyrk => cblas_dsyrk,
+    dsyr2k => cblas_dsyr2k,
+);
+
+symmetric!(
+    c_float_complex, pointer, c_float_complex, pointer, CblasNoTrans | CblasTrans,
+    csymm => cblas_csymm,
+    csyrk => cblas_csyrk,
+    csyr2k => cblas_csyr2k,
+);
+
+symmetric!(
+    c_double_complex, pointer, c_double_complex, pointer, CblasNoTrans | CblasTrans,
+    zsymm => cblas_zsymm,
+    zsyrk => cblas_zsyrk,
+    zsyr2k => cblas_zsyr2k,
+);
+
+symmetric!(
+    c_float_complex, pointer, c_float, value, CblasNoTrans | CblasConjTrans,
+    chemm => cblas_chemm,
+    cherk => cblas_cherk,
+    cher2k => cblas_cher2k,
+);
+
+symmetric!(
+    c_double_complex, pointer, c_double, value, CblasNoTrans | CblasConjTrans,
+    zhemm => cblas_zhemm,
+    zherk => cblas_zherk,
+    zher2k => cblas_zher2k,
+);
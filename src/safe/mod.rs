@@ -0,0 +1,300 @@
+//! Safe wrappers over slices.
+//!
+//! The wrappers take slices instead of pointers, dimensions as `usize`, and
+//! increments as `isize`. Before calling a function, they check the arguments
+//! as CBLAS would and check that every slice is long enough for the elements
+//! that the function accesses, so that an invalid call results in an error
+//! instead of undefined behavior or a call to `cblas_xerbla`.
+//!
+//! As in CBLAS, a negative increment means that the elements are accessed
+//! backward, starting from the end of the slice. A zero increment is accepted
+//! for the vectors that level 1 functions only read, which then use the first
+//! element of the slice n times.
+
+use core::{cmp, fmt};
+
+use {blasint, CblasColMajor, CblasRowMajor, CBLAS_LAYOUT};
+
+// Passes a scalar by value for real types and by pointer for complex ones.
+macro_rules! scalar {
+    (value, $value:expr) => {
+        $value
+    };
+    (pointer, $value:expr) => {
+        &$value as *const _
+    };
+}
+
+pub mod level1;
+pub mod level2;
+pub mod level3;
+
+#[cfg(feature = "extensions")]
+pub mod batch;
+
+mod scalar;
+
+pub use self::scalar::BlasScalar;
+
+/// An error reported by a safe wrapper.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CblasError {
+    /// An argument is invalid, e.g., a zero increment or a leading dimension
+    /// smaller than the matrix requires.
+    InvalidArgument {
+        /// The name of the argument.
+        name: &'static str,
+    },
+    /// A slice is shorter than the dimensions and the increment or the
+    /// leading dimension require.
+    BufferTooSmall {
+        /// The name of the slice.
+        name: &'static str,
+        /// The number of elements required.
+        required: usize,
+        /// The number of elements given.
+        actual: usize,
+    },
+    /// A dimension or an increment does not fit into [`blasint`], or the size
+    /// of a slice that it implies does not fit into `usize`.
+    ///
+    /// [`blasint`]: ../type.blasint.html
+    Overflow {
+        /// The name of the argument.
+        name: &'static str,
+    },
+}
+
+impl fmt::Display for CblasError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CblasError::InvalidArgument { name } => write!(formatter, "`{}` is invalid", name),
+            CblasError::BufferTooSmall {
+                name,
+                required,
+                actual,
+            } => write!(
+                formatter,
+                "`{}` has {} elements but {} are required",
+                name, actual, required,
+            ),
+            CblasError::Overflow { name } => write!(formatter, "`{}` is too large", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for CblasError {}
+
+/// Convert a dimension.
+fn dimension(name: &'static str, value: usize) -> Result<blasint, CblasError> {
+    if value > blasint::MAX as usize {
+        return Err(CblasError::Overflow { name });
+    }
+    Ok(value as blasint)
+}
+
+/// Check that a slice is long enough.
+fn length(name: &'static str, actual: usize, required: usize) -> Result<(), CblasError> {
+    if actual < required {
+        return Err(CblasError::BufferTooSmall {
+            name,
+            required,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Check a vector with n elements and convert its increment.
+fn vector(
+    name: &'static str,
+    inc_name: &'static str,
+    len: usize,
+    n: usize,
+    inc: isize,
+) -> Result<blasint, CblasError> {
+    if inc == 0 {
+        return Err(CblasError::InvalidArgument { name: inc_name });
+    }
+    if inc.unsigned_abs() > blasint::MAX as usize {
+        return Err(CblasError::Overflow { name: inc_name });
+    }
+    let required = match n {
+        0 => 0,
+        _ => (n - 1)
+            .checked_mul(inc.unsigned_abs())
+            .and_then(|offset| offset.checked_add(1))
+            .ok_or(CblasError::Overflow { name })?,
+    };
+    length(name, len, required)?;
+    Ok(inc as blasint)
+}
+
+/// Check a vector that is only read, whose increment may be zero, and convert
+/// its increment.
+fn input(
+    name: &'static str,
+    inc_name: &'static str,
+    len: usize,
+    n: usize,
+    inc: isize,
+) -> Result<blasint, CblasError> {
+    if inc == 0 {
+        length(name, len, cmp::min(n, 1))?;
+        return Ok(0);
+    }
+    vector(name, inc_name, len, n, inc)
+}
+
+/// Check a vector whose increment has to be positive, as functions that
+/// ignore vectors with other increments require.
+fn positive(
+    name: &'static str,
+    inc_name: &'static str,
+    len: usize,
+    n: usize,
+    inc: isize,
+) -> Result<blasint, CblasError> {
+    if inc < 0 {
+        return Err(CblasError::InvalidArgument { name: inc_name });
+    }
+    vector(name, inc_name, len, n, inc)
+}
+
+/// Check a matrix with the given numbers of rows and columns and convert its
+/// leading dimension.
+fn matrix(
+    name: &'static str,
+    ld_name: &'static str,
+    len: usize,
+    layout: CBLAS_LAYOUT,
+    rows: usize,
+    cols: usize,
+    ld: usize,
+) -> Result<blasint, CblasError> {
+    match layout {
+        CblasColMajor => stored(name, ld_name, len, cols, rows, ld),
+        CblasRowMajor => stored(name, ld_name, len, rows, cols, ld),
+    }
+}
+
+/// Check a band matrix with the given numbers of rows, columns, subdiagonals,
+/// and superdiagonals and convert its leading dimension.
+#[allow(clippy::too_many_arguments)]
+fn band(
+    name: &'static str,
+    ld_name: &'static str,
+    len: usize,
+    layout: CBLAS_LAYOUT,
+    rows: usize,
+    cols: usize,
+    kl: usize,
+    ku: usize,
+    ld: usize,
+) -> Result<blasint, CblasError> {
+    let bands = kl
+        .checked_add(ku)
+        .and_then(|bands| bands.checked_add(1))
+        .ok_or(CblasError::Overflow { name: ld_name })?;
+    let major = match (rows, cols, layout) {
+        (0, _, _) | (_, 0, _) => 0,
+        (_, _, CblasColMajor) => cols,
+        (_, _, CblasRowMajor) => rows,
+    };
+    stored(name, ld_name, len, major, bands, ld)
+}
+
+/// Check a matrix stored as `major` vectors of `minor` elements each, which
+/// start `ld` elements apart, and convert the leading dimension.
+fn stored(
+    name: &'static str,
+    ld_name: &'static str,
+    len: usize,
+    major: usize,
+    minor: usize,
+    ld: usize,
+) -> Result<blasint, CblasError> {
+    if ld < cmp::max(1, minor) {
+        return Err(CblasError::InvalidArgument { name: ld_name });
+    }
+    let converted = dimension(ld_name, ld)?;
+    let required = match (major, minor) {
+        (0, _) | (_, 0) => 0,
+        _ => (major - 1)
+            .checked_mul(ld)
+            .and_then(|offset| offset.checked_add(minor))
+            .ok_or(CblasError::Overflow { name })?,
+    };
+    length(name, len, required)?;
+    Ok(converted)
+}
+
+/// Check a triangular matrix of order n stored in packed form.
+fn packed(name: &'static str, len: usize, n: usize) -> Result<(), CblasError> {
+    let required = match n % 2 {
+        0 => (n / 2).checked_mul(n + 1),
+        _ => n.checked_mul(n / 2 + 1),
+    };
+    let required = required.ok_or(CblasError::Overflow { name })?;
+    length(name, len, required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input, positive, vector, CblasError};
+
+    #[test]
+    fn vector_lengths() {
+        assert_eq!(vector("x", "incx", 7, 3, 3), Ok(3));
+        assert_eq!(vector("x", "incx", 7, 3, -3), Ok(-3));
+        assert_eq!(vector("x", "incx", 0, 0, 1), Ok(1));
+        assert_eq!(
+            vector("x", "incx", 6, 3, -3),
+            Err(CblasError::BufferTooSmall {
+                name: "x",
+                required: 7,
+                actual: 6,
+            })
+        );
+        assert_eq!(
+            vector("x", "incx", 3, usize::MAX, 2),
+            Err(CblasError::Overflow { name: "x" })
+        );
+    }
+
+    #[test]
+    fn vector_zero_increment() {
+        assert_eq!(
+            vector("y", "incy", 4, 2, 0),
+            Err(CblasError::InvalidArgument { name: "incy" })
+        );
+        assert_eq!(
+            positive("x", "incx", 4, 2, -1),
+            Err(CblasError::InvalidArgument { name: "incx" })
+        );
+    }
+
+    #[test]
+    fn input_zero_increment() {
+        assert_eq!(input("x", "incx", 1, 5, 0), Ok(0));
+        assert_eq!(input("x", "incx", 0, 0, 0), Ok(0));
+        assert_eq!(
+            input("x", "incx", 0, 5, 0),
+            Err(CblasError::BufferTooSmall {
+                name: "x",
+                required: 1,
+                actual: 0,
+            })
+        );
+        assert_eq!(input("x", "incx", 5, 3, 2), Ok(2));
+        assert_eq!(
+            input("x", "incx", 4, 3, 2),
+            Err(CblasError::BufferTooSmall {
+                name: "x",
+                required: 5,
+                actual: 4,
+            })
+        );
+    }
+}
@@ -0,0 +1,629 @@
+//! A trait for writing code generic over the type of the elements.
+
+#![allow(clippy::too_many_arguments)]
+
+use libc::{c_double, c_float};
+
+#[cfg(feature = "extensions")]
+use super::batch::{self, Axpy, Gemm, Gemv, Trsm};
+use super::{level1, level2, level3, CblasError};
+use {c_double_complex, c_float_complex};
+use {CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+
+/// A type supported by the routines, which allows for writing code generic
+/// over precision.
+///
+/// The functions dispatch to the wrappers with the corresponding prefix, `s`,
+/// `d`, `c`, or `z`, and take every scalar by value. For real types, the
+/// conjugated and unconjugated variants coincide.
+pub trait BlasScalar: Copy {
+    /// The type of norms and of the parts of a complex number.
+    type Real: Copy;
+
+    /// Compute the dot product of x and y.
+    fn dot(n: usize, x: &[Self], incx: isize, y: &[Self], incy: isize) -> Result<Self, CblasError>;
+
+    /// Compute the dot product of the conjugate of x and y.
+    fn dotc(n: usize, x: &[Self], incx: isize, y: &[Self], incy: isize)
+        -> Result<Self, CblasError>;
+
+    /// Compute the Euclidean norm of x.
+    fn nrm2(n: usize, x: &[Self], incx: isize) -> Result<Self::Real, CblasError>;
+
+    /// Compute the sum of the absolute values of the real and imaginary
+    /// parts of the elements of x.
+    fn asum(n: usize, x: &[Self], incx: isize) -> Result<Self::Real, CblasError>;
+
+    /// Find the index of the first element of x with the largest sum of the
+    /// absolute values of the real and imaginary parts.
+    fn iamax(n: usize, x: &[Self], incx: isize) -> Result<usize, CblasError>;
+
+    /// Swap x and y.
+    fn swap(
+        n: usize,
+        x: &mut [Self],
+        incx: isize,
+        y: &mut [Self],
+        incy: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Copy x into y.
+    fn copy(
+        n: usize,
+        x: &[Self],
+        incx: isize,
+        y: &mut [Self],
+        incy: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute y := alpha * x + y.
+    fn axpy(
+        n: usize,
+        alpha: Self,
+        x: &[Self],
+        incx: isize,
+        y: &mut [Self],
+        incy: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute x := alpha * x.
+    fn scal(n: usize, alpha: Self, x: &mut [Self], incx: isize) -> Result<(), CblasError>;
+
+    /// Compute y := alpha * op(A) * x + beta * y for an m-by-n A.
+    fn gemv(
+        layout: CBLAS_LAYOUT,
+        trans: CBLAS_TRANSPOSE,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        x: &[Self],
+        incx: isize,
+        beta: Self,
+        y: &mut [Self],
+        incy: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute A := alpha * x * y^T + A for an m-by-n A.
+    fn ger(
+        layout: CBLAS_LAYOUT,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        x: &[Self],
+        incx: isize,
+        y: &[Self],
+        incy: isize,
+        a: &mut [Self],
+        lda: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute x := op(A) * x for a triangular A of order n.
+    fn trmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: usize,
+        a: &[Self],
+        lda: usize,
+        x: &mut [Self],
+        incx: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Solve op(A) * x = b for a triangular A of order n, overwriting b given
+    /// in x.
+    fn trsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: usize,
+        a: &[Self],
+        lda: usize,
+        x: &mut [Self],
+        incx: isize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute C := alpha * op(A) * op(B) + beta * C for an m-by-k op(A), a
+    /// k-by-n op(B), and an m-by-n C.
+    fn gemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &[Self],
+        ldb: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute C := alpha * A * B + beta * C or C := alpha * B * A + beta * C,
+    /// depending on `side`, for a symmetric A and m-by-n B and C.
+    fn symm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &[Self],
+        ldb: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute C := alpha * op(A) * op(A)^T + beta * C for an n-by-k op(A)
+    /// and a symmetric C of order n.
+    fn syrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: usize,
+        k: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        beta: Self,
+        c: &mut [Self],
+        ldc: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute B := alpha * op(A) * B or B := alpha * B * op(A), depending on
+    /// `side`, for a triangular A and an m-by-n B.
+    fn trmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &mut [Self],
+        ldb: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Solve op(A) * X = alpha * B or X * op(A) = alpha * B, depending on
+    /// `side`, for a triangular A and an m-by-n B, overwriting B with X.
+    fn trsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: usize,
+        n: usize,
+        alpha: Self,
+        a: &[Self],
+        lda: usize,
+        b: &mut [Self],
+        ldb: usize,
+    ) -> Result<(), CblasError>;
+
+    /// Compute y := alpha * x + y for every problem of a batch.
+    #[cfg(feature = "extensions")]
+    fn axpy_batch(problems: &mut [Axpy<Self>]) -> Result<(), CblasError>;
+
+    /// Compute y := alpha * op(A) * x + beta * y for every problem of a batch.
+    #[cfg(feature = "extensions")]
+    fn gemv_batch(layout: CBLAS_LAYOUT, problems: &mut [Gemv<Self>]) -> Result<(), CblasError>;
+
+    /// Compute C := alpha * op(A) * op(B) + beta * C for every problem of a
+    /// batch.
+    #[cfg(feature = "extensions")]
+    fn gemm_batch(layout: CBLAS_LAYOUT, problems: &mut [Gemm<Self>]) -> Result<(), CblasError>;
+
+    /// Solve op(A) * X = alpha * B or X * op(A) = alpha * B for every problem
+    /// of a batch, overwriting B with X.
+    #[cfg(feature = "extensions")]
+    fn trsm_batch(layout: CBLAS_LAYOUT, problems: &mut [Trsm<Self>]) -> Result<(), CblasError>;
+}
+
+macro_rules! implement {
+    (
+        $t:ty, $real:ty,
+        $dot:ident, $dotc:ident, $nrm2:ident, $asum:ident, $iamax:ident,
+        $swap:ident, $copy:ident, $axpy:ident, $scal:ident,
+        $gemv:ident, $ger:ident, $trmv:ident, $trsv:ident,
+        $gemm:ident, $symm:ident, $syrk:ident, $trmm:ident, $trsm:ident,
+        $axpy_batch:ident, $gemv_batch:ident, $gemm_batch:ident, $trsm_batch:ident,
+    ) => {
+        impl BlasScalar for $t {
+            type Real = $real;
+
+            #[inline]
+            fn dot(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+            ) -> Result<Self, CblasError> {
+                level1::$dot(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            fn dotc(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+            ) -> Result<Self, CblasError> {
+                level1::$dotc(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            fn nrm2(n: usize, x: &[Self], incx: isize) -> Result<$real, CblasError> {
+                level1::$nrm2(n, x, incx)
+            }
+
+            #[inline]
+            fn asum(n: usize, x: &[Self], incx: isize) -> Result<$real, CblasError> {
+                level1::$asum(n, x, incx)
+            }
+
+            #[inline]
+            fn iamax(n: usize, x: &[Self], incx: isize) -> Result<usize, CblasError> {
+                level1::$iamax(n, x, incx)
+            }
+
+            #[inline]
+            fn swap(
+                n: usize,
+                x: &mut [Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                level1::$swap(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            fn copy(
+                n: usize,
+                x: &[Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                level1::$copy(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            fn axpy(
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: isize,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                level1::$axpy(n, alpha, x, incx, y, incy)
+            }
+
+            #[inline]
+            fn scal(n: usize, alpha: Self, x: &mut [Self], incx: isize) -> Result<(), CblasError> {
+                level1::$scal(n, alpha, x, incx)
+            }
+
+            #[inline]
+            fn gemv(
+                layout: CBLAS_LAYOUT,
+                trans: CBLAS_TRANSPOSE,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                x: &[Self],
+                incx: isize,
+                beta: Self,
+                y: &mut [Self],
+                incy: isize,
+            ) -> Result<(), CblasError> {
+                level2::$gemv(layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+            }
+
+            #[inline]
+            fn ger(
+                layout: CBLAS_LAYOUT,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                x: &[Self],
+                incx: isize,
+                y: &[Self],
+                incy: isize,
+                a: &mut [Self],
+                lda: usize,
+            ) -> Result<(), CblasError> {
+                level2::$ger(layout, m, n, alpha, x, incx, y, incy, a, lda)
+            }
+
+            #[inline]
+            fn trmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: usize,
+                a: &[Self],
+                lda: usize,
+                x: &mut [Self],
+                incx: isize,
+            ) -> Result<(), CblasError> {
+                level2::$trmv(layout, uplo, trans, diag, n, a, lda, x, incx)
+            }
+
+            #[inline]
+            fn trsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: usize,
+                a: &[Self],
+                lda: usize,
+                x: &mut [Self],
+                incx: isize,
+            ) -> Result<(), CblasError> {
+                level2::$trsv(layout, uplo, trans, diag, n, a, lda, x, incx)
+            }
+
+            #[inline]
+            fn gemm(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                transb: CBLAS_TRANSPOSE,
+                m: usize,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                level3::$gemm(
+                    layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+                )
+            }
+
+            #[inline]
+            fn symm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &[Self],
+                ldb: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                level3::$symm(
+                    layout, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc,
+                )
+            }
+
+            #[inline]
+            fn syrk(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                trans: CBLAS_TRANSPOSE,
+                n: usize,
+                k: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                beta: Self,
+                c: &mut [Self],
+                ldc: usize,
+            ) -> Result<(), CblasError> {
+                level3::$syrk(layout, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+            }
+
+            #[inline]
+            fn trmm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) -> Result<(), CblasError> {
+                level3::$trmm(
+                    layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                )
+            }
+
+            #[inline]
+            fn trsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: usize,
+                n: usize,
+                alpha: Self,
+                a: &[Self],
+                lda: usize,
+                b: &mut [Self],
+                ldb: usize,
+            ) -> Result<(), CblasError> {
+                level3::$trsm(
+                    layout, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb,
+                )
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn axpy_batch(problems: &mut [Axpy<Self>]) -> Result<(), CblasError> {
+                batch::$axpy_batch(problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn gemv_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Gemv<Self>],
+            ) -> Result<(), CblasError> {
+                batch::$gemv_batch(layout, problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn gemm_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Gemm<Self>],
+            ) -> Result<(), CblasError> {
+                batch::$gemm_batch(layout, problems)
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn trsm_batch(
+                layout: CBLAS_LAYOUT,
+                problems: &mut [Trsm<Self>],
+            ) -> Result<(), CblasError> {
+                batch::$trsm_batch(layout, problems)
+            }
+        }
+    };
+}
+
+implement!(
+    c_float,
+    c_float,
+    sdot,
+    sdot,
+    snrm2,
+    sasum,
+    isamax,
+    sswap,
+    scopy,
+    saxpy,
+    sscal,
+    sgemv,
+    sger,
+    strmv,
+    strsv,
+    sgemm,
+    ssymm,
+    ssyrk,
+    strmm,
+    strsm,
+    saxpy_batch,
+    sgemv_batch,
+    sgemm_batch,
+    strsm_batch,
+);
+
+implement!(
+    c_double,
+    c_double,
+    ddot,
+    ddot,
+    dnrm2,
+    dasum,
+    idamax,
+    dswap,
+    dcopy,
+    daxpy,
+    dscal,
+    dgemv,
+    dger,
+    dtrmv,
+    dtrsv,
+    dgemm,
+    dsymm,
+    dsyrk,
+    dtrmm,
+    dtrsm,
+    daxpy_batch,
+    dgemv_batch,
+    dgemm_batch,
+    dtrsm_batch,
+);
+
+implement!(
+    c_float_complex,
+    c_float,
+    cdotu_value,
+    cdotc_value,
+    scnrm2,
+    scasum,
+    icamax,
+    cswap,
+    ccopy,
+    caxpy,
+    cscal,
+    cgemv,
+    cgeru,
+    ctrmv,
+    ctrsv,
+    cgemm,
+    csymm,
+    csyrk,
+    ctrmm,
+    ctrsm,
+    caxpy_batch,
+    cgemv_batch,
+    cgemm_batch,
+    ctrsm_batch,
+);
+
+implement!(
+    c_double_complex,
+    c_double,
+    zdotu_value,
+    zdotc_value,
+    dznrm2,
+    dzasum,
+    izamax,
+    zswap,
+    zcopy,
+    zaxpy,
+    zscal,
+    zgemv,
+    zgeru,
+    ztrmv,
+    ztrsv,
+    zgemm,
+    zsymm,
+    zsyrk,
+    ztrmm,
+    ztrsm,
+    zaxpy_batch,
+    zgemv_batch,
+    zgemm_batch,
+    ztrsm_batch,
+);
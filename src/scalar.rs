@@ -0,0 +1,263 @@
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use {c_double_complex, c_float_complex};
+
+/// A real or complex scalar.
+pub trait Scalar: Copy {
+    /// The type of the real and imaginary parts.
+    type Real: Real;
+
+    /// The type of `alpha` and `beta` arguments, which are passed by value for
+    /// real numbers and by pointer for complex ones.
+    type Arg: Copy;
+
+    const COMPLEX: bool;
+
+    unsafe fn load(arg: Self::Arg) -> Self;
+
+    /// The argument passing a value, which refers to the value for complex
+    /// numbers.
+    #[cfg(feature = "extensions")]
+    fn arg(value: &Self) -> Self::Arg;
+
+    fn from_real(value: Self::Real) -> Self;
+    fn re(self) -> Self::Real;
+
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    fn div(self, other: Self) -> Self;
+    fn conj(self) -> Self;
+    fn scale(self, factor: Self::Real) -> Self;
+
+    /// The sum of the absolute values of the real and imaginary parts.
+    fn abs1(self) -> Self::Real;
+
+    /// The real and imaginary parts, the latter being zero for real numbers.
+    fn parts(self) -> [Self::Real; 2];
+
+    #[inline]
+    fn zero() -> Self {
+        Self::from_real(Self::Real::ZERO)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self::from_real(Self::Real::ONE)
+    }
+
+    #[inline]
+    fn is_zero(self) -> bool {
+        let [re, im] = self.parts();
+        re == Self::Real::ZERO && im == Self::Real::ZERO
+    }
+
+    #[inline]
+    fn is_one(self) -> bool {
+        let [re, im] = self.parts();
+        re == Self::Real::ONE && im == Self::Real::ZERO
+    }
+
+    /// Conjugates the number if `conj` is true.
+    #[inline]
+    fn conj_if(self, conj: bool) -> Self {
+        if conj {
+            self.conj()
+        } else {
+            self
+        }
+    }
+}
+
+/// A real number.
+pub trait Real:
+    Scalar<Real = Self>
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! implement {
+    ($real:ty, $complex:ty) => {
+        impl Scalar for $real {
+            type Real = $real;
+            type Arg = $real;
+
+            const COMPLEX: bool = false;
+
+            #[inline]
+            unsafe fn load(arg: $real) -> $real {
+                arg
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn arg(value: &$real) -> $real {
+                *value
+            }
+
+            #[inline]
+            fn from_real(value: $real) -> $real {
+                value
+            }
+
+            #[inline]
+            fn re(self) -> $real {
+                self
+            }
+
+            #[inline]
+            fn add(self, other: $real) -> $real {
+                self + other
+            }
+
+            #[inline]
+            fn sub(self, other: $real) -> $real {
+                self - other
+            }
+
+            #[inline]
+            fn mul(self, other: $real) -> $real {
+                self * other
+            }
+
+            #[inline]
+            fn div(self, other: $real) -> $real {
+                self / other
+            }
+
+            #[inline]
+            fn conj(self) -> $real {
+                self
+            }
+
+            #[inline]
+            fn scale(self, factor: $real) -> $real {
+                self * factor
+            }
+
+            #[inline]
+            fn abs1(self) -> $real {
+                self.abs()
+            }
+
+            #[inline]
+            fn parts(self) -> [$real; 2] {
+                [self, 0.0]
+            }
+        }
+
+        impl Real for $real {
+            const ZERO: $real = 0.0;
+            const ONE: $real = 1.0;
+
+            #[inline]
+            fn abs(self) -> $real {
+                <$real>::abs(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $real {
+                <$real>::sqrt(self)
+            }
+        }
+
+        impl Scalar for $complex {
+            type Real = $real;
+            type Arg = *const $complex;
+
+            const COMPLEX: bool = true;
+
+            #[inline]
+            unsafe fn load(arg: *const $complex) -> $complex {
+                *arg
+            }
+
+            #[cfg(feature = "extensions")]
+            #[inline]
+            fn arg(value: &$complex) -> *const $complex {
+                value
+            }
+
+            #[inline]
+            fn from_real(value: $real) -> $complex {
+                [value, 0.0]
+            }
+
+            #[inline]
+            fn re(self) -> $real {
+                self[0]
+            }
+
+            #[inline]
+            fn add(self, other: $complex) -> $complex {
+                [self[0] + other[0], self[1] + other[1]]
+            }
+
+            #[inline]
+            fn sub(self, other: $complex) -> $complex {
+                [self[0] - other[0], self[1] - other[1]]
+            }
+
+            #[inline]
+            fn mul(self, other: $complex) -> $complex {
+                [
+                    self[0] * other[0] - self[1] * other[1],
+                    self[0] * other[1] + self[1] * other[0],
+                ]
+            }
+
+            // Smith's algorithm, which avoids needless overflow.
+            #[inline]
+            fn div(self, other: $complex) -> $complex {
+                if other[0].abs() >= other[1].abs() {
+                    let ratio = other[1] / other[0];
+                    let denominator = other[0] + other[1] * ratio;
+                    [
+                        (self[0] + self[1] * ratio) / denominator,
+                        (self[1] - self[0] * ratio) / denominator,
+                    ]
+                } else {
+                    let ratio = other[0] / other[1];
+                    let denominator = other[0] * ratio + other[1];
+                    [
+                        (self[0] * ratio + self[1]) / denominator,
+                        (self[1] * ratio - self[0]) / denominator,
+                    ]
+                }
+            }
+
+            #[inline]
+            fn conj(self) -> $complex {
+                [self[0], -self[1]]
+            }
+
+            #[inline]
+            fn scale(self, factor: $real) -> $complex {
+                [self[0] * factor, self[1] * factor]
+            }
+
+            #[inline]
+            fn abs1(self) -> $real {
+                self[0].abs() + self[1].abs()
+            }
+
+            #[inline]
+            fn parts(self) -> [$real; 2] {
+                self
+            }
+        }
+    };
+}
+
+implement!(f32, c_float_complex);
+implement!(f64, c_double_complex);
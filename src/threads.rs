@@ -0,0 +1,281 @@
+//! Limiting the number of threads used by the library.
+//!
+//! A [`ThreadLimit`] sets the number of threads that the routines may use
+//! until it is dropped, which restores the previous number. If the `openblas`
+//! or `mkl` feature is enabled, the service functions of the selected library
+//! are called directly. Otherwise, the library is detected by looking up the
+//! service functions of OpenBLAS, MKL, and BLIS among the loaded libraries, in
+//! this order, taking into account the suffix selected via the `suffix-*`
+//! features, if any, for OpenBLAS. If none of them is found, e.g., when the
+//! library is linked statically, is loaded via the `dynamic` feature, or runs
+//! single-threaded anyway, such as the reference implementation, the limit
+//! does nothing, as it does if the `pure-rust` or `vendored` feature is
+//! enabled. The lookup is only supported on Unix.
+//!
+//! OpenBLAS and BLIS keep the number of threads as a setting of the whole
+//! process, so that limits set concurrently from several threads interfere.
+//! MKL, on the other hand, is limited via `MKL_Set_Num_Threads_Local`, which
+//! only applies to the calling thread and falls back to the global setting
+//! when the limit is dropped, if it was not limited before. For instance, to
+//! call the routines from the threads of a pool without oversubscribing the
+//! processor, the limit is set once around the work of the pool for OpenBLAS
+//! and BLIS, and in every thread of the pool for MKL:
+//!
+//! ```no_run
+//! # extern crate cblas_sys;
+//! # fn main() {
+//! use cblas_sys::threads::ThreadLimit;
+//!
+//! ThreadLimit::scope(1, || {
+//!     // Spawn the threads calling `cblas_dgemm` and wait for them.
+//! });
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+#[cfg(not(any(
+    feature = "openblas",
+    feature = "mkl",
+    feature = "pure-rust",
+    feature = "vendored"
+)))]
+use core::mem;
+
+use libc::c_int;
+
+#[cfg(not(any(
+    feature = "openblas",
+    feature = "mkl",
+    feature = "pure-rust",
+    feature = "vendored"
+)))]
+use lookup::Function;
+
+type Set = unsafe extern "C" fn(c_int);
+type Get = unsafe extern "C" fn() -> c_int;
+type SetLocal = unsafe extern "C" fn(c_int) -> c_int;
+// The `dim_t` of BLIS is a 64-bit integer unless configured otherwise.
+type BliSet = unsafe extern "C" fn(i64);
+type BliGet = unsafe extern "C" fn() -> i64;
+
+/// The service functions of a library setting and returning the number of
+/// threads, of which the variants constructed depend on the features.
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+enum Control {
+    OpenBlas(Set, Get),
+    /// The function setting the number of threads of the calling thread and
+    /// returning the previous one, where zero stands for the global setting.
+    Mkl(SetLocal),
+    Blis(BliSet, BliGet),
+}
+
+impl Control {
+    /// Return the service functions of the library selected at build time,
+    /// or look up those of the first loaded library that provides them.
+    #[cfg(feature = "openblas")]
+    fn find() -> Option<Control> {
+        Some(Control::OpenBlas(
+            ::openblas::openblas_set_num_threads,
+            ::openblas::openblas_get_num_threads,
+        ))
+    }
+
+    #[cfg(feature = "mkl")]
+    fn find() -> Option<Control> {
+        Some(Control::Mkl(::mkl::mkl_set_num_threads_local))
+    }
+
+    // The implementations written in Rust and C run in a single thread.
+    #[cfg(any(feature = "pure-rust", feature = "vendored"))]
+    fn find() -> Option<Control> {
+        None
+    }
+
+    #[cfg(not(any(
+        feature = "openblas",
+        feature = "mkl",
+        feature = "pure-rust",
+        feature = "vendored"
+    )))]
+    fn find() -> Option<Control> {
+        static OPENBLAS_SET_NUM_THREADS: Function =
+            Function::new(symbol!(openblas_set_num_threads));
+        static OPENBLAS_GET_NUM_THREADS: Function =
+            Function::new(symbol!(openblas_get_num_threads));
+        static MKL_SET_NUM_THREADS_LOCAL: Function = Function::new("MKL_Set_Num_Threads_Local\0");
+        static BLI_THREAD_SET_NUM_THREADS: Function = Function::new("bli_thread_set_num_threads\0");
+        static BLI_THREAD_GET_NUM_THREADS: Function = Function::new("bli_thread_get_num_threads\0");
+
+        unsafe {
+            if let (Some(set), Some(get)) = (
+                OPENBLAS_SET_NUM_THREADS.address(),
+                OPENBLAS_GET_NUM_THREADS.address(),
+            ) {
+                return Some(Control::OpenBlas(
+                    mem::transmute::<usize, Set>(set),
+                    mem::transmute::<usize, Get>(get),
+                ));
+            }
+            if let Some(set_local) = MKL_SET_NUM_THREADS_LOCAL.address() {
+                return Some(Control::Mkl(mem::transmute::<usize, SetLocal>(set_local)));
+            }
+            if let (Some(set), Some(get)) = (
+                BLI_THREAD_SET_NUM_THREADS.address(),
+                BLI_THREAD_GET_NUM_THREADS.address(),
+            ) {
+                return Some(Control::Blis(
+                    mem::transmute::<usize, BliSet>(set),
+                    mem::transmute::<usize, BliGet>(get),
+                ));
+            }
+        }
+        None
+    }
+
+    /// Set the number of threads, where zero is treated as one, returning
+    /// the previous setting.
+    fn replace(self, num_threads: usize) -> i64 {
+        let num_threads = num_threads.max(1).min(c_int::MAX as usize);
+        unsafe {
+            match self {
+                Control::OpenBlas(set, get) => {
+                    let previous = get();
+                    set(num_threads as c_int);
+                    previous as i64
+                }
+                Control::Mkl(set_local) => set_local(num_threads as c_int) as i64,
+                Control::Blis(set, get) => {
+                    let previous = get();
+                    set(num_threads as i64);
+                    previous
+                }
+            }
+        }
+    }
+
+    /// Restore a setting returned by `replace` as it is.
+    fn restore(self, previous: i64) {
+        unsafe {
+            match self {
+                Control::OpenBlas(set, _) => set(previous as c_int),
+                Control::Mkl(set_local) => {
+                    set_local(previous as c_int);
+                }
+                Control::Blis(set, _) => set(previous),
+            }
+        }
+    }
+}
+
+/// A limit on the number of threads used by the routines, which restores the
+/// previous number when dropped.
+///
+/// The limit is not `Send`, since MKL restores the number of threads of the
+/// thread that drops it.
+#[must_use = "the limit is lifted when it is dropped"]
+pub struct ThreadLimit {
+    previous: Option<(Control, i64)>,
+    thread: PhantomData<*const ()>,
+}
+
+impl ThreadLimit {
+    /// Set the number of threads used by the routines, where zero is treated
+    /// as one.
+    pub fn new(num_threads: usize) -> ThreadLimit {
+        ThreadLimit::with(Control::find(), num_threads)
+    }
+
+    fn with(control: Option<Control>, num_threads: usize) -> ThreadLimit {
+        ThreadLimit {
+            previous: control.map(|control| (control, control.replace(num_threads))),
+            thread: PhantomData,
+        }
+    }
+
+    /// Call a function with the number of threads used by the routines set,
+    /// restoring the previous number afterwards, even if the function panics.
+    pub fn scope<F, R>(num_threads: usize, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let _limit = ThreadLimit::new(num_threads);
+        f()
+    }
+
+    /// Return whether the number of threads could be set, that is, whether a
+    /// supported library was found.
+    pub fn is_active(&self) -> bool {
+        self.previous.is_some()
+    }
+}
+
+impl Drop for ThreadLimit {
+    fn drop(&mut self) {
+        if let Some((control, previous)) = self.previous {
+            control.restore(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicI64, Ordering};
+
+    use libc::c_int;
+
+    use super::{Control, ThreadLimit};
+
+    static GLOBAL: AtomicI64 = AtomicI64::new(8);
+    static LOCAL: AtomicI64 = AtomicI64::new(0);
+
+    unsafe extern "C" fn set(num_threads: c_int) {
+        GLOBAL.store(num_threads as i64, Ordering::SeqCst);
+    }
+
+    unsafe extern "C" fn get() -> c_int {
+        GLOBAL.load(Ordering::SeqCst) as c_int
+    }
+
+    unsafe extern "C" fn set_local(num_threads: c_int) -> c_int {
+        LOCAL.swap(num_threads as i64, Ordering::SeqCst) as c_int
+    }
+
+    #[test]
+    fn global() {
+        let control = Some(Control::OpenBlas(set, get));
+        let outer = ThreadLimit::with(control, 4);
+        assert!(outer.is_active());
+        assert_eq!(GLOBAL.load(Ordering::SeqCst), 4);
+        {
+            let _inner = ThreadLimit::with(control, 0);
+            assert_eq!(GLOBAL.load(Ordering::SeqCst), 1);
+        }
+        assert_eq!(GLOBAL.load(Ordering::SeqCst), 4);
+        drop(outer);
+        assert_eq!(GLOBAL.load(Ordering::SeqCst), 8);
+    }
+
+    #[test]
+    fn local() {
+        let control = Some(Control::Mkl(set_local));
+        let outer = ThreadLimit::with(control, 2);
+        assert_eq!(LOCAL.load(Ordering::SeqCst), 2);
+        {
+            let _inner = ThreadLimit::with(control, 1);
+            assert_eq!(LOCAL.load(Ordering::SeqCst), 1);
+        }
+        assert_eq!(LOCAL.load(Ordering::SeqCst), 2);
+        drop(outer);
+        assert_eq!(LOCAL.load(Ordering::SeqCst), 0);
+    }
+
+    #[cfg(any(feature = "pure-rust", feature = "vendored"))]
+    #[test]
+    fn inactive() {
+        let limit = ThreadLimit::new(2);
+        assert!(!limit.is_active());
+        drop(limit);
+        assert_eq!(ThreadLimit::scope(1, || 42), 42);
+    }
+}
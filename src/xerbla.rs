@@ -0,0 +1,126 @@
+//! Reporting of invalid arguments without terminating the process.
+//!
+//! CBLAS calls `cblas_xerbla` when a routine receives an invalid argument,
+//! and the default implementation prints a message and exits. The module
+//! provides an implementation that instead records the error for the current
+//! thread and passes it to a handler, if one is registered, after which the
+//! routine returns without doing anything.
+//!
+//! The implementation takes effect for providers that report errors via
+//! `cblas_xerbla`, e.g., the reference implementation and the `vendored` and
+//! `pure-rust` features; some optimized libraries report errors differently.
+//!
+//! ```no_run
+//! # extern crate cblas_sys;
+//! # fn main() {
+//! use cblas_sys::{cblas_dgemm, xerbla, CblasColMajor, CblasNoTrans};
+//!
+//! xerbla::set_handler(|error| eprintln!("{}", error));
+//!
+//! let (a, b, mut c) = ([1.0; 4], [1.0; 4], [0.0; 4]);
+//! unsafe {
+//!     cblas_dgemm(
+//!         CblasColMajor, CblasNoTrans, CblasNoTrans, 2, 2, 2,
+//!         1.0, a.as_ptr(), 1, b.as_ptr(), 2, 0.0, c.as_mut_ptr(), 2,
+//!     );
+//! }
+//!
+//! let error = xerbla::take_last_error().unwrap();
+//! assert_eq!((&*error.routine, error.position), ("cblas_dgemm", 9));
+//! # }
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::fmt;
+use std::string::String;
+use std::sync::{Arc, RwLock};
+
+use blasint;
+
+/// An invalid argument reported by a routine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidArgument {
+    /// The name of the routine, e.g., `cblas_dgemm`.
+    pub routine: String,
+    /// The position of the argument, starting from one.
+    pub position: blasint,
+}
+
+impl fmt::Display for InvalidArgument {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "parameter {} to routine {} was incorrect",
+            self.position, self.routine,
+        )
+    }
+}
+
+impl ::std::error::Error for InvalidArgument {}
+
+type Handler = Arc<dyn Fn(&InvalidArgument) + Send + Sync>;
+
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<InvalidArgument>> = const { RefCell::new(None) };
+}
+
+/// Register a handler called on every invalid argument, replacing the
+/// previous one.
+///
+/// The handler is called on the thread that called the routine. It must not
+/// panic, since the panic would have to unwind through C.
+pub fn set_handler<F>(handler: F)
+where
+    F: Fn(&InvalidArgument) + Send + Sync + 'static,
+{
+    *HANDLER.write().unwrap_or_else(|error| error.into_inner()) = Some(Arc::new(handler));
+}
+
+/// Remove the handler, if any.
+pub fn clear_handler() {
+    *HANDLER.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Return the last invalid argument reported on the current thread.
+pub fn last_error() -> Option<InvalidArgument> {
+    LAST_ERROR.with(|error| error.borrow().clone())
+}
+
+/// Return the last invalid argument reported on the current thread and
+/// forget it.
+pub fn take_last_error() -> Option<InvalidArgument> {
+    LAST_ERROR.with(|error| error.borrow_mut().take())
+}
+
+fn report(error: InvalidArgument) {
+    let handler = HANDLER
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone();
+    if let Some(handler) = handler {
+        handler(&error);
+    }
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+}
+
+define_xerbla! {
+    /// Record an invalid argument and pass it to the handler, if any.
+    ///
+    /// # Safety
+    ///
+    /// `rout` has to be null or point to a null-terminated string.
+    |p, rout| {
+        let routine = if rout.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(rout).to_string_lossy().trim().into()
+        };
+        report(InvalidArgument {
+            routine,
+            position: p,
+        });
+    }
+}
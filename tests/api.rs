@@ -0,0 +1,39 @@
+//! The checks of the table of the linked functions, which are those of the
+//! implementation written in Rust.
+
+#![cfg(feature = "pure-rust")]
+
+extern crate cblas_sys;
+
+use cblas_sys::{CblasApi, CblasColMajor, CblasNoTrans};
+
+#[test]
+fn linked() {
+    let api = CblasApi::linked();
+    let (x, y) = ([1.0, 2.0, 3.0], [4.0, -5.0, 6.0]);
+    assert_eq!(
+        unsafe { (api.cblas_ddot)(3, x.as_ptr(), 1, y.as_ptr(), 1) },
+        12.0
+    );
+
+    // y := 2 * A * x + y with A = [1 3; 2 4] in column-major order.
+    let a = [1.0f32, 2.0, 3.0, 4.0];
+    let (x, mut y) = ([1.0f32, 1.0], [1.0f32, -1.0]);
+    unsafe {
+        (api.cblas_sgemv)(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            2,
+            2.0,
+            a.as_ptr(),
+            2,
+            x.as_ptr(),
+            1,
+            1.0,
+            y.as_mut_ptr(),
+            1,
+        )
+    };
+    assert_eq!(y, [9.0, 11.0]);
+}
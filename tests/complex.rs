@@ -0,0 +1,161 @@
+//! The functions of the `complex` module taking complex numbers, compared
+//! with hand-computed results.
+
+#![cfg(all(
+    feature = "num-complex",
+    any(feature = "pure-rust", feature = "vendored")
+))]
+
+extern crate cblas_sys;
+extern crate num_complex;
+
+use cblas_sys::complex;
+use cblas_sys::{CblasColMajor, CblasConjTrans, CblasLeft, CblasNoTrans, CblasRowMajor};
+use cblas_sys::{CblasUpper, CBLAS_LAYOUT};
+use num_complex::Complex64;
+
+fn c(re: f64, im: f64) -> Complex64 {
+    Complex64::new(re, im)
+}
+
+// The Hermitian matrix [[2, 1 - i], [1 + i, 3]] with the lower triangle
+// replaced by garbage, which has to be ignored.
+fn hermitian(layout: CBLAS_LAYOUT) -> [Complex64; 4] {
+    let garbage = c(f64::NAN, f64::NAN);
+    match layout {
+        CblasColMajor => [c(2.0, 0.0), garbage, c(1.0, -1.0), c(3.0, 0.0)],
+        CblasRowMajor => [c(2.0, 0.0), c(1.0, -1.0), garbage, c(3.0, 0.0)],
+    }
+}
+
+#[test]
+fn dot() {
+    let x = [c(1.0, 2.0), c(3.0, 4.0)];
+    let y = [c(5.0, 6.0), c(7.0, 8.0)];
+    assert_eq!(complex::zdotu(2, &x, 1, &y, 1), Ok(c(-18.0, 68.0)));
+    assert_eq!(complex::zdotc(2, &x, 1, &y, 1), Ok(c(70.0, -8.0)));
+    let x = [num_complex::Complex32::new(1.0, 1.0)];
+    assert_eq!(
+        complex::cdotc(1, &x, 1, &x, 1),
+        Ok(num_complex::Complex32::new(2.0, 0.0))
+    );
+}
+
+#[test]
+fn level2() {
+    for &layout in &[CblasColMajor, CblasRowMajor] {
+        let a = hermitian(layout);
+        let x = [c(1.0, 0.0), c(0.0, 1.0)];
+        let mut y = [c(0.0, 0.0); 2];
+        complex::zhemv(
+            layout,
+            CblasUpper,
+            2,
+            c(1.0, 0.0),
+            &a,
+            2,
+            &x,
+            1,
+            c(0.0, 0.0),
+            &mut y,
+            1,
+        )
+        .unwrap();
+        assert_eq!(y, [c(3.0, 1.0), c(1.0, 4.0)]);
+
+        // A := x * x' + A, whose diagonal is real.
+        let mut b = a;
+        complex::zher(layout, CblasUpper, 2, 1.0, &x, 1, &mut b, 2).unwrap();
+        assert_eq!(b[0], c(3.0, 0.0));
+        assert_eq!(b[3], c(4.0, 0.0));
+        let upper = if layout == CblasColMajor { 2 } else { 1 };
+        assert_eq!(b[upper], c(1.0, -2.0));
+
+        // A := x * y' + y * x' + A, with y = x, is A := 2 * x * x' + A.
+        let mut b = a;
+        complex::zher2(layout, CblasUpper, 2, c(1.0, 0.0), &x, 1, &x, 1, &mut b, 2).unwrap();
+        assert_eq!(b[0], c(4.0, 0.0));
+        assert_eq!(b[upper], c(1.0, -3.0));
+    }
+}
+
+#[test]
+fn level3() {
+    for &layout in &[CblasColMajor, CblasRowMajor] {
+        let a = hermitian(layout);
+        let identity = [c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)];
+        let mut m = [c(0.0, 0.0); 4];
+        complex::zhemm(
+            layout,
+            CblasLeft,
+            CblasUpper,
+            2,
+            2,
+            c(1.0, 0.0),
+            &a,
+            2,
+            &identity,
+            2,
+            c(0.0, 0.0),
+            &mut m,
+            2,
+        )
+        .unwrap();
+        // The full Hermitian matrix.
+        let (lower, upper) = if layout == CblasColMajor {
+            (1, 2)
+        } else {
+            (2, 1)
+        };
+        assert_eq!(m[lower], c(1.0, 1.0));
+        assert_eq!(m[upper], c(1.0, -1.0));
+
+        // C := A' * A for A = [[1, i], [0, 1]] stored by columns or rows.
+        let b = match layout {
+            CblasColMajor => [c(1.0, 0.0), c(0.0, 0.0), c(0.0, 1.0), c(1.0, 0.0)],
+            CblasRowMajor => [c(1.0, 0.0), c(0.0, 1.0), c(0.0, 0.0), c(1.0, 0.0)],
+        };
+        let mut k = [c(0.0, 0.0); 4];
+        complex::zherk(
+            layout,
+            CblasUpper,
+            CblasConjTrans,
+            2,
+            2,
+            1.0,
+            &b,
+            2,
+            0.0,
+            &mut k,
+            2,
+        )
+        .unwrap();
+        assert_eq!(
+            [k[0], k[upper], k[3]],
+            [c(1.0, 0.0), c(0.0, 1.0), c(2.0, 0.0)]
+        );
+
+        // C := A * I' + I * A' + C with C = 0 is A + A'.
+        let mut k = [c(0.0, 0.0); 4];
+        complex::zher2k(
+            layout,
+            CblasUpper,
+            CblasNoTrans,
+            2,
+            2,
+            c(1.0, 0.0),
+            &b,
+            2,
+            &identity,
+            2,
+            0.0,
+            &mut k,
+            2,
+        )
+        .unwrap();
+        assert_eq!(
+            [k[0], k[upper], k[3]],
+            [c(2.0, 0.0), c(0.0, 1.0), c(2.0, 0.0)]
+        );
+    }
+}
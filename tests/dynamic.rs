@@ -0,0 +1,55 @@
+//! The checks of a library loaded at run time, which is the one whose path is
+//! given in `CBLAS_LIB`, e.g., the package built as a shared object with the
+//! implementation written in Rust:
+//!
+//! ```sh
+//! cargo rustc --lib --features pure-rust --crate-type cdylib
+//! export CBLAS_LIB=$PWD/target/debug/libcblas_sys.so
+//! cargo test --features dynamic,pure-rust --test dynamic
+//! ```
+//!
+//! The build script only ignores the variable if the package provides the
+//! functions itself, hence `pure-rust`. The checks of a provided library are
+//! skipped if the variable is not set.
+
+#![cfg(feature = "dynamic")]
+
+extern crate cblas_sys;
+
+use std::env;
+
+use cblas_sys::CblasLibrary;
+
+#[test]
+fn provided() {
+    let path = match env::var_os("CBLAS_LIB") {
+        Some(path) => path,
+        None => {
+            eprintln!("skipped since CBLAS_LIB is not set");
+            return;
+        }
+    };
+    let library = unsafe { CblasLibrary::open(&path) }.unwrap();
+    assert!(library.missing().is_empty(), "{:?}", library.missing());
+
+    let (x, y) = ([1.0, 2.0, 3.0], [4.0, -5.0, 6.0]);
+    let dot = unsafe { library.cblas_ddot(3, x.as_ptr(), 1, y.as_ptr(), 1) };
+    assert_eq!(dot, 12.0);
+    let api = library.api().unwrap();
+    let dot = unsafe { (api.cblas_ddot)(2, x.as_ptr(), 2, y.as_ptr(), -1) };
+    assert_eq!(dot, 7.0);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn missing() {
+    let library = unsafe { CblasLibrary::open("libm.so.6") }.unwrap();
+    assert!(library.missing().contains(&"cblas_dgemm"));
+    assert!(library.missing().contains(&"cblas_xerbla"));
+    assert!(library.api().is_none());
+}
+
+#[test]
+fn unavailable() {
+    assert!(unsafe { CblasLibrary::open("libcblas_sys_missing.so") }.is_err());
+}
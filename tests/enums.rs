@@ -0,0 +1,122 @@
+extern crate cblas_sys;
+extern crate libc;
+
+use std::convert::TryFrom;
+
+use libc::c_int;
+
+use cblas_sys::*;
+
+// Checks the conversions of every variant of an enumeration given its value,
+// its character code, and its name, and the rejection of the given invalid
+// values and characters.
+macro_rules! conversions {
+    ($test:ident, $name:ident {
+        $($variant:ident: $value:expr, $code:expr, $string:expr;)*
+    } invalid $invalid_values:expr, $invalid_codes:expr) => {
+        #[test]
+        fn $test() {
+            let error = InvalidEnumValue {
+                name: stringify!($name),
+            };
+            $(
+                assert_eq!(c_int::from($variant), $value);
+                assert_eq!($name::try_from($value as c_int), Ok($variant));
+                assert_eq!(char::from($variant), $code);
+                assert_eq!($name::try_from($code), Ok($variant));
+                assert_eq!($name::try_from($code.to_ascii_lowercase()), Ok($variant));
+                assert_eq!($variant.to_string(), $string);
+                assert_eq!($string.parse::<$name>(), Ok($variant));
+                assert_eq!($string.to_lowercase().parse::<$name>(), Err(error));
+            )*
+            for &value in $invalid_values.iter() {
+                assert_eq!($name::try_from(value as c_int), Err(error));
+            }
+            for &code in $invalid_codes.iter() {
+                assert_eq!($name::try_from(code), Err(error));
+            }
+            assert_eq!("".parse::<$name>(), Err(error));
+            assert_eq!(
+                error.to_string(),
+                concat!("invalid value for `", stringify!($name), "`")
+            );
+        }
+    };
+}
+
+conversions!(layout, CBLAS_LAYOUT {
+    CblasRowMajor: 101, 'R', "RowMajor";
+    CblasColMajor: 102, 'C', "ColMajor";
+} invalid [0, -101, 100, 103, 111], ['N', 'X', ' ', '1', 'é']);
+
+conversions!(transpose, CBLAS_TRANSPOSE {
+    CblasNoTrans: 111, 'N', "NoTrans";
+    CblasTrans: 112, 'T', "Trans";
+    CblasConjTrans: 113, 'C', "ConjTrans";
+} invalid [0, -111, 101, 110, 114], ['R', 'X', ' ', '1', 'é']);
+
+conversions!(uplo, CBLAS_UPLO {
+    CblasUpper: 121, 'U', "Upper";
+    CblasLower: 122, 'L', "Lower";
+} invalid [0, -121, 111, 120, 123], ['N', 'X', ' ', '1', 'é']);
+
+conversions!(diag, CBLAS_DIAG {
+    CblasNonUnit: 131, 'N', "NonUnit";
+    CblasUnit: 132, 'U', "Unit";
+} invalid [0, -131, 121, 130, 133], ['L', 'X', ' ', '1', 'é']);
+
+conversions!(side, CBLAS_SIDE {
+    CblasLeft: 141, 'L', "Left";
+    CblasRight: 142, 'R', "Right";
+} invalid [0, -141, 131, 140, 143], ['U', 'X', ' ', '1', 'é']);
+
+#[cfg(feature = "serde")]
+mod serde {
+    extern crate serde_json;
+
+    use cblas_sys::*;
+
+    // Checks that every variant of an enumeration is serialized as its name
+    // and deserialized back, and that an unknown name is rejected.
+    macro_rules! round_trip {
+        ($test:ident, $name:ident { $($variant:ident: $string:expr;)* }) => {
+            #[test]
+            fn $test() {
+                $(
+                    let json = serde_json::to_string(&$variant).unwrap();
+                    assert_eq!(json, concat!("\"", $string, "\""));
+                    assert_eq!(serde_json::from_str::<$name>(&json).unwrap(), $variant);
+                )*
+                let error = serde_json::from_str::<$name>("\"Unknown\"").unwrap_err();
+                assert!(error.to_string().starts_with("unknown variant `Unknown`"));
+                assert!(serde_json::from_str::<$name>("101").is_err());
+            }
+        };
+    }
+
+    round_trip!(layout, CBLAS_LAYOUT {
+        CblasRowMajor: "RowMajor";
+        CblasColMajor: "ColMajor";
+    });
+
+    round_trip!(transpose, CBLAS_TRANSPOSE {
+        CblasNoTrans: "NoTrans";
+        CblasTrans: "Trans";
+        CblasConjTrans: "ConjTrans";
+    });
+
+    round_trip!(uplo, CBLAS_UPLO {
+        CblasUpper: "Upper";
+        CblasLower: "Lower";
+    });
+
+    round_trip!(diag, CBLAS_DIAG {
+        CblasNonUnit: "NonUnit";
+        CblasUnit: "Unit";
+    });
+
+    round_trip!(side, CBLAS_SIDE {
+        CblasLeft: "Left";
+        CblasRight: "Right";
+    });
+}
@@ -0,0 +1,32 @@
+//! Smoke tests of the service functions of MKL, which the `mkl` feature
+//! links.
+
+#![cfg(feature = "mkl")]
+
+extern crate cblas_sys;
+
+use cblas_sys::mkl::{self, Branch, CbwrError, Verbose};
+
+#[cfg(feature = "std")]
+#[test]
+fn version_string() {
+    let version = mkl::version_string();
+    assert!(version.starts_with("Intel(R)"), "{}", version);
+    assert_eq!(version.trim(), version);
+}
+
+#[test]
+fn cbwr() {
+    // The branch cannot be set anymore if another test has called MKL.
+    match mkl::cbwr_set(Branch::Compatible, false) {
+        Ok(()) | Err(CbwrError::ModeChangeFailure) => {}
+        Err(error) => panic!("{}", error),
+    }
+}
+
+#[test]
+fn verbose() {
+    let previous = mkl::verbose(Verbose::On).unwrap();
+    assert_eq!(mkl::verbose(Verbose::Timing), Some(Verbose::On));
+    assert_eq!(mkl::verbose(previous), Some(Verbose::Timing));
+}
@@ -0,0 +1,39 @@
+//! Smoke tests of the service functions of OpenBLAS, which the `openblas`
+//! feature links.
+
+#![cfg(feature = "openblas")]
+
+extern crate cblas_sys;
+
+use cblas_sys::openblas;
+
+#[cfg(feature = "std")]
+#[test]
+fn config() {
+    let config = openblas::config();
+    let mut options = config.split_whitespace();
+    assert_eq!(options.next(), Some("OpenBLAS"), "{}", config);
+    let version = options.next().unwrap_or("");
+    assert!(
+        version.starts_with(|c: char| c.is_ascii_digit()),
+        "{}",
+        config
+    );
+}
+
+#[test]
+fn corename() {
+    assert!(!openblas::corename().is_empty());
+}
+
+#[test]
+fn threads() {
+    assert!(openblas::num_procs() >= 1);
+    assert!(openblas::parallel().is_some());
+    let previous = openblas::num_threads();
+    assert!(previous >= 1);
+    openblas::set_num_threads(1);
+    assert_eq!(openblas::num_threads(), 1);
+    openblas::set_num_threads(previous);
+    assert_eq!(openblas::num_threads(), previous);
+}
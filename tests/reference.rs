@@ -0,0 +1,4238 @@
+//! The routines of the bundled implementations, i.e., the C sources compiled
+//! via the `vendored` feature and the Rust ones selected via the `pure-rust`
+//! feature, compared with naive loops over dense matrices for both layouts and
+//! every combination of the options, as are the fallbacks of the `extensions`
+//! module if the feature is enabled.
+//!
+//! The storage formats are unpacked independently of the implementations, and
+//! the elements of the storage that a routine must not touch are set to NaN,
+//! which is compared as equal to itself, so that writing them is detected.
+
+#![cfg(any(feature = "pure-rust", feature = "vendored"))]
+#![allow(clippy::too_many_arguments)]
+
+extern crate cblas_sys;
+
+use std::fmt::Debug;
+
+use cblas_sys::*;
+
+const LAYOUTS: [CBLAS_LAYOUT; 2] = [CblasRowMajor, CblasColMajor];
+const TRANSPOSES: [CBLAS_TRANSPOSE; 3] = [CblasNoTrans, CblasTrans, CblasConjTrans];
+const UPLOS: [CBLAS_UPLO; 2] = [CblasUpper, CblasLower];
+const DIAGS: [CBLAS_DIAG; 2] = [CblasNonUnit, CblasUnit];
+const SIDES: [CBLAS_SIDE; 2] = [CblasLeft, CblasRight];
+
+// The increments of x and y, including negative ones.
+const INCREMENTS: [(isize, isize); 3] = [(1, 1), (2, -1), (-3, 2)];
+
+/// An element type together with the routines taking it. The real types stand
+/// in for the complex ones, e.g., `cblas_dsymv` for `cblas_zhemv`.
+trait Blas: Copy + Debug {
+    type Real: Copy;
+
+    const COMPLEX: bool;
+    const EPSILON: f64;
+
+    fn new(re: f64, im: f64) -> Self;
+    fn re(self) -> f64;
+    fn im(self) -> f64;
+    fn real(value: f64) -> Self::Real;
+
+    unsafe fn swap(n: blasint, x: *mut Self, incx: blasint, y: *mut Self, incy: blasint);
+    unsafe fn copy(n: blasint, x: *const Self, incx: blasint, y: *mut Self, incy: blasint);
+    unsafe fn axpy(
+        n: blasint,
+        alpha: Self,
+        x: *const Self,
+        incx: blasint,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn scal(n: blasint, alpha: Self, x: *mut Self, incx: blasint);
+    unsafe fn nrm2(n: blasint, x: *const Self, incx: blasint) -> f64;
+    unsafe fn asum(n: blasint, x: *const Self, incx: blasint) -> f64;
+    unsafe fn iamax(n: blasint, x: *const Self, incx: blasint) -> usize;
+    unsafe fn dotu(
+        n: blasint,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+    ) -> Self;
+    unsafe fn dotc(
+        n: blasint,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+    ) -> Self;
+
+    unsafe fn gemv(
+        layout: CBLAS_LAYOUT,
+        trans: CBLAS_TRANSPOSE,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        x: *const Self,
+        incx: blasint,
+        beta: Self,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn gbmv(
+        layout: CBLAS_LAYOUT,
+        trans: CBLAS_TRANSPOSE,
+        m: blasint,
+        n: blasint,
+        kl: blasint,
+        ku: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        x: *const Self,
+        incx: blasint,
+        beta: Self,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn trmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        a: *const Self,
+        lda: blasint,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn tbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        k: blasint,
+        a: *const Self,
+        lda: blasint,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn tpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        a: *const Self,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn trsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        a: *const Self,
+        lda: blasint,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn tbsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        k: blasint,
+        a: *const Self,
+        lda: blasint,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn tpsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: blasint,
+        a: *const Self,
+        x: *mut Self,
+        incx: blasint,
+    );
+    unsafe fn hemv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        x: *const Self,
+        incx: blasint,
+        beta: Self,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn hbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        k: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        x: *const Self,
+        incx: blasint,
+        beta: Self,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn hpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        x: *const Self,
+        incx: blasint,
+        beta: Self,
+        y: *mut Self,
+        incy: blasint,
+    );
+    unsafe fn geru(
+        layout: CBLAS_LAYOUT,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+        a: *mut Self,
+        lda: blasint,
+    );
+    unsafe fn gerc(
+        layout: CBLAS_LAYOUT,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+        a: *mut Self,
+        lda: blasint,
+    );
+    unsafe fn her(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self::Real,
+        x: *const Self,
+        incx: blasint,
+        a: *mut Self,
+        lda: blasint,
+    );
+    unsafe fn hpr(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self::Real,
+        x: *const Self,
+        incx: blasint,
+        a: *mut Self,
+    );
+    unsafe fn her2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+        a: *mut Self,
+        lda: blasint,
+    );
+    unsafe fn hpr2(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: blasint,
+        alpha: Self,
+        x: *const Self,
+        incx: blasint,
+        y: *const Self,
+        incy: blasint,
+        a: *mut Self,
+    );
+
+    unsafe fn gemm(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        transb: CBLAS_TRANSPOSE,
+        m: blasint,
+        n: blasint,
+        k: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *const Self,
+        ldb: blasint,
+        beta: Self,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn symm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *const Self,
+        ldb: blasint,
+        beta: Self,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn hemm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *const Self,
+        ldb: blasint,
+        beta: Self,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn syrk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blasint,
+        k: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        beta: Self,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn herk(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blasint,
+        k: blasint,
+        alpha: Self::Real,
+        a: *const Self,
+        lda: blasint,
+        beta: Self::Real,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn syr2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blasint,
+        k: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *const Self,
+        ldb: blasint,
+        beta: Self,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn her2k(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        trans: CBLAS_TRANSPOSE,
+        n: blasint,
+        k: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *const Self,
+        ldb: blasint,
+        beta: Self::Real,
+        c: *mut Self,
+        ldc: blasint,
+    );
+    unsafe fn trmm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *mut Self,
+        ldb: blasint,
+    );
+    unsafe fn trsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: blasint,
+        n: blasint,
+        alpha: Self,
+        a: *const Self,
+        lda: blasint,
+        b: *mut Self,
+        ldb: blasint,
+    );
+}
+
+fn value<T: Copy>(x: &T) -> T {
+    *x
+}
+
+fn pointer<T>(x: &T) -> *const T {
+    x
+}
+
+// The scalars are passed by value for the real types and by pointer for the
+// complex ones, as selected by `$arg`.
+macro_rules! blas {
+    (
+        $t:ty, $real:ty, $complex:expr, $epsilon:expr, $arg:ident,
+        [$swap:ident, $copy:ident, $axpy:ident, $scal:ident, $nrm2:ident, $asum:ident,
+         $iamax:ident],
+        [$gemv:ident, $gbmv:ident, $trmv:ident, $tbmv:ident, $tpmv:ident, $trsv:ident,
+         $tbsv:ident, $tpsv:ident, $hemv:ident, $hbmv:ident, $hpmv:ident, $geru:ident,
+         $gerc:ident, $her:ident, $hpr:ident, $her2:ident, $hpr2:ident],
+        [$gemm:ident, $symm:ident, $hemm:ident, $syrk:ident, $herk:ident, $syr2k:ident,
+         $her2k:ident, $trmm:ident, $trsm:ident],
+        { $($items:tt)* }
+    ) => {
+        impl Blas for $t {
+            type Real = $real;
+
+            const COMPLEX: bool = $complex;
+            const EPSILON: f64 = $epsilon;
+
+            fn real(value: f64) -> $real {
+                value as $real
+            }
+
+            $($items)*
+
+            unsafe fn swap(n: blasint, x: *mut Self, incx: blasint, y: *mut Self,
+                           incy: blasint) {
+                $swap(n, x as _, incx, y as _, incy)
+            }
+
+            unsafe fn copy(n: blasint, x: *const Self, incx: blasint, y: *mut Self,
+                           incy: blasint) {
+                $copy(n, x as _, incx, y as _, incy)
+            }
+
+            unsafe fn axpy(n: blasint, alpha: Self, x: *const Self, incx: blasint,
+                           y: *mut Self, incy: blasint) {
+                $axpy(n, $arg(&alpha) as _, x as _, incx, y as _, incy)
+            }
+
+            unsafe fn scal(n: blasint, alpha: Self, x: *mut Self, incx: blasint) {
+                $scal(n, $arg(&alpha) as _, x as _, incx)
+            }
+
+            unsafe fn nrm2(n: blasint, x: *const Self, incx: blasint) -> f64 {
+                $nrm2(n, x as _, incx) as f64
+            }
+
+            unsafe fn asum(n: blasint, x: *const Self, incx: blasint) -> f64 {
+                $asum(n, x as _, incx) as f64
+            }
+
+            unsafe fn iamax(n: blasint, x: *const Self, incx: blasint) -> usize {
+                $iamax(n, x as _, incx) as usize
+            }
+
+            unsafe fn gemv(layout: CBLAS_LAYOUT, trans: CBLAS_TRANSPOSE, m: blasint,
+                           n: blasint, alpha: Self, a: *const Self, lda: blasint,
+                           x: *const Self, incx: blasint, beta: Self, y: *mut Self,
+                           incy: blasint) {
+                $gemv(layout, trans, m, n, $arg(&alpha) as _, a as _, lda, x as _, incx,
+                      $arg(&beta) as _, y as _, incy)
+            }
+
+            unsafe fn gbmv(layout: CBLAS_LAYOUT, trans: CBLAS_TRANSPOSE, m: blasint,
+                           n: blasint, kl: blasint, ku: blasint, alpha: Self,
+                           a: *const Self, lda: blasint, x: *const Self, incx: blasint,
+                           beta: Self, y: *mut Self, incy: blasint) {
+                $gbmv(layout, trans, m, n, kl, ku, $arg(&alpha) as _, a as _, lda, x as _,
+                      incx, $arg(&beta) as _, y as _, incy)
+            }
+
+            unsafe fn trmv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, a: *const Self, lda: blasint,
+                           x: *mut Self, incx: blasint) {
+                $trmv(layout, uplo, trans, diag, n, a as _, lda, x as _, incx)
+            }
+
+            unsafe fn tbmv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, k: blasint, a: *const Self,
+                           lda: blasint, x: *mut Self, incx: blasint) {
+                $tbmv(layout, uplo, trans, diag, n, k, a as _, lda, x as _, incx)
+            }
+
+            unsafe fn tpmv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, a: *const Self, x: *mut Self,
+                           incx: blasint) {
+                $tpmv(layout, uplo, trans, diag, n, a as _, x as _, incx)
+            }
+
+            unsafe fn trsv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, a: *const Self, lda: blasint,
+                           x: *mut Self, incx: blasint) {
+                $trsv(layout, uplo, trans, diag, n, a as _, lda, x as _, incx)
+            }
+
+            unsafe fn tbsv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, k: blasint, a: *const Self,
+                           lda: blasint, x: *mut Self, incx: blasint) {
+                $tbsv(layout, uplo, trans, diag, n, k, a as _, lda, x as _, incx)
+            }
+
+            unsafe fn tpsv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           diag: CBLAS_DIAG, n: blasint, a: *const Self, x: *mut Self,
+                           incx: blasint) {
+                $tpsv(layout, uplo, trans, diag, n, a as _, x as _, incx)
+            }
+
+            unsafe fn hemv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: Self,
+                           a: *const Self, lda: blasint, x: *const Self, incx: blasint,
+                           beta: Self, y: *mut Self, incy: blasint) {
+                $hemv(layout, uplo, n, $arg(&alpha) as _, a as _, lda, x as _, incx,
+                      $arg(&beta) as _, y as _, incy)
+            }
+
+            unsafe fn hbmv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, k: blasint,
+                           alpha: Self, a: *const Self, lda: blasint, x: *const Self,
+                           incx: blasint, beta: Self, y: *mut Self, incy: blasint) {
+                $hbmv(layout, uplo, n, k, $arg(&alpha) as _, a as _, lda, x as _, incx,
+                      $arg(&beta) as _, y as _, incy)
+            }
+
+            unsafe fn hpmv(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: Self,
+                           a: *const Self, x: *const Self, incx: blasint, beta: Self,
+                           y: *mut Self, incy: blasint) {
+                $hpmv(layout, uplo, n, $arg(&alpha) as _, a as _, x as _, incx,
+                      $arg(&beta) as _, y as _, incy)
+            }
+
+            unsafe fn geru(layout: CBLAS_LAYOUT, m: blasint, n: blasint, alpha: Self,
+                           x: *const Self, incx: blasint, y: *const Self, incy: blasint,
+                           a: *mut Self, lda: blasint) {
+                $geru(layout, m, n, $arg(&alpha) as _, x as _, incx, y as _, incy, a as _,
+                      lda)
+            }
+
+            unsafe fn gerc(layout: CBLAS_LAYOUT, m: blasint, n: blasint, alpha: Self,
+                           x: *const Self, incx: blasint, y: *const Self, incy: blasint,
+                           a: *mut Self, lda: blasint) {
+                $gerc(layout, m, n, $arg(&alpha) as _, x as _, incx, y as _, incy, a as _,
+                      lda)
+            }
+
+            unsafe fn her(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: $real,
+                          x: *const Self, incx: blasint, a: *mut Self, lda: blasint) {
+                $her(layout, uplo, n, alpha, x as _, incx, a as _, lda)
+            }
+
+            unsafe fn hpr(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: $real,
+                          x: *const Self, incx: blasint, a: *mut Self) {
+                $hpr(layout, uplo, n, alpha, x as _, incx, a as _)
+            }
+
+            unsafe fn her2(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: Self,
+                           x: *const Self, incx: blasint, y: *const Self, incy: blasint,
+                           a: *mut Self, lda: blasint) {
+                $her2(layout, uplo, n, $arg(&alpha) as _, x as _, incx, y as _, incy,
+                      a as _, lda)
+            }
+
+            unsafe fn hpr2(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, n: blasint, alpha: Self,
+                           x: *const Self, incx: blasint, y: *const Self, incy: blasint,
+                           a: *mut Self) {
+                $hpr2(layout, uplo, n, $arg(&alpha) as _, x as _, incx, y as _, incy,
+                      a as _)
+            }
+
+            unsafe fn gemm(layout: CBLAS_LAYOUT, transa: CBLAS_TRANSPOSE,
+                           transb: CBLAS_TRANSPOSE, m: blasint, n: blasint, k: blasint,
+                           alpha: Self, a: *const Self, lda: blasint, b: *const Self,
+                           ldb: blasint, beta: Self, c: *mut Self, ldc: blasint) {
+                $gemm(layout, transa, transb, m, n, k, $arg(&alpha) as _, a as _, lda,
+                      b as _, ldb, $arg(&beta) as _, c as _, ldc)
+            }
+
+            unsafe fn symm(layout: CBLAS_LAYOUT, side: CBLAS_SIDE, uplo: CBLAS_UPLO,
+                           m: blasint, n: blasint, alpha: Self, a: *const Self,
+                           lda: blasint, b: *const Self, ldb: blasint, beta: Self,
+                           c: *mut Self, ldc: blasint) {
+                $symm(layout, side, uplo, m, n, $arg(&alpha) as _, a as _, lda, b as _, ldb,
+                      $arg(&beta) as _, c as _, ldc)
+            }
+
+            unsafe fn hemm(layout: CBLAS_LAYOUT, side: CBLAS_SIDE, uplo: CBLAS_UPLO,
+                           m: blasint, n: blasint, alpha: Self, a: *const Self,
+                           lda: blasint, b: *const Self, ldb: blasint, beta: Self,
+                           c: *mut Self, ldc: blasint) {
+                $hemm(layout, side, uplo, m, n, $arg(&alpha) as _, a as _, lda, b as _, ldb,
+                      $arg(&beta) as _, c as _, ldc)
+            }
+
+            unsafe fn syrk(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           n: blasint, k: blasint, alpha: Self, a: *const Self,
+                           lda: blasint, beta: Self, c: *mut Self, ldc: blasint) {
+                $syrk(layout, uplo, trans, n, k, $arg(&alpha) as _, a as _, lda,
+                      $arg(&beta) as _, c as _, ldc)
+            }
+
+            unsafe fn herk(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                           n: blasint, k: blasint, alpha: $real, a: *const Self,
+                           lda: blasint, beta: $real, c: *mut Self, ldc: blasint) {
+                $herk(layout, uplo, trans, n, k, alpha, a as _, lda, beta, c as _, ldc)
+            }
+
+            unsafe fn syr2k(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                            n: blasint, k: blasint, alpha: Self, a: *const Self,
+                            lda: blasint, b: *const Self, ldb: blasint, beta: Self,
+                            c: *mut Self, ldc: blasint) {
+                $syr2k(layout, uplo, trans, n, k, $arg(&alpha) as _, a as _, lda, b as _,
+                       ldb, $arg(&beta) as _, c as _, ldc)
+            }
+
+            unsafe fn her2k(layout: CBLAS_LAYOUT, uplo: CBLAS_UPLO, trans: CBLAS_TRANSPOSE,
+                            n: blasint, k: blasint, alpha: Self, a: *const Self,
+                            lda: blasint, b: *const Self, ldb: blasint, beta: $real,
+                            c: *mut Self, ldc: blasint) {
+                $her2k(layout, uplo, trans, n, k, $arg(&alpha) as _, a as _, lda, b as _,
+                       ldb, beta, c as _, ldc)
+            }
+
+            unsafe fn trmm(layout: CBLAS_LAYOUT, side: CBLAS_SIDE, uplo: CBLAS_UPLO,
+                           transa: CBLAS_TRANSPOSE, diag: CBLAS_DIAG, m: blasint,
+                           n: blasint, alpha: Self, a: *const Self, lda: blasint,
+                           b: *mut Self, ldb: blasint) {
+                $trmm(layout, side, uplo, transa, diag, m, n, $arg(&alpha) as _, a as _, lda,
+                      b as _, ldb)
+            }
+
+            unsafe fn trsm(layout: CBLAS_LAYOUT, side: CBLAS_SIDE, uplo: CBLAS_UPLO,
+                           transa: CBLAS_TRANSPOSE, diag: CBLAS_DIAG, m: blasint,
+                           n: blasint, alpha: Self, a: *const Self, lda: blasint,
+                           b: *mut Self, ldb: blasint) {
+                $trsm(layout, side, uplo, transa, diag, m, n, $arg(&alpha) as _, a as _, lda,
+                      b as _, ldb)
+            }
+        }
+    };
+}
+
+macro_rules! real_items {
+    ($t:ty, $dot:ident) => {
+        fn new(re: f64, _: f64) -> $t {
+            re as $t
+        }
+
+        fn re(self) -> f64 {
+            self as f64
+        }
+
+        fn im(self) -> f64 {
+            0.0
+        }
+
+        unsafe fn dotu(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+            $dot(n, x, incx, y, incy)
+        }
+
+        unsafe fn dotc(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+            $dot(n, x, incx, y, incy)
+        }
+    };
+}
+
+macro_rules! complex_items {
+    ($t:ty, $real:ty, $dotu:ident, $dotc:ident) => {
+        fn new(re: f64, im: f64) -> $t {
+            [re as $real, im as $real]
+        }
+
+        fn re(self) -> f64 {
+            self[0] as f64
+        }
+
+        fn im(self) -> f64 {
+            self[1] as f64
+        }
+
+        unsafe fn dotu(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+            let mut dot = [0.0; 2];
+            $dotu(n, x as _, incx, y as _, incy, &mut dot as *mut $t as _);
+            dot
+        }
+
+        unsafe fn dotc(n: blasint, x: *const $t, incx: blasint, y: *const $t, incy: blasint) -> $t {
+            let mut dot = [0.0; 2];
+            $dotc(n, x as _, incx, y as _, incy, &mut dot as *mut $t as _);
+            dot
+        }
+    };
+}
+
+blas! {
+    f32, f32, false, 1e-4, value,
+    [cblas_sswap, cblas_scopy, cblas_saxpy, cblas_sscal, cblas_snrm2, cblas_sasum,
+     cblas_isamax],
+    [cblas_sgemv, cblas_sgbmv, cblas_strmv, cblas_stbmv, cblas_stpmv, cblas_strsv,
+     cblas_stbsv, cblas_stpsv, cblas_ssymv, cblas_ssbmv, cblas_sspmv, cblas_sger, cblas_sger,
+     cblas_ssyr, cblas_sspr, cblas_ssyr2, cblas_sspr2],
+    [cblas_sgemm, cblas_ssymm, cblas_ssymm, cblas_ssyrk, cblas_ssyrk, cblas_ssyr2k,
+     cblas_ssyr2k, cblas_strmm, cblas_strsm],
+    { real_items!(f32, cblas_sdot); }
+}
+
+blas! {
+    f64, f64, false, 1e-10, value,
+    [cblas_dswap, cblas_dcopy, cblas_daxpy, cblas_dscal, cblas_dnrm2, cblas_dasum,
+     cblas_idamax],
+    [cblas_dgemv, cblas_dgbmv, cblas_dtrmv, cblas_dtbmv, cblas_dtpmv, cblas_dtrsv,
+     cblas_dtbsv, cblas_dtpsv, cblas_dsymv, cblas_dsbmv, cblas_dspmv, cblas_dger, cblas_dger,
+     cblas_dsyr, cblas_dspr, cblas_dsyr2, cblas_dspr2],
+    [cblas_dgemm, cblas_dsymm, cblas_dsymm, cblas_dsyrk, cblas_dsyrk, cblas_dsyr2k,
+     cblas_dsyr2k, cblas_dtrmm, cblas_dtrsm],
+    { real_items!(f64, cblas_ddot); }
+}
+
+blas! {
+    c_float_complex, f32, true, 1e-4, pointer,
+    [cblas_cswap, cblas_ccopy, cblas_caxpy, cblas_cscal, cblas_scnrm2, cblas_scasum,
+     cblas_icamax],
+    [cblas_cgemv, cblas_cgbmv, cblas_ctrmv, cblas_ctbmv, cblas_ctpmv, cblas_ctrsv,
+     cblas_ctbsv, cblas_ctpsv, cblas_chemv, cblas_chbmv, cblas_chpmv, cblas_cgeru,
+     cblas_cgerc, cblas_cher, cblas_chpr, cblas_cher2, cblas_chpr2],
+    [cblas_cgemm, cblas_csymm, cblas_chemm, cblas_csyrk, cblas_cherk, cblas_csyr2k,
+     cblas_cher2k, cblas_ctrmm, cblas_ctrsm],
+    { complex_items!(c_float_complex, f32, cblas_cdotu_sub, cblas_cdotc_sub); }
+}
+
+blas! {
+    c_double_complex, f64, true, 1e-10, pointer,
+    [cblas_zswap, cblas_zcopy, cblas_zaxpy, cblas_zscal, cblas_dznrm2, cblas_dzasum,
+     cblas_izamax],
+    [cblas_zgemv, cblas_zgbmv, cblas_ztrmv, cblas_ztbmv, cblas_ztpmv, cblas_ztrsv,
+     cblas_ztbsv, cblas_ztpsv, cblas_zhemv, cblas_zhbmv, cblas_zhpmv, cblas_zgeru,
+     cblas_zgerc, cblas_zher, cblas_zhpr, cblas_zher2, cblas_zhpr2],
+    [cblas_zgemm, cblas_zsymm, cblas_zhemm, cblas_zsyrk, cblas_zherk, cblas_zsyr2k,
+     cblas_zher2k, cblas_ztrmm, cblas_ztrsm],
+    { complex_items!(c_double_complex, f64, cblas_zdotu_sub, cblas_zdotc_sub); }
+}
+
+fn zero<T: Blas>() -> T {
+    T::new(0.0, 0.0)
+}
+
+fn one<T: Blas>() -> T {
+    T::new(1.0, 0.0)
+}
+
+fn nan<T: Blas>() -> T {
+    T::new(f64::NAN, f64::NAN)
+}
+
+fn add<T: Blas>(a: T, b: T) -> T {
+    T::new(a.re() + b.re(), a.im() + b.im())
+}
+
+fn mul<T: Blas>(a: T, b: T) -> T {
+    T::new(
+        a.re() * b.re() - a.im() * b.im(),
+        a.re() * b.im() + a.im() * b.re(),
+    )
+}
+
+fn conj<T: Blas>(a: T) -> T {
+    T::new(a.re(), -a.im())
+}
+
+fn is_zero<T: Blas>(a: T) -> bool {
+    a.re() == 0.0 && a.im() == 0.0
+}
+
+/// Compute `alpha * t + beta * y`, where `y` is not read if `beta` is zero.
+fn update<T: Blas>(alpha: T, t: T, beta: T, y: T) -> T {
+    if is_zero(beta) {
+        mul(alpha, t)
+    } else {
+        add(mul(alpha, t), mul(beta, y))
+    }
+}
+
+fn close<T: Blas>(actual: T, expected: T) -> bool {
+    let near = |a: f64, e: f64| {
+        (a.is_nan() && e.is_nan()) || (a - e).abs() <= T::EPSILON * (1.0 + e.abs())
+    };
+    near(actual.re(), expected.re()) && near(actual.im(), expected.im())
+}
+
+fn assert_close<T: Blas>(actual: &[T], expected: &[T], context: &str) {
+    assert_eq!(actual.len(), expected.len(), "{}", context);
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            close(a, e),
+            "{}: element {} is {:?} instead of {:?}",
+            context,
+            i,
+            a,
+            e
+        );
+    }
+}
+
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Rng {
+        Rng(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Return a number in [-1, 1).
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+
+    fn value<T: Blas>(&mut self) -> T {
+        T::new(self.next(), self.next())
+    }
+
+    fn vector<T: Blas>(&mut self, n: usize) -> Vec<T> {
+        (0..n).map(|_| self.value()).collect()
+    }
+}
+
+/// The position of the ith element of a vector of length n in its storage.
+fn position(n: usize, inc: isize, i: usize) -> usize {
+    if inc > 0 {
+        i * inc as usize
+    } else {
+        (n - 1 - i) * inc.unsigned_abs()
+    }
+}
+
+/// The storage of a vector with the given increment, whose gaps are NaN.
+fn strided<T: Blas>(v: &[T], inc: isize) -> Vec<T> {
+    let n = v.len();
+    let len = if n == 0 {
+        1
+    } else {
+        1 + (n - 1) * inc.unsigned_abs()
+    };
+    let mut storage = vec![nan(); len];
+    for (i, &value) in v.iter().enumerate() {
+        storage[position(n, inc, i)] = value;
+    }
+    storage
+}
+
+/// The elements of a vector of length n in its storage.
+fn elements<T: Blas>(storage: &[T], n: usize, inc: isize) -> Vec<T> {
+    (0..n).map(|i| storage[position(n, inc, i)]).collect()
+}
+
+/// A dense matrix stored by rows.
+#[derive(Clone, Debug)]
+struct Dense<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Blas> Dense<T> {
+    fn zeros(rows: usize, cols: usize) -> Dense<T> {
+        Dense {
+            rows,
+            cols,
+            data: vec![zero(); rows * cols],
+        }
+    }
+
+    fn random(rng: &mut Rng, rows: usize, cols: usize) -> Dense<T> {
+        Dense {
+            rows,
+            cols,
+            data: rng.vector(rows * cols),
+        }
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self.data[i * self.cols + j]
+    }
+
+    fn set(&mut self, i: usize, j: usize, value: T) {
+        self.data[i * self.cols + j] = value;
+    }
+
+    fn op(&self, trans: CBLAS_TRANSPOSE) -> Dense<T> {
+        if trans == CblasNoTrans {
+            return self.clone();
+        }
+        let mut result = Dense::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let value = self.get(i, j);
+                let value = if trans == CblasConjTrans {
+                    conj(value)
+                } else {
+                    value
+                };
+                result.set(j, i, value);
+            }
+        }
+        result
+    }
+
+    fn times(&self, other: &Dense<T>) -> Dense<T> {
+        assert_eq!(self.cols, other.rows);
+        let mut result = Dense::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = zero();
+                for l in 0..self.cols {
+                    sum = add(sum, mul(self.get(i, l), other.get(l, j)));
+                }
+                result.set(i, j, sum);
+            }
+        }
+        result
+    }
+
+    fn times_vector(&self, x: &[T]) -> Vec<T> {
+        assert_eq!(self.cols, x.len());
+        (0..self.rows)
+            .map(|i| (0..self.cols).fold(zero(), |sum, j| add(sum, mul(self.get(i, j), x[j]))))
+            .collect()
+    }
+}
+
+/// The offset of element (i, j) in the storage of a general matrix.
+fn general_offset(layout: CBLAS_LAYOUT, ld: usize, i: usize, j: usize) -> usize {
+    if layout == CblasColMajor {
+        i + j * ld
+    } else {
+        i * ld + j
+    }
+}
+
+/// The storage of a general matrix with the given leading dimension, whose
+/// padding is NaN.
+fn general<T: Blas>(layout: CBLAS_LAYOUT, a: &Dense<T>, ld: usize) -> Vec<T> {
+    let major = if layout == CblasColMajor {
+        a.cols
+    } else {
+        a.rows
+    };
+    let mut storage = vec![nan(); (ld * major).max(1)];
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            storage[general_offset(layout, ld, i, j)] = a.get(i, j);
+        }
+    }
+    storage
+}
+
+/// The leading dimension of a general matrix with one element of padding.
+fn leading(layout: CBLAS_LAYOUT, rows: usize, cols: usize) -> usize {
+    if layout == CblasColMajor {
+        rows + 1
+    } else {
+        cols + 1
+    }
+}
+
+/// The offset of element (i, j) in the band storage of a general matrix.
+fn band_offset(
+    layout: CBLAS_LAYOUT,
+    kl: usize,
+    ku: usize,
+    ld: usize,
+    i: usize,
+    j: usize,
+) -> Option<usize> {
+    if i > j + kl || j > i + ku {
+        return None;
+    }
+    Some(if layout == CblasColMajor {
+        ku + i - j + j * ld
+    } else {
+        i * ld + kl + j - i
+    })
+}
+
+/// The storage formats of symmetric, Hermitian, and triangular matrices.
+#[derive(Clone, Copy, Debug)]
+enum Storage {
+    Full,
+    Band(usize),
+    Packed,
+}
+
+const STORAGES: [Storage; 3] = [Storage::Full, Storage::Band(2), Storage::Packed];
+
+impl Storage {
+    /// The leading dimension with one element of padding.
+    fn leading(self, n: usize) -> usize {
+        match self {
+            Storage::Full => n + 1,
+            Storage::Band(k) => k + 2,
+            Storage::Packed => 0,
+        }
+    }
+
+    fn len(self, n: usize) -> usize {
+        match self {
+            Storage::Full | Storage::Band(_) => self.leading(n) * n,
+            Storage::Packed => n * (n + 1) / 2,
+        }
+        .max(1)
+    }
+
+    /// The offset of element (i, j) of a matrix of order n, if it is stored.
+    fn offset(
+        self,
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: usize,
+        i: usize,
+        j: usize,
+    ) -> Option<usize> {
+        let upper = uplo == CblasUpper;
+        if (upper && i > j) || (!upper && i < j) {
+            return None;
+        }
+        let ld = self.leading(n);
+        let col = layout == CblasColMajor;
+        match self {
+            Storage::Full => Some(general_offset(layout, ld, i, j)),
+            Storage::Band(k) => {
+                if i.max(j) - i.min(j) > k {
+                    return None;
+                }
+                Some(match (col, upper) {
+                    (true, true) => k - (j - i) + j * ld,
+                    (true, false) => (i - j) + j * ld,
+                    (false, true) => i * ld + (j - i),
+                    (false, false) => i * ld + k - (i - j),
+                })
+            }
+            Storage::Packed => Some(match (col, upper) {
+                (true, true) => j * (j + 1) / 2 + i,
+                (true, false) => j * (2 * n - j + 1) / 2 + (i - j),
+                (false, true) => i * (2 * n - i + 1) / 2 + (j - i),
+                (false, false) => i * (i + 1) / 2 + j,
+            }),
+        }
+    }
+
+    /// A random stored triangle, whose padding is NaN. If the matrix is to be
+    /// inverted, its diagonal is made dominant.
+    fn random<T: Blas>(
+        self,
+        rng: &mut Rng,
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: usize,
+        dominant: bool,
+    ) -> Vec<T> {
+        let mut storage = vec![nan(); self.len(n)];
+        for i in 0..n {
+            for j in 0..n {
+                if let Some(offset) = self.offset(layout, uplo, n, i, j) {
+                    let value = rng.value();
+                    storage[offset] = if dominant && i == j {
+                        add(value, T::new(n as f64 + 1.0, 0.0))
+                    } else {
+                        value
+                    };
+                }
+            }
+        }
+        storage
+    }
+
+    /// The symmetric matrix, or the Hermitian one if `hermitian`, whose
+    /// triangle is stored, ignoring the imaginary part of the diagonal of a
+    /// Hermitian matrix.
+    fn symmetric<T: Blas>(
+        self,
+        storage: &[T],
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: usize,
+        hermitian: bool,
+    ) -> Dense<T> {
+        let mut a = Dense::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let value = if let Some(offset) = self.offset(layout, uplo, n, i, j) {
+                    let value = storage[offset];
+                    if hermitian && i == j {
+                        T::new(value.re(), 0.0)
+                    } else {
+                        value
+                    }
+                } else if let Some(offset) = self.offset(layout, uplo, n, j, i) {
+                    if hermitian {
+                        conj(storage[offset])
+                    } else {
+                        storage[offset]
+                    }
+                } else {
+                    zero()
+                };
+                a.set(i, j, value);
+            }
+        }
+        a
+    }
+
+    /// The triangular matrix whose triangle is stored.
+    fn triangular<T: Blas>(
+        self,
+        storage: &[T],
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        diag: CBLAS_DIAG,
+        n: usize,
+    ) -> Dense<T> {
+        let mut a = Dense::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                if i == j && diag == CblasUnit {
+                    a.set(i, j, one());
+                } else if let Some(offset) = self.offset(layout, uplo, n, i, j) {
+                    a.set(i, j, storage[offset]);
+                }
+            }
+        }
+        a
+    }
+
+    /// The storage with the stored triangle replaced by that of a matrix,
+    /// dropping the imaginary part of the diagonal if `hermitian`.
+    fn store<T: Blas>(
+        self,
+        storage: &[T],
+        a: &Dense<T>,
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        hermitian: bool,
+    ) -> Vec<T> {
+        let n = a.rows;
+        let mut storage = storage.to_vec();
+        for i in 0..n {
+            for j in 0..n {
+                if let Some(offset) = self.offset(layout, uplo, n, i, j) {
+                    let value = a.get(i, j);
+                    storage[offset] = if hermitian && i == j {
+                        T::new(value.re(), 0.0)
+                    } else {
+                        value
+                    };
+                }
+            }
+        }
+        storage
+    }
+}
+
+fn int(value: usize) -> blasint {
+    value as blasint
+}
+
+fn swap<T: Blas>() {
+    let mut rng = Rng::new();
+    for &(incx, incy) in &INCREMENTS {
+        let n = 5;
+        let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+        let (mut xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+        unsafe {
+            T::swap(
+                int(n),
+                xs.as_mut_ptr(),
+                incx as blasint,
+                ys.as_mut_ptr(),
+                incy as blasint,
+            )
+        };
+        assert_close(&xs, &strided(&y, incx), "swap x");
+        assert_close(&ys, &strided(&x, incy), "swap y");
+    }
+}
+
+fn copy<T: Blas>() {
+    let mut rng = Rng::new();
+    for &(incx, incy) in &INCREMENTS {
+        let n = 5;
+        let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+        let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+        unsafe {
+            T::copy(
+                int(n),
+                xs.as_ptr(),
+                incx as blasint,
+                ys.as_mut_ptr(),
+                incy as blasint,
+            )
+        };
+        assert_close(&ys, &strided(&x, incy), "copy");
+    }
+}
+
+fn axpy<T: Blas>() {
+    let mut rng = Rng::new();
+    for &(incx, incy) in &INCREMENTS {
+        let n = 5;
+        let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+        let alpha = rng.value::<T>();
+        let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+        unsafe {
+            T::axpy(
+                int(n),
+                alpha,
+                xs.as_ptr(),
+                incx as blasint,
+                ys.as_mut_ptr(),
+                incy as blasint,
+            )
+        };
+        let expected = (0..n)
+            .map(|i| update(alpha, x[i], one(), y[i]))
+            .collect::<Vec<T>>();
+        assert_close(&ys, &strided(&expected, incy), "axpy");
+    }
+}
+
+fn scal<T: Blas>() {
+    let mut rng = Rng::new();
+    for &inc in &[1, 3] {
+        let n = 5;
+        let x = rng.vector::<T>(n);
+        let alpha = rng.value::<T>();
+        let mut xs = strided(&x, inc);
+        unsafe { T::scal(int(n), alpha, xs.as_mut_ptr(), inc as blasint) };
+        let expected = x.iter().map(|&x| mul(alpha, x)).collect::<Vec<T>>();
+        assert_close(&xs, &strided(&expected, inc), "scal");
+    }
+}
+
+fn dot<T: Blas>() {
+    let mut rng = Rng::new();
+    for &(incx, incy) in &INCREMENTS {
+        let n = 5;
+        let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+        let (xs, ys) = (strided(&x, incx), strided(&y, incy));
+        let (incx, incy) = (incx as blasint, incy as blasint);
+        let dotu = unsafe { T::dotu(int(n), xs.as_ptr(), incx, ys.as_ptr(), incy) };
+        let dotc = unsafe { T::dotc(int(n), xs.as_ptr(), incx, ys.as_ptr(), incy) };
+        let expected_dotu = (0..n).fold(zero(), |sum, i| add(sum, mul(x[i], y[i])));
+        let expected_dotc = (0..n).fold(zero(), |sum, i| add(sum, mul(conj(x[i]), y[i])));
+        assert_close(&[dotu], &[expected_dotu], "dotu");
+        assert_close(&[dotc], &[expected_dotc], "dotc");
+    }
+}
+
+fn norms<T: Blas>() {
+    let mut rng = Rng::new();
+    for &inc in &[1, 2] {
+        let n = 6;
+        let x = rng.vector::<T>(n);
+        let xs = strided(&x, inc);
+        let inc = inc as blasint;
+        let nrm2 = unsafe { T::nrm2(int(n), xs.as_ptr(), inc) };
+        let asum = unsafe { T::asum(int(n), xs.as_ptr(), inc) };
+        let iamax = unsafe { T::iamax(int(n), xs.as_ptr(), inc) };
+        let expected_nrm2 = x
+            .iter()
+            .map(|x| x.re() * x.re() + x.im() * x.im())
+            .sum::<f64>()
+            .sqrt();
+        let magnitudes = x
+            .iter()
+            .map(|x| x.re().abs() + x.im().abs())
+            .collect::<Vec<f64>>();
+        let expected_asum = magnitudes.iter().sum::<f64>();
+        let mut expected_iamax = 0;
+        for (i, &magnitude) in magnitudes.iter().enumerate() {
+            if magnitude > magnitudes[expected_iamax] {
+                expected_iamax = i;
+            }
+        }
+        assert_close(&[T::new(nrm2, 0.0)], &[T::new(expected_nrm2, 0.0)], "nrm2");
+        assert_close(&[T::new(asum, 0.0)], &[T::new(expected_asum, 0.0)], "asum");
+        assert_eq!(iamax, expected_iamax, "iamax");
+    }
+}
+
+fn gemv<T: Blas>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    for &layout in &LAYOUTS {
+        for &trans in &TRANSPOSES {
+            for &(incx, incy) in &INCREMENTS {
+                let a = Dense::<T>::random(&mut rng, m, n);
+                let lda = leading(layout, m, n);
+                let storage = general(layout, &a, lda);
+                let op = a.op(trans);
+                let (x, y) = (rng.vector::<T>(op.cols), rng.vector::<T>(op.rows));
+                let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+                unsafe {
+                    T::gemv(
+                        layout,
+                        trans,
+                        int(m),
+                        int(n),
+                        alpha,
+                        storage.as_ptr(),
+                        int(lda),
+                        xs.as_ptr(),
+                        incx as blasint,
+                        beta,
+                        ys.as_mut_ptr(),
+                        incy as blasint,
+                    )
+                };
+                let ax = op.times_vector(&x);
+                let expected = (0..y.len())
+                    .map(|i| update(alpha, ax[i], beta, y[i]))
+                    .collect::<Vec<T>>();
+                let context = format!("gemv {:?} {:?} {} {}", layout, trans, incx, incy);
+                assert_close(&ys, &strided(&expected, incy), &context);
+            }
+        }
+    }
+}
+
+fn gbmv<T: Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, kl, ku) = (4, 5, 1, 2);
+    for &layout in &LAYOUTS {
+        for &trans in &TRANSPOSES {
+            for &(incx, incy) in &INCREMENTS {
+                let lda = kl + ku + 2;
+                let major = if layout == CblasColMajor { n } else { m };
+                let mut storage = vec![nan::<T>(); lda * major];
+                let mut a = Dense::<T>::zeros(m, n);
+                for i in 0..m {
+                    for j in 0..n {
+                        if let Some(offset) = band_offset(layout, kl, ku, lda, i, j) {
+                            let value = rng.value();
+                            storage[offset] = value;
+                            a.set(i, j, value);
+                        }
+                    }
+                }
+                let op = a.op(trans);
+                let (x, y) = (rng.vector::<T>(op.cols), rng.vector::<T>(op.rows));
+                let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+                unsafe {
+                    T::gbmv(
+                        layout,
+                        trans,
+                        int(m),
+                        int(n),
+                        int(kl),
+                        int(ku),
+                        alpha,
+                        storage.as_ptr(),
+                        int(lda),
+                        xs.as_ptr(),
+                        incx as blasint,
+                        beta,
+                        ys.as_mut_ptr(),
+                        incy as blasint,
+                    )
+                };
+                let ax = op.times_vector(&x);
+                let expected = (0..y.len())
+                    .map(|i| update(alpha, ax[i], beta, y[i]))
+                    .collect::<Vec<T>>();
+                let context = format!("gbmv {:?} {:?} {} {}", layout, trans, incx, incy);
+                assert_close(&ys, &strided(&expected, incy), &context);
+            }
+        }
+    }
+}
+
+/// Call trmv, tbmv, or tpmv, or the corresponding solver, depending on the
+/// storage.
+unsafe fn triangular<T: Blas>(
+    solve: bool,
+    storage: Storage,
+    layout: CBLAS_LAYOUT,
+    uplo: CBLAS_UPLO,
+    trans: CBLAS_TRANSPOSE,
+    diag: CBLAS_DIAG,
+    n: usize,
+    a: &[T],
+    x: &mut [T],
+    incx: isize,
+) {
+    let (n, lda, x, incx) = (
+        int(n),
+        int(storage.leading(n)),
+        x.as_mut_ptr(),
+        incx as blasint,
+    );
+    match (storage, solve) {
+        (Storage::Full, false) => T::trmv(layout, uplo, trans, diag, n, a.as_ptr(), lda, x, incx),
+        (Storage::Full, true) => T::trsv(layout, uplo, trans, diag, n, a.as_ptr(), lda, x, incx),
+        (Storage::Band(k), false) => T::tbmv(
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            int(k),
+            a.as_ptr(),
+            lda,
+            x,
+            incx,
+        ),
+        (Storage::Band(k), true) => T::tbsv(
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            int(k),
+            a.as_ptr(),
+            lda,
+            x,
+            incx,
+        ),
+        (Storage::Packed, false) => T::tpmv(layout, uplo, trans, diag, n, a.as_ptr(), x, incx),
+        (Storage::Packed, true) => T::tpsv(layout, uplo, trans, diag, n, a.as_ptr(), x, incx),
+    }
+}
+
+fn trmv<T: Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    for &storage in &STORAGES {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &trans in &TRANSPOSES {
+                    for &diag in &DIAGS {
+                        for &incx in &[1, -2] {
+                            let a = storage.random::<T>(&mut rng, layout, uplo, n, false);
+                            let op = storage.triangular(&a, layout, uplo, diag, n).op(trans);
+                            let x = rng.vector::<T>(n);
+                            let mut xs = strided(&x, incx);
+                            unsafe {
+                                triangular(
+                                    false, storage, layout, uplo, trans, diag, n, &a, &mut xs, incx,
+                                )
+                            };
+                            let context = format!(
+                                "{:?} {:?} {:?} {:?} {:?} {}",
+                                storage, layout, uplo, trans, diag, incx
+                            );
+                            assert_close(&xs, &strided(&op.times_vector(&x), incx), &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn trsv<T: Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    for &storage in &STORAGES {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &trans in &TRANSPOSES {
+                    for &diag in &DIAGS {
+                        for &incx in &[1, -2] {
+                            let a = storage.random::<T>(&mut rng, layout, uplo, n, true);
+                            let op = storage.triangular(&a, layout, uplo, diag, n).op(trans);
+                            let b = rng.vector::<T>(n);
+                            let mut xs = strided(&b, incx);
+                            unsafe {
+                                triangular(
+                                    true, storage, layout, uplo, trans, diag, n, &a, &mut xs, incx,
+                                )
+                            };
+                            let context = format!(
+                                "solve {:?} {:?} {:?} {:?} {:?} {}",
+                                storage, layout, uplo, trans, diag, incx
+                            );
+                            let x = elements(&xs, n, incx);
+                            assert_close(&xs, &strided(&x, incx), &context);
+                            assert_close(&op.times_vector(&x), &b, &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn hemv<T: Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    for &storage in &STORAGES {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &(incx, incy) in &INCREMENTS {
+                    let a = storage.random::<T>(&mut rng, layout, uplo, n, false);
+                    let dense = storage.symmetric(&a, layout, uplo, n, T::COMPLEX);
+                    let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+                    let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                    let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+                    let (x_, y_) = (xs.as_ptr(), ys.as_mut_ptr());
+                    let (incx_, incy_) = (incx as blasint, incy as blasint);
+                    let lda = int(storage.leading(n));
+                    unsafe {
+                        match storage {
+                            Storage::Full => T::hemv(
+                                layout,
+                                uplo,
+                                int(n),
+                                alpha,
+                                a.as_ptr(),
+                                lda,
+                                x_,
+                                incx_,
+                                beta,
+                                y_,
+                                incy_,
+                            ),
+                            Storage::Band(k) => T::hbmv(
+                                layout,
+                                uplo,
+                                int(n),
+                                int(k),
+                                alpha,
+                                a.as_ptr(),
+                                lda,
+                                x_,
+                                incx_,
+                                beta,
+                                y_,
+                                incy_,
+                            ),
+                            Storage::Packed => T::hpmv(
+                                layout,
+                                uplo,
+                                int(n),
+                                alpha,
+                                a.as_ptr(),
+                                x_,
+                                incx_,
+                                beta,
+                                y_,
+                                incy_,
+                            ),
+                        }
+                    };
+                    let ax = dense.times_vector(&x);
+                    let expected = (0..n)
+                        .map(|i| update(alpha, ax[i], beta, y[i]))
+                        .collect::<Vec<T>>();
+                    let context = format!(
+                        "hemv {:?} {:?} {:?} {} {}",
+                        storage, layout, uplo, incx, incy
+                    );
+                    assert_close(&ys, &strided(&expected, incy), &context);
+                }
+            }
+        }
+    }
+}
+
+/// The general matrix in its storage.
+fn unstore<T: Blas>(
+    layout: CBLAS_LAYOUT,
+    storage: &[T],
+    rows: usize,
+    cols: usize,
+    ld: usize,
+) -> Dense<T> {
+    let mut a = Dense::zeros(rows, cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            a.set(i, j, storage[general_offset(layout, ld, i, j)]);
+        }
+    }
+    a
+}
+
+fn ger<T: Blas>() {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    for &layout in &LAYOUTS {
+        for &conjugate in &[false, true] {
+            for &(incx, incy) in &INCREMENTS {
+                let a = Dense::<T>::random(&mut rng, m, n);
+                let lda = leading(layout, m, n);
+                let mut storage = general(layout, &a, lda);
+                let (x, y) = (rng.vector::<T>(m), rng.vector::<T>(n));
+                let alpha = rng.value::<T>();
+                let (xs, ys) = (strided(&x, incx), strided(&y, incy));
+                let (x_, y_, a_) = (xs.as_ptr(), ys.as_ptr(), storage.as_mut_ptr());
+                let (incx_, incy_) = (incx as blasint, incy as blasint);
+                unsafe {
+                    if conjugate {
+                        T::gerc(
+                            layout,
+                            int(m),
+                            int(n),
+                            alpha,
+                            x_,
+                            incx_,
+                            y_,
+                            incy_,
+                            a_,
+                            int(lda),
+                        )
+                    } else {
+                        T::geru(
+                            layout,
+                            int(m),
+                            int(n),
+                            alpha,
+                            x_,
+                            incx_,
+                            y_,
+                            incy_,
+                            a_,
+                            int(lda),
+                        )
+                    }
+                };
+                let mut expected = a.clone();
+                for (i, &xi) in x.iter().enumerate() {
+                    for (j, &yj) in y.iter().enumerate() {
+                        let yj = if conjugate { conj(yj) } else { yj };
+                        expected.set(i, j, update(alpha, mul(xi, yj), one(), a.get(i, j)));
+                    }
+                }
+                let context = format!("ger {:?} {} {} {}", layout, conjugate, incx, incy);
+                assert_close(&storage, &general(layout, &expected, lda), &context);
+            }
+        }
+    }
+}
+
+fn her<T: Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    for &storage in &[Storage::Full, Storage::Packed] {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &incx in &[1, -2] {
+                    let original = storage.random::<T>(&mut rng, layout, uplo, n, false);
+                    let dense = storage.symmetric(&original, layout, uplo, n, T::COMPLEX);
+                    let x = rng.vector::<T>(n);
+                    let alpha = rng.next();
+                    let xs = strided(&x, incx);
+                    let mut a = original.clone();
+                    let (n_, alpha_, incx_) = (int(n), T::real(alpha), incx as blasint);
+                    unsafe {
+                        match storage {
+                            Storage::Packed => {
+                                T::hpr(layout, uplo, n_, alpha_, xs.as_ptr(), incx_, a.as_mut_ptr())
+                            }
+                            _ => T::her(
+                                layout,
+                                uplo,
+                                n_,
+                                alpha_,
+                                xs.as_ptr(),
+                                incx_,
+                                a.as_mut_ptr(),
+                                int(storage.leading(n)),
+                            ),
+                        }
+                    };
+                    let mut expected = dense.clone();
+                    for i in 0..n {
+                        for j in 0..n {
+                            let xx = mul(x[i], conj(x[j]));
+                            let value = update(T::new(alpha, 0.0), xx, one(), dense.get(i, j));
+                            expected.set(i, j, value);
+                        }
+                    }
+                    let expected = storage.store(&original, &expected, layout, uplo, T::COMPLEX);
+                    let context = format!("her {:?} {:?} {:?} {}", storage, layout, uplo, incx);
+                    assert_close(&a, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn her2<T: Blas>() {
+    let mut rng = Rng::new();
+    let n = 4;
+    for &storage in &[Storage::Full, Storage::Packed] {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &(incx, incy) in &INCREMENTS {
+                    let original = storage.random::<T>(&mut rng, layout, uplo, n, false);
+                    let dense = storage.symmetric(&original, layout, uplo, n, T::COMPLEX);
+                    let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+                    let alpha = rng.value::<T>();
+                    let (xs, ys) = (strided(&x, incx), strided(&y, incy));
+                    let mut a = original.clone();
+                    let (x_, y_) = (xs.as_ptr(), ys.as_ptr());
+                    let (incx_, incy_) = (incx as blasint, incy as blasint);
+                    unsafe {
+                        match storage {
+                            Storage::Packed => T::hpr2(
+                                layout,
+                                uplo,
+                                int(n),
+                                alpha,
+                                x_,
+                                incx_,
+                                y_,
+                                incy_,
+                                a.as_mut_ptr(),
+                            ),
+                            _ => T::her2(
+                                layout,
+                                uplo,
+                                int(n),
+                                alpha,
+                                x_,
+                                incx_,
+                                y_,
+                                incy_,
+                                a.as_mut_ptr(),
+                                int(storage.leading(n)),
+                            ),
+                        }
+                    };
+                    let mut expected = dense.clone();
+                    for i in 0..n {
+                        for j in 0..n {
+                            let xy = mul(alpha, mul(x[i], conj(y[j])));
+                            let yx = mul(conj(alpha), mul(y[i], conj(x[j])));
+                            expected.set(i, j, add(dense.get(i, j), add(xy, yx)));
+                        }
+                    }
+                    let expected = storage.store(&original, &expected, layout, uplo, T::COMPLEX);
+                    let context = format!(
+                        "her2 {:?} {:?} {:?} {} {}",
+                        storage, layout, uplo, incx, incy
+                    );
+                    assert_close(&a, &expected, &context);
+                }
+            }
+        }
+    }
+}
+
+fn gemm<T: Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, k) = (3, 4, 2);
+    for &layout in &LAYOUTS {
+        for &transa in &TRANSPOSES {
+            for &transb in &TRANSPOSES {
+                let a = if transa == CblasNoTrans {
+                    Dense::<T>::random(&mut rng, m, k)
+                } else {
+                    Dense::<T>::random(&mut rng, k, m)
+                };
+                let b = if transb == CblasNoTrans {
+                    Dense::<T>::random(&mut rng, k, n)
+                } else {
+                    Dense::<T>::random(&mut rng, n, k)
+                };
+                let c = Dense::<T>::random(&mut rng, m, n);
+                let (lda, ldb, ldc) = (
+                    leading(layout, a.rows, a.cols),
+                    leading(layout, b.rows, b.cols),
+                    leading(layout, m, n),
+                );
+                let (a_, b_) = (general(layout, &a, lda), general(layout, &b, ldb));
+                let mut c_ = general(layout, &c, ldc);
+                let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                unsafe {
+                    T::gemm(
+                        layout,
+                        transa,
+                        transb,
+                        int(m),
+                        int(n),
+                        int(k),
+                        alpha,
+                        a_.as_ptr(),
+                        int(lda),
+                        b_.as_ptr(),
+                        int(ldb),
+                        beta,
+                        c_.as_mut_ptr(),
+                        int(ldc),
+                    )
+                };
+                let product = a.op(transa).times(&b.op(transb));
+                let mut expected = c.clone();
+                for i in 0..m {
+                    for j in 0..n {
+                        expected.set(i, j, update(alpha, product.get(i, j), beta, c.get(i, j)));
+                    }
+                }
+                let context = format!("gemm {:?} {:?} {:?}", layout, transa, transb);
+                assert_close(&c_, &general(layout, &expected, ldc), &context);
+            }
+        }
+    }
+}
+
+fn symm<T: Blas>(hermitian: bool) {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    for &layout in &LAYOUTS {
+        for &side in &SIDES {
+            for &uplo in &UPLOS {
+                let order = if side == CblasLeft { m } else { n };
+                let a = Storage::Full.random::<T>(&mut rng, layout, uplo, order, false);
+                let dense = Storage::Full.symmetric(&a, layout, uplo, order, hermitian);
+                let (b, c) = (
+                    Dense::<T>::random(&mut rng, m, n),
+                    Dense::<T>::random(&mut rng, m, n),
+                );
+                let ld = leading(layout, m, n);
+                let b_ = general(layout, &b, ld);
+                let mut c_ = general(layout, &c, ld);
+                let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                let args = (int(m), int(n), int(Storage::Full.leading(order)), int(ld));
+                unsafe {
+                    if hermitian {
+                        T::hemm(
+                            layout,
+                            side,
+                            uplo,
+                            args.0,
+                            args.1,
+                            alpha,
+                            a.as_ptr(),
+                            args.2,
+                            b_.as_ptr(),
+                            args.3,
+                            beta,
+                            c_.as_mut_ptr(),
+                            args.3,
+                        )
+                    } else {
+                        T::symm(
+                            layout,
+                            side,
+                            uplo,
+                            args.0,
+                            args.1,
+                            alpha,
+                            a.as_ptr(),
+                            args.2,
+                            b_.as_ptr(),
+                            args.3,
+                            beta,
+                            c_.as_mut_ptr(),
+                            args.3,
+                        )
+                    }
+                };
+                let product = if side == CblasLeft {
+                    dense.times(&b)
+                } else {
+                    b.times(&dense)
+                };
+                let mut expected = c.clone();
+                for i in 0..m {
+                    for j in 0..n {
+                        expected.set(i, j, update(alpha, product.get(i, j), beta, c.get(i, j)));
+                    }
+                }
+                let context = format!("symm {} {:?} {:?} {:?}", hermitian, layout, side, uplo);
+                assert_close(&c_, &general(layout, &expected, ld), &context);
+            }
+        }
+    }
+}
+
+/// The transpositions allowed by syrk and syr2k, or by herk and her2k if
+/// `hermitian`, which coincide for the real types.
+fn rank_transposes<T: Blas>(hermitian: bool) -> Vec<CBLAS_TRANSPOSE> {
+    if !T::COMPLEX {
+        TRANSPOSES.to_vec()
+    } else if hermitian {
+        vec![CblasNoTrans, CblasConjTrans]
+    } else {
+        vec![CblasNoTrans, CblasTrans]
+    }
+}
+
+fn syrk<T: Blas>(hermitian: bool) {
+    let mut rng = Rng::new();
+    let (n, k) = (4, 3);
+    let second = if hermitian {
+        CblasConjTrans
+    } else {
+        CblasTrans
+    };
+    for &layout in &LAYOUTS {
+        for &uplo in &UPLOS {
+            for &trans in &rank_transposes::<T>(hermitian) {
+                let a = if trans == CblasNoTrans {
+                    Dense::<T>::random(&mut rng, n, k)
+                } else {
+                    Dense::<T>::random(&mut rng, k, n)
+                };
+                let lda = leading(layout, a.rows, a.cols);
+                let a_ = general(layout, &a, lda);
+                let original = Storage::Full.random::<T>(&mut rng, layout, uplo, n, false);
+                let c = Storage::Full.symmetric(&original, layout, uplo, n, hermitian);
+                let mut c_ = original.clone();
+                let ldc = int(Storage::Full.leading(n));
+                let (alpha, beta) = if hermitian {
+                    (T::new(rng.next(), 0.0), T::new(rng.next(), 0.0))
+                } else {
+                    (rng.value::<T>(), rng.value::<T>())
+                };
+                unsafe {
+                    if hermitian {
+                        T::herk(
+                            layout,
+                            uplo,
+                            trans,
+                            int(n),
+                            int(k),
+                            T::real(alpha.re()),
+                            a_.as_ptr(),
+                            int(lda),
+                            T::real(beta.re()),
+                            c_.as_mut_ptr(),
+                            ldc,
+                        )
+                    } else {
+                        T::syrk(
+                            layout,
+                            uplo,
+                            trans,
+                            int(n),
+                            int(k),
+                            alpha,
+                            a_.as_ptr(),
+                            int(lda),
+                            beta,
+                            c_.as_mut_ptr(),
+                            ldc,
+                        )
+                    }
+                };
+                let op = a.op(trans);
+                let product = op.times(&op.op(second));
+                let mut expected = c.clone();
+                for i in 0..n {
+                    for j in 0..n {
+                        expected.set(i, j, update(alpha, product.get(i, j), beta, c.get(i, j)));
+                    }
+                }
+                let expected = Storage::Full.store(&original, &expected, layout, uplo, hermitian);
+                let context = format!("syrk {} {:?} {:?} {:?}", hermitian, layout, uplo, trans);
+                assert_close(&c_, &expected, &context);
+            }
+        }
+    }
+}
+
+fn syr2k<T: Blas>(hermitian: bool) {
+    let mut rng = Rng::new();
+    let (n, k) = (4, 3);
+    let second = if hermitian {
+        CblasConjTrans
+    } else {
+        CblasTrans
+    };
+    for &layout in &LAYOUTS {
+        for &uplo in &UPLOS {
+            for &trans in &rank_transposes::<T>(hermitian) {
+                let (rows, cols) = if trans == CblasNoTrans {
+                    (n, k)
+                } else {
+                    (k, n)
+                };
+                let a = Dense::<T>::random(&mut rng, rows, cols);
+                let b = Dense::<T>::random(&mut rng, rows, cols);
+                let ld = leading(layout, rows, cols);
+                let (a_, b_) = (general(layout, &a, ld), general(layout, &b, ld));
+                let original = Storage::Full.random::<T>(&mut rng, layout, uplo, n, false);
+                let c = Storage::Full.symmetric(&original, layout, uplo, n, hermitian);
+                let mut c_ = original.clone();
+                let ldc = int(Storage::Full.leading(n));
+                let alpha = rng.value::<T>();
+                let beta = if hermitian {
+                    T::new(rng.next(), 0.0)
+                } else {
+                    rng.value::<T>()
+                };
+                unsafe {
+                    if hermitian {
+                        T::her2k(
+                            layout,
+                            uplo,
+                            trans,
+                            int(n),
+                            int(k),
+                            alpha,
+                            a_.as_ptr(),
+                            int(ld),
+                            b_.as_ptr(),
+                            int(ld),
+                            T::real(beta.re()),
+                            c_.as_mut_ptr(),
+                            ldc,
+                        )
+                    } else {
+                        T::syr2k(
+                            layout,
+                            uplo,
+                            trans,
+                            int(n),
+                            int(k),
+                            alpha,
+                            a_.as_ptr(),
+                            int(ld),
+                            b_.as_ptr(),
+                            int(ld),
+                            beta,
+                            c_.as_mut_ptr(),
+                            ldc,
+                        )
+                    }
+                };
+                let (op_a, op_b) = (a.op(trans), b.op(trans));
+                let ab = op_a.times(&op_b.op(second));
+                let ba = op_b.times(&op_a.op(second));
+                let alpha2 = if hermitian { conj(alpha) } else { alpha };
+                let mut expected = c.clone();
+                for i in 0..n {
+                    for j in 0..n {
+                        let sum = add(mul(alpha, ab.get(i, j)), mul(alpha2, ba.get(i, j)));
+                        expected.set(i, j, update(one(), sum, beta, c.get(i, j)));
+                    }
+                }
+                let expected = Storage::Full.store(&original, &expected, layout, uplo, hermitian);
+                let context = format!("syr2k {} {:?} {:?} {:?}", hermitian, layout, uplo, trans);
+                assert_close(&c_, &expected, &context);
+            }
+        }
+    }
+}
+
+fn trmm<T: Blas>(solve: bool) {
+    let mut rng = Rng::new();
+    let (m, n) = (3, 4);
+    for &layout in &LAYOUTS {
+        for &side in &SIDES {
+            for &uplo in &UPLOS {
+                for &transa in &TRANSPOSES {
+                    for &diag in &DIAGS {
+                        let order = if side == CblasLeft { m } else { n };
+                        let a = Storage::Full.random::<T>(&mut rng, layout, uplo, order, solve);
+                        let op = Storage::Full
+                            .triangular(&a, layout, uplo, diag, order)
+                            .op(transa);
+                        let b = Dense::<T>::random(&mut rng, m, n);
+                        let ldb = leading(layout, m, n);
+                        let mut b_ = general(layout, &b, ldb);
+                        let alpha = rng.value::<T>();
+                        let (lda, b_ptr) = (int(Storage::Full.leading(order)), b_.as_mut_ptr());
+                        unsafe {
+                            if solve {
+                                T::trsm(
+                                    layout,
+                                    side,
+                                    uplo,
+                                    transa,
+                                    diag,
+                                    int(m),
+                                    int(n),
+                                    alpha,
+                                    a.as_ptr(),
+                                    lda,
+                                    b_ptr,
+                                    int(ldb),
+                                )
+                            } else {
+                                T::trmm(
+                                    layout,
+                                    side,
+                                    uplo,
+                                    transa,
+                                    diag,
+                                    int(m),
+                                    int(n),
+                                    alpha,
+                                    a.as_ptr(),
+                                    lda,
+                                    b_ptr,
+                                    int(ldb),
+                                )
+                            }
+                        };
+                        let context = format!(
+                            "trmm {} {:?} {:?} {:?} {:?} {:?}",
+                            solve, layout, side, uplo, transa, diag
+                        );
+                        let result = unstore(layout, &b_, m, n, ldb);
+                        assert_close(&b_, &general(layout, &result, ldb), &context);
+                        // The solution is multiplied back, so that both check
+                        // that op(A) X = alpha B, or X op(A) = alpha B.
+                        let (x, rhs) = if solve { (&result, &b) } else { (&b, &result) };
+                        let product = if side == CblasLeft {
+                            op.times(x)
+                        } else {
+                            x.times(&op)
+                        };
+                        let (actual, expected) = if solve {
+                            (
+                                product.data,
+                                b.data.iter().map(|&b| mul(alpha, b)).collect::<Vec<T>>(),
+                            )
+                        } else {
+                            let product = product
+                                .data
+                                .iter()
+                                .map(|&p| mul(alpha, p))
+                                .collect::<Vec<T>>();
+                            (rhs.data.clone(), product)
+                        };
+                        assert_close(&actual, &expected, &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+macro_rules! tests {
+    ($($module:ident: $t:ty,)*) => {$(
+        mod $module {
+            #[test]
+            fn swap() {
+                super::swap::<$t>();
+            }
+
+            #[test]
+            fn copy() {
+                super::copy::<$t>();
+            }
+
+            #[test]
+            fn axpy() {
+                super::axpy::<$t>();
+            }
+
+            #[test]
+            fn scal() {
+                super::scal::<$t>();
+            }
+
+            #[test]
+            fn dot() {
+                super::dot::<$t>();
+            }
+
+            #[test]
+            fn norms() {
+                super::norms::<$t>();
+            }
+
+            #[test]
+            fn gemv() {
+                super::gemv::<$t>();
+            }
+
+            #[test]
+            fn gbmv() {
+                super::gbmv::<$t>();
+            }
+
+            #[test]
+            fn trmv() {
+                super::trmv::<$t>();
+            }
+
+            #[test]
+            fn trsv() {
+                super::trsv::<$t>();
+            }
+
+            #[test]
+            fn hemv() {
+                super::hemv::<$t>();
+            }
+
+            #[test]
+            fn ger() {
+                super::ger::<$t>();
+            }
+
+            #[test]
+            fn her() {
+                super::her::<$t>();
+            }
+
+            #[test]
+            fn her2() {
+                super::her2::<$t>();
+            }
+
+            #[test]
+            fn gemm() {
+                super::gemm::<$t>();
+            }
+
+            #[test]
+            fn symm() {
+                super::symm::<$t>(false);
+            }
+
+            #[test]
+            fn hemm() {
+                super::symm::<$t>(true);
+            }
+
+            #[test]
+            fn syrk() {
+                super::syrk::<$t>(false);
+            }
+
+            #[test]
+            fn herk() {
+                super::syrk::<$t>(true);
+            }
+
+            #[test]
+            fn syr2k() {
+                super::syr2k::<$t>(false);
+            }
+
+            #[test]
+            fn her2k() {
+                super::syr2k::<$t>(true);
+            }
+
+            #[test]
+            fn trmm() {
+                super::trmm::<$t>(false);
+            }
+
+            #[test]
+            fn trsm() {
+                super::trmm::<$t>(true);
+            }
+        }
+    )*};
+}
+
+tests! {
+    single: f32,
+    double: f64,
+    complex: ::cblas_sys::c_float_complex,
+    complex16: ::cblas_sys::c_double_complex,
+}
+
+#[test]
+fn rot() {
+    let mut rng = Rng::new();
+    for &(incx, incy) in &INCREMENTS {
+        let n = 5;
+        let (x, y) = (rng.vector::<f64>(n), rng.vector::<f64>(n));
+        let (c, s) = (0.6, 0.8);
+        let (mut xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+        unsafe {
+            cblas_drot(
+                int(n),
+                xs.as_mut_ptr(),
+                incx as blasint,
+                ys.as_mut_ptr(),
+                incy as blasint,
+                c,
+                s,
+            )
+        };
+        let expected_x = (0..n).map(|i| c * x[i] + s * y[i]).collect::<Vec<f64>>();
+        let expected_y = (0..n).map(|i| c * y[i] - s * x[i]).collect::<Vec<f64>>();
+        assert_close(&xs, &strided(&expected_x, incx), "rot x");
+        assert_close(&ys, &strided(&expected_y, incy), "rot y");
+    }
+}
+
+#[test]
+fn rotg() {
+    for &(a, b) in &[(3.0, 4.0), (-4.0, 3.0), (0.0, 2.0), (2.0, 0.0), (0.0, 0.0)] {
+        let (mut r, mut z, mut c, mut s) = (a, b, 0.0, 0.0);
+        unsafe { cblas_drotg(&mut r, &mut z, &mut c, &mut s) };
+        let context = format!("rotg {} {}", a, b);
+        assert_close(&[c * c + s * s], &[1.0], &context);
+        assert_close(&[c * a + s * b, c * b - s * a], &[r, 0.0], &context);
+        assert_close(&[r.abs()], &[f64::hypot(a, b)], &context);
+    }
+}
+
+#[test]
+fn rotm() {
+    let mut rng = Rng::new();
+    let h = [rng.next(), rng.next(), rng.next(), rng.next()];
+    let flags: [(f64, [f64; 4]); 4] = [
+        (-1.0, h),
+        (0.0, [1.0, h[1], h[2], 1.0]),
+        (1.0, [h[0], -1.0, 1.0, h[3]]),
+        (-2.0, [1.0, 0.0, 0.0, 1.0]),
+    ];
+    for &(flag, [h11, h21, h12, h22]) in &flags {
+        for &(incx, incy) in &INCREMENTS {
+            let n = 4;
+            let (x, y) = (rng.vector::<f64>(n), rng.vector::<f64>(n));
+            let param = [flag, h[0], h[1], h[2], h[3]];
+            let (mut xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+            unsafe {
+                cblas_drotm(
+                    int(n),
+                    xs.as_mut_ptr(),
+                    incx as blasint,
+                    ys.as_mut_ptr(),
+                    incy as blasint,
+                    param.as_ptr(),
+                )
+            };
+            let expected_x = (0..n)
+                .map(|i| h11 * x[i] + h12 * y[i])
+                .collect::<Vec<f64>>();
+            let expected_y = (0..n)
+                .map(|i| h21 * x[i] + h22 * y[i])
+                .collect::<Vec<f64>>();
+            let context = format!("rotm {} {} {}", flag, incx, incy);
+            assert_close(&xs, &strided(&expected_x, incx), &context);
+            assert_close(&ys, &strided(&expected_y, incy), &context);
+        }
+    }
+}
+
+#[test]
+fn rotmg() {
+    for &(d1, d2, x1, y1) in &[
+        (2.0, 3.0, 1.5, -0.5),
+        (1.0, 1.0, 0.1, 4.0),
+        (1.0, 0.5, 2.0, 0.0),
+    ] {
+        let (mut d1_, mut d2_, mut x1_) = (d1, d2, x1);
+        let mut param = [0.0; 5];
+        unsafe { cblas_drotmg(&mut d1_, &mut d2_, &mut x1_, y1, param.as_mut_ptr()) };
+        let flag = param[0];
+        let (h11, h21, h12, h22) = if flag == -1.0 {
+            (param[1], param[2], param[3], param[4])
+        } else if flag == 0.0 {
+            (1.0, param[2], param[3], 1.0)
+        } else if flag == 1.0 {
+            (param[1], -1.0, 1.0, param[4])
+        } else {
+            (1.0, 0.0, 0.0, 1.0)
+        };
+        // H zeroes the second component of (sqrt(d1) x1, sqrt(d2) y1) up to
+        // the scaling, and the new factors preserve the norm.
+        let context = format!("rotmg {} {} {} {}", d1, d2, x1, y1);
+        assert_close(&[h21 * x1 + h22 * y1], &[0.0], &context);
+        assert_close(&[h11 * x1 + h12 * y1], &[x1_], &context);
+        assert_close(&[d1_ * x1_ * x1_], &[d1 * x1 * x1 + d2 * y1 * y1], &context);
+    }
+}
+
+/// Empty operands, for which the reference implementation returns without
+/// touching the output, except for scaling C by beta in gemm if k = 0.
+fn zero_sizes<T: Blas>() {
+    let mut rng = Rng::new();
+    let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+    for &layout in &LAYOUTS {
+        for &(m, n) in &[(0, 3), (3, 0)] {
+            let a = [nan::<T>(); 4];
+            let x = [nan::<T>(); 4];
+            let y = rng.vector::<T>(4);
+            let mut y_ = y.clone();
+            unsafe {
+                T::gemv(
+                    layout,
+                    CblasNoTrans,
+                    int(m),
+                    int(n),
+                    alpha,
+                    a.as_ptr(),
+                    4,
+                    x.as_ptr(),
+                    1,
+                    beta,
+                    y_.as_mut_ptr(),
+                    1,
+                )
+            };
+            assert_close(&y_, &y, "gemv with an empty matrix");
+        }
+        let (m, n) = (2, 3);
+        let c = Dense::<T>::random(&mut rng, m, n);
+        let ldc = leading(layout, m, n);
+        let a = [nan::<T>(); 4];
+        for &(mm, nn, k) in &[(0, n, 2), (m, 0, 2), (m, n, 0)] {
+            let mut c_ = general(layout, &c, ldc);
+            unsafe {
+                T::gemm(
+                    layout,
+                    CblasNoTrans,
+                    CblasNoTrans,
+                    int(mm),
+                    int(nn),
+                    int(k),
+                    alpha,
+                    a.as_ptr(),
+                    4,
+                    a.as_ptr(),
+                    4,
+                    beta,
+                    c_.as_mut_ptr(),
+                    int(ldc),
+                )
+            };
+            let mut expected = c.clone();
+            if k == 0 {
+                for value in &mut expected.data {
+                    *value = mul(beta, *value);
+                }
+            }
+            let context = format!("gemm {:?} with sizes {} {} {}", layout, mm, nn, k);
+            assert_close(&c_, &general(layout, &expected, ldc), &context);
+        }
+        let mut x = rng.vector::<T>(2);
+        let expected = x.clone();
+        unsafe {
+            T::trsv(
+                layout,
+                CblasUpper,
+                CblasNoTrans,
+                CblasNonUnit,
+                0,
+                a.as_ptr(),
+                1,
+                x.as_mut_ptr(),
+                1,
+            )
+        };
+        assert_close(&x, &expected, "trsv with an empty matrix");
+    }
+    let x = [nan::<T>(); 2];
+    unsafe {
+        assert_eq!(T::nrm2(0, x.as_ptr(), 1), 0.0);
+        assert_eq!(T::asum(0, x.as_ptr(), 1), 0.0);
+        assert_eq!(T::iamax(0, x.as_ptr(), 1), 0);
+        assert_close(
+            &[T::dotc(0, x.as_ptr(), 1, x.as_ptr(), 1)],
+            &[zero()],
+            "dot",
+        );
+    }
+}
+
+/// Non-positive increments, for which the reductions return zero and scal
+/// does nothing, as in the reference implementation.
+fn nonpositive_increments<T: Blas>() {
+    let mut rng = Rng::new();
+    let x = rng.vector::<T>(4);
+    for &inc in &[0, -1] {
+        unsafe {
+            assert_eq!(T::nrm2(4, x.as_ptr(), inc), 0.0);
+            assert_eq!(T::asum(4, x.as_ptr(), inc), 0.0);
+            assert_eq!(T::iamax(4, x.as_ptr(), inc), 0);
+            let mut y = x.clone();
+            T::scal(4, rng.value(), y.as_mut_ptr(), inc);
+            assert_close(&y, &x, "scal");
+        }
+    }
+}
+
+/// A zero beta, for which the output is not read, so that NaN in it does not
+/// propagate, and a zero alpha, for which the input matrices are not read.
+fn zero_scalars<T: Blas>() {
+    let mut rng = Rng::new();
+    let (m, n, k) = (3, 4, 2);
+    for &layout in &LAYOUTS {
+        let a = Dense::<T>::random(&mut rng, m, n);
+        let lda = leading(layout, m, n);
+        let a_ = general(layout, &a, lda);
+        let x = rng.vector::<T>(n);
+        let alpha = rng.value::<T>();
+        let mut y = vec![nan::<T>(); m];
+        unsafe {
+            T::gemv(
+                layout,
+                CblasNoTrans,
+                int(m),
+                int(n),
+                alpha,
+                a_.as_ptr(),
+                int(lda),
+                x.as_ptr(),
+                1,
+                zero(),
+                y.as_mut_ptr(),
+                1,
+            )
+        };
+        let expected = a.times_vector(&x).into_iter().map(|v| mul(alpha, v));
+        assert_close(&y, &expected.collect::<Vec<T>>(), "gemv with a zero beta");
+
+        let nans = vec![nan::<T>(); lda * m];
+        let y = rng.vector::<T>(m);
+        let beta = rng.value::<T>();
+        let mut y_ = y.clone();
+        unsafe {
+            T::gemv(
+                layout,
+                CblasNoTrans,
+                int(m),
+                int(n),
+                zero(),
+                nans.as_ptr(),
+                int(lda),
+                nans.as_ptr(),
+                1,
+                beta,
+                y_.as_mut_ptr(),
+                1,
+            )
+        };
+        let expected = y.iter().map(|&y| mul(beta, y)).collect::<Vec<T>>();
+        assert_close(&y_, &expected, "gemv with a zero alpha");
+
+        let s = Storage::Full;
+        let c_ = vec![nan::<T>(); s.len(n)];
+        let b = Dense::<T>::random(&mut rng, n, k);
+        let ldb = leading(layout, n, k);
+        let b_ = general(layout, &b, ldb);
+        let ldc = int(s.leading(n));
+        for &hermitian in &[false, true] {
+            let mut actual = c_.clone();
+            unsafe {
+                if hermitian {
+                    T::herk(
+                        layout,
+                        CblasUpper,
+                        CblasNoTrans,
+                        int(n),
+                        int(k),
+                        T::real(1.0),
+                        b_.as_ptr(),
+                        int(ldb),
+                        T::real(0.0),
+                        actual.as_mut_ptr(),
+                        ldc,
+                    )
+                } else {
+                    T::syrk(
+                        layout,
+                        CblasUpper,
+                        CblasNoTrans,
+                        int(n),
+                        int(k),
+                        one(),
+                        b_.as_ptr(),
+                        int(ldb),
+                        zero(),
+                        actual.as_mut_ptr(),
+                        ldc,
+                    )
+                }
+            };
+            let second = if hermitian {
+                CblasConjTrans
+            } else {
+                CblasTrans
+            };
+            let product = b.times(&b.op(second));
+            let expected = s.store(&c_, &product, layout, CblasUpper, hermitian);
+            let context = format!("syrk {} {:?} with a zero beta", hermitian, layout);
+            assert_close(&actual, &expected, &context);
+        }
+
+        let b = Dense::<T>::random(&mut rng, k, n);
+        let ldb = leading(layout, k, n);
+        let b_ = general(layout, &b, ldb);
+        let ldc = leading(layout, m, n);
+        let a = Dense::<T>::random(&mut rng, m, k);
+        let lda = leading(layout, m, k);
+        let a_ = general(layout, &a, lda);
+        let expected = general(layout, &a.times(&b), ldc);
+        let mut c_ = vec![nan::<T>(); expected.len()];
+        unsafe {
+            T::gemm(
+                layout,
+                CblasNoTrans,
+                CblasNoTrans,
+                int(m),
+                int(n),
+                int(k),
+                one(),
+                a_.as_ptr(),
+                int(lda),
+                b_.as_ptr(),
+                int(ldb),
+                zero(),
+                c_.as_mut_ptr(),
+                int(ldc),
+            )
+        };
+        assert_close(&c_, &expected, "gemm with a zero beta");
+    }
+}
+
+macro_rules! edge_tests {
+    ($($module:ident: $t:ty,)*) => {$(
+        mod $module {
+            #[test]
+            fn zero_sizes() {
+                super::zero_sizes::<$t>();
+            }
+
+            #[test]
+            fn nonpositive_increments() {
+                super::nonpositive_increments::<$t>();
+            }
+
+            #[test]
+            fn zero_scalars() {
+                super::zero_scalars::<$t>();
+            }
+        }
+    )*};
+}
+
+edge_tests! {
+    single_edges: f32,
+    double_edges: f64,
+    complex_edges: ::cblas_sys::c_float_complex,
+    complex16_edges: ::cblas_sys::c_double_complex,
+}
+
+// The functions of the `extensions` module, which are computed by the
+// fallbacks since the test executable does not export the symbols of the
+// bundled implementations to the lookup.
+#[cfg(feature = "extensions")]
+mod fallbacks {
+    use cblas_sys::*;
+
+    use super::{assert_close, general, general_offset, int, leading, nan, strided, update};
+    use super::{one, zero, Blas, Dense, Rng, Storage};
+    use super::{pointer, value};
+    use super::{DIAGS, INCREMENTS, LAYOUTS, SIDES, TRANSPOSES, UPLOS};
+
+    trait Extensions: Blas {
+        unsafe fn axpby(
+            n: blasint,
+            alpha: Self,
+            x: *const Self,
+            incx: blasint,
+            beta: Self,
+            y: *mut Self,
+            incy: blasint,
+        );
+        unsafe fn omatcopy(
+            order: CBLAS_LAYOUT,
+            trans: CBLAS_TRANSPOSE,
+            rows: blasint,
+            cols: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            b: *mut Self,
+            ldb: blasint,
+        );
+        unsafe fn imatcopy(
+            order: CBLAS_LAYOUT,
+            trans: CBLAS_TRANSPOSE,
+            rows: blasint,
+            cols: blasint,
+            alpha: Self,
+            a: *mut Self,
+            lda: blasint,
+            ldb: blasint,
+        );
+        unsafe fn geadd(
+            order: CBLAS_LAYOUT,
+            rows: blasint,
+            cols: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            beta: Self,
+            c: *mut Self,
+            ldc: blasint,
+        );
+        unsafe fn iamin(n: blasint, x: *const Self, incx: blasint) -> usize;
+        unsafe fn gemmt(
+            layout: CBLAS_LAYOUT,
+            uplo: CBLAS_UPLO,
+            transa: CBLAS_TRANSPOSE,
+            transb: CBLAS_TRANSPOSE,
+            n: blasint,
+            k: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            b: *const Self,
+            ldb: blasint,
+            beta: Self,
+            c: *mut Self,
+            ldc: blasint,
+        );
+        unsafe fn axpy_batch(
+            n_array: *const blasint,
+            alpha_array: *const Self,
+            x_array: *const *const Self,
+            incx_array: *const blasint,
+            y_array: *const *mut Self,
+            incy_array: *const blasint,
+            group_count: blasint,
+            group_size: *const blasint,
+        );
+        unsafe fn axpy_batch_strided(
+            n: blasint,
+            alpha: Self,
+            x: *const Self,
+            incx: blasint,
+            stridex: blasint,
+            y: *mut Self,
+            incy: blasint,
+            stridey: blasint,
+            batch_size: blasint,
+        );
+        unsafe fn gemv_batch(
+            layout: CBLAS_LAYOUT,
+            trans_array: *const CBLAS_TRANSPOSE,
+            m_array: *const blasint,
+            n_array: *const blasint,
+            alpha_array: *const Self,
+            a_array: *const *const Self,
+            lda_array: *const blasint,
+            x_array: *const *const Self,
+            incx_array: *const blasint,
+            beta_array: *const Self,
+            y_array: *const *mut Self,
+            incy_array: *const blasint,
+            group_count: blasint,
+            group_size: *const blasint,
+        );
+        unsafe fn gemv_batch_strided(
+            layout: CBLAS_LAYOUT,
+            trans: CBLAS_TRANSPOSE,
+            m: blasint,
+            n: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            stridea: blasint,
+            x: *const Self,
+            incx: blasint,
+            stridex: blasint,
+            beta: Self,
+            y: *mut Self,
+            incy: blasint,
+            stridey: blasint,
+            batch_size: blasint,
+        );
+        unsafe fn gemm_batch(
+            layout: CBLAS_LAYOUT,
+            transa_array: *const CBLAS_TRANSPOSE,
+            transb_array: *const CBLAS_TRANSPOSE,
+            m_array: *const blasint,
+            n_array: *const blasint,
+            k_array: *const blasint,
+            alpha_array: *const Self,
+            a_array: *const *const Self,
+            lda_array: *const blasint,
+            b_array: *const *const Self,
+            ldb_array: *const blasint,
+            beta_array: *const Self,
+            c_array: *const *mut Self,
+            ldc_array: *const blasint,
+            group_count: blasint,
+            group_size: *const blasint,
+        );
+        unsafe fn gemm_batch_strided(
+            layout: CBLAS_LAYOUT,
+            transa: CBLAS_TRANSPOSE,
+            transb: CBLAS_TRANSPOSE,
+            m: blasint,
+            n: blasint,
+            k: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            stridea: blasint,
+            b: *const Self,
+            ldb: blasint,
+            strideb: blasint,
+            beta: Self,
+            c: *mut Self,
+            ldc: blasint,
+            stridec: blasint,
+            batch_size: blasint,
+        );
+        unsafe fn trsm_batch(
+            layout: CBLAS_LAYOUT,
+            side_array: *const CBLAS_SIDE,
+            uplo_array: *const CBLAS_UPLO,
+            transa_array: *const CBLAS_TRANSPOSE,
+            diag_array: *const CBLAS_DIAG,
+            m_array: *const blasint,
+            n_array: *const blasint,
+            alpha_array: *const Self,
+            a_array: *const *const Self,
+            lda_array: *const blasint,
+            b_array: *const *mut Self,
+            ldb_array: *const blasint,
+            group_count: blasint,
+            group_size: *const blasint,
+        );
+        unsafe fn trsm_batch_strided(
+            layout: CBLAS_LAYOUT,
+            side: CBLAS_SIDE,
+            uplo: CBLAS_UPLO,
+            transa: CBLAS_TRANSPOSE,
+            diag: CBLAS_DIAG,
+            m: blasint,
+            n: blasint,
+            alpha: Self,
+            a: *const Self,
+            lda: blasint,
+            stridea: blasint,
+            b: *mut Self,
+            ldb: blasint,
+            strideb: blasint,
+            batch_size: blasint,
+        );
+    }
+
+    macro_rules! extensions {
+        (
+            $t:ty, $arg:ident,
+            [$axpby:ident, $omatcopy:ident, $imatcopy:ident, $geadd:ident, $iamin:ident,
+             $gemmt:ident],
+            [$axpy_batch:ident, $axpy_batch_strided:ident, $gemv_batch:ident,
+             $gemv_batch_strided:ident, $gemm_batch:ident, $gemm_batch_strided:ident,
+             $trsm_batch:ident, $trsm_batch_strided:ident]
+        ) => {
+            impl Extensions for $t {
+                unsafe fn axpby(
+                    n: blasint,
+                    alpha: Self,
+                    x: *const Self,
+                    incx: blasint,
+                    beta: Self,
+                    y: *mut Self,
+                    incy: blasint,
+                ) {
+                    extensions::$axpby(n, $arg(&alpha), x, incx, $arg(&beta), y, incy)
+                }
+
+                unsafe fn omatcopy(
+                    order: CBLAS_LAYOUT,
+                    trans: CBLAS_TRANSPOSE,
+                    rows: blasint,
+                    cols: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    b: *mut Self,
+                    ldb: blasint,
+                ) {
+                    extensions::$omatcopy(order, trans, rows, cols, $arg(&alpha), a, lda, b, ldb)
+                }
+
+                unsafe fn imatcopy(
+                    order: CBLAS_LAYOUT,
+                    trans: CBLAS_TRANSPOSE,
+                    rows: blasint,
+                    cols: blasint,
+                    alpha: Self,
+                    a: *mut Self,
+                    lda: blasint,
+                    ldb: blasint,
+                ) {
+                    extensions::$imatcopy(order, trans, rows, cols, $arg(&alpha), a, lda, ldb)
+                }
+
+                unsafe fn geadd(
+                    order: CBLAS_LAYOUT,
+                    rows: blasint,
+                    cols: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    beta: Self,
+                    c: *mut Self,
+                    ldc: blasint,
+                ) {
+                    extensions::$geadd(order, rows, cols, $arg(&alpha), a, lda, $arg(&beta), c, ldc)
+                }
+
+                unsafe fn iamin(n: blasint, x: *const Self, incx: blasint) -> usize {
+                    extensions::$iamin(n, x, incx) as usize
+                }
+
+                unsafe fn gemmt(
+                    layout: CBLAS_LAYOUT,
+                    uplo: CBLAS_UPLO,
+                    transa: CBLAS_TRANSPOSE,
+                    transb: CBLAS_TRANSPOSE,
+                    n: blasint,
+                    k: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    b: *const Self,
+                    ldb: blasint,
+                    beta: Self,
+                    c: *mut Self,
+                    ldc: blasint,
+                ) {
+                    extensions::$gemmt(
+                        layout,
+                        uplo,
+                        transa,
+                        transb,
+                        n,
+                        k,
+                        $arg(&alpha),
+                        a,
+                        lda,
+                        b,
+                        ldb,
+                        $arg(&beta),
+                        c,
+                        ldc,
+                    )
+                }
+
+                unsafe fn axpy_batch(
+                    n_array: *const blasint,
+                    alpha_array: *const Self,
+                    x_array: *const *const Self,
+                    incx_array: *const blasint,
+                    y_array: *const *mut Self,
+                    incy_array: *const blasint,
+                    group_count: blasint,
+                    group_size: *const blasint,
+                ) {
+                    extensions::$axpy_batch(
+                        n_array,
+                        alpha_array,
+                        x_array,
+                        incx_array,
+                        y_array,
+                        incy_array,
+                        group_count,
+                        group_size,
+                    )
+                }
+
+                unsafe fn axpy_batch_strided(
+                    n: blasint,
+                    alpha: Self,
+                    x: *const Self,
+                    incx: blasint,
+                    stridex: blasint,
+                    y: *mut Self,
+                    incy: blasint,
+                    stridey: blasint,
+                    batch_size: blasint,
+                ) {
+                    extensions::$axpy_batch_strided(
+                        n,
+                        $arg(&alpha),
+                        x,
+                        incx,
+                        stridex,
+                        y,
+                        incy,
+                        stridey,
+                        batch_size,
+                    )
+                }
+
+                unsafe fn gemv_batch(
+                    layout: CBLAS_LAYOUT,
+                    trans_array: *const CBLAS_TRANSPOSE,
+                    m_array: *const blasint,
+                    n_array: *const blasint,
+                    alpha_array: *const Self,
+                    a_array: *const *const Self,
+                    lda_array: *const blasint,
+                    x_array: *const *const Self,
+                    incx_array: *const blasint,
+                    beta_array: *const Self,
+                    y_array: *const *mut Self,
+                    incy_array: *const blasint,
+                    group_count: blasint,
+                    group_size: *const blasint,
+                ) {
+                    extensions::$gemv_batch(
+                        layout,
+                        trans_array,
+                        m_array,
+                        n_array,
+                        alpha_array,
+                        a_array,
+                        lda_array,
+                        x_array,
+                        incx_array,
+                        beta_array,
+                        y_array,
+                        incy_array,
+                        group_count,
+                        group_size,
+                    )
+                }
+
+                unsafe fn gemv_batch_strided(
+                    layout: CBLAS_LAYOUT,
+                    trans: CBLAS_TRANSPOSE,
+                    m: blasint,
+                    n: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    stridea: blasint,
+                    x: *const Self,
+                    incx: blasint,
+                    stridex: blasint,
+                    beta: Self,
+                    y: *mut Self,
+                    incy: blasint,
+                    stridey: blasint,
+                    batch_size: blasint,
+                ) {
+                    extensions::$gemv_batch_strided(
+                        layout,
+                        trans,
+                        m,
+                        n,
+                        $arg(&alpha),
+                        a,
+                        lda,
+                        stridea,
+                        x,
+                        incx,
+                        stridex,
+                        $arg(&beta),
+                        y,
+                        incy,
+                        stridey,
+                        batch_size,
+                    )
+                }
+
+                unsafe fn gemm_batch(
+                    layout: CBLAS_LAYOUT,
+                    transa_array: *const CBLAS_TRANSPOSE,
+                    transb_array: *const CBLAS_TRANSPOSE,
+                    m_array: *const blasint,
+                    n_array: *const blasint,
+                    k_array: *const blasint,
+                    alpha_array: *const Self,
+                    a_array: *const *const Self,
+                    lda_array: *const blasint,
+                    b_array: *const *const Self,
+                    ldb_array: *const blasint,
+                    beta_array: *const Self,
+                    c_array: *const *mut Self,
+                    ldc_array: *const blasint,
+                    group_count: blasint,
+                    group_size: *const blasint,
+                ) {
+                    extensions::$gemm_batch(
+                        layout,
+                        transa_array,
+                        transb_array,
+                        m_array,
+                        n_array,
+                        k_array,
+                        alpha_array,
+                        a_array,
+                        lda_array,
+                        b_array,
+                        ldb_array,
+                        beta_array,
+                        c_array,
+                        ldc_array,
+                        group_count,
+                        group_size,
+                    )
+                }
+
+                unsafe fn gemm_batch_strided(
+                    layout: CBLAS_LAYOUT,
+                    transa: CBLAS_TRANSPOSE,
+                    transb: CBLAS_TRANSPOSE,
+                    m: blasint,
+                    n: blasint,
+                    k: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    stridea: blasint,
+                    b: *const Self,
+                    ldb: blasint,
+                    strideb: blasint,
+                    beta: Self,
+                    c: *mut Self,
+                    ldc: blasint,
+                    stridec: blasint,
+                    batch_size: blasint,
+                ) {
+                    extensions::$gemm_batch_strided(
+                        layout,
+                        transa,
+                        transb,
+                        m,
+                        n,
+                        k,
+                        $arg(&alpha),
+                        a,
+                        lda,
+                        stridea,
+                        b,
+                        ldb,
+                        strideb,
+                        $arg(&beta),
+                        c,
+                        ldc,
+                        stridec,
+                        batch_size,
+                    )
+                }
+
+                unsafe fn trsm_batch(
+                    layout: CBLAS_LAYOUT,
+                    side_array: *const CBLAS_SIDE,
+                    uplo_array: *const CBLAS_UPLO,
+                    transa_array: *const CBLAS_TRANSPOSE,
+                    diag_array: *const CBLAS_DIAG,
+                    m_array: *const blasint,
+                    n_array: *const blasint,
+                    alpha_array: *const Self,
+                    a_array: *const *const Self,
+                    lda_array: *const blasint,
+                    b_array: *const *mut Self,
+                    ldb_array: *const blasint,
+                    group_count: blasint,
+                    group_size: *const blasint,
+                ) {
+                    extensions::$trsm_batch(
+                        layout,
+                        side_array,
+                        uplo_array,
+                        transa_array,
+                        diag_array,
+                        m_array,
+                        n_array,
+                        alpha_array,
+                        a_array,
+                        lda_array,
+                        b_array,
+                        ldb_array,
+                        group_count,
+                        group_size,
+                    )
+                }
+
+                unsafe fn trsm_batch_strided(
+                    layout: CBLAS_LAYOUT,
+                    side: CBLAS_SIDE,
+                    uplo: CBLAS_UPLO,
+                    transa: CBLAS_TRANSPOSE,
+                    diag: CBLAS_DIAG,
+                    m: blasint,
+                    n: blasint,
+                    alpha: Self,
+                    a: *const Self,
+                    lda: blasint,
+                    stridea: blasint,
+                    b: *mut Self,
+                    ldb: blasint,
+                    strideb: blasint,
+                    batch_size: blasint,
+                ) {
+                    extensions::$trsm_batch_strided(
+                        layout,
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        $arg(&alpha),
+                        a,
+                        lda,
+                        stridea,
+                        b,
+                        ldb,
+                        strideb,
+                        batch_size,
+                    )
+                }
+            }
+        };
+    }
+
+    extensions!(
+        f32,
+        value,
+        [
+            cblas_saxpby,
+            cblas_somatcopy,
+            cblas_simatcopy,
+            cblas_sgeadd,
+            cblas_isamin,
+            cblas_sgemmt
+        ],
+        [
+            cblas_saxpy_batch,
+            cblas_saxpy_batch_strided,
+            cblas_sgemv_batch,
+            cblas_sgemv_batch_strided,
+            cblas_sgemm_batch,
+            cblas_sgemm_batch_strided,
+            cblas_strsm_batch,
+            cblas_strsm_batch_strided
+        ]
+    );
+    extensions!(
+        f64,
+        value,
+        [
+            cblas_daxpby,
+            cblas_domatcopy,
+            cblas_dimatcopy,
+            cblas_dgeadd,
+            cblas_idamin,
+            cblas_dgemmt
+        ],
+        [
+            cblas_daxpy_batch,
+            cblas_daxpy_batch_strided,
+            cblas_dgemv_batch,
+            cblas_dgemv_batch_strided,
+            cblas_dgemm_batch,
+            cblas_dgemm_batch_strided,
+            cblas_dtrsm_batch,
+            cblas_dtrsm_batch_strided
+        ]
+    );
+    extensions!(
+        c_float_complex,
+        pointer,
+        [
+            cblas_caxpby,
+            cblas_comatcopy,
+            cblas_cimatcopy,
+            cblas_cgeadd,
+            cblas_icamin,
+            cblas_cgemmt
+        ],
+        [
+            cblas_caxpy_batch,
+            cblas_caxpy_batch_strided,
+            cblas_cgemv_batch,
+            cblas_cgemv_batch_strided,
+            cblas_cgemm_batch,
+            cblas_cgemm_batch_strided,
+            cblas_ctrsm_batch,
+            cblas_ctrsm_batch_strided
+        ]
+    );
+    extensions!(
+        c_double_complex,
+        pointer,
+        [
+            cblas_zaxpby,
+            cblas_zomatcopy,
+            cblas_zimatcopy,
+            cblas_zgeadd,
+            cblas_izamin,
+            cblas_zgemmt
+        ],
+        [
+            cblas_zaxpy_batch,
+            cblas_zaxpy_batch_strided,
+            cblas_zgemv_batch,
+            cblas_zgemv_batch_strided,
+            cblas_zgemm_batch,
+            cblas_zgemm_batch_strided,
+            cblas_ztrsm_batch,
+            cblas_ztrsm_batch_strided
+        ]
+    );
+
+    fn axpby<T: Extensions>() {
+        let mut rng = Rng::new();
+        for &(incx, incy) in &INCREMENTS {
+            for &zero_beta in &[false, true] {
+                let n = 5;
+                let x = rng.vector::<T>(n);
+                // y is not read if beta is zero.
+                let y = if zero_beta {
+                    vec![nan::<T>(); n]
+                } else {
+                    rng.vector::<T>(n)
+                };
+                let alpha = rng.value::<T>();
+                let beta = if zero_beta { zero() } else { rng.value::<T>() };
+                let (xs, mut ys) = (strided(&x, incx), strided(&y, incy));
+                unsafe {
+                    T::axpby(
+                        int(n),
+                        alpha,
+                        xs.as_ptr(),
+                        incx as blasint,
+                        beta,
+                        ys.as_mut_ptr(),
+                        incy as blasint,
+                    )
+                };
+                let expected = (0..n)
+                    .map(|i| update(alpha, x[i], beta, y[i]))
+                    .collect::<Vec<T>>();
+                let context = format!("axpby {} {} {}", incx, incy, zero_beta);
+                assert_close(&ys, &strided(&expected, incy), &context);
+            }
+        }
+    }
+
+    /// The product of alpha and op(A).
+    fn scaled<T: Blas>(alpha: T, a: &Dense<T>, trans: CBLAS_TRANSPOSE) -> Dense<T> {
+        let mut result = a.op(trans);
+        for value in result.data.iter_mut() {
+            *value = super::mul(alpha, *value);
+        }
+        result
+    }
+
+    fn omatcopy<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (rows, cols) = (3, 5);
+        for &layout in &LAYOUTS {
+            for &trans in &TRANSPOSES {
+                let a = Dense::<T>::random(&mut rng, rows, cols);
+                let lda = leading(layout, rows, cols);
+                let a_ = general(layout, &a, lda);
+                let alpha = rng.value::<T>();
+                let expected = scaled(alpha, &a, trans);
+                let ldb = leading(layout, expected.rows, expected.cols) + 1;
+                let mut b_ = general(layout, &Dense::zeros(expected.rows, expected.cols), ldb);
+                for value in b_.iter_mut() {
+                    *value = nan();
+                }
+                unsafe {
+                    T::omatcopy(
+                        layout,
+                        trans,
+                        int(rows),
+                        int(cols),
+                        alpha,
+                        a_.as_ptr(),
+                        int(lda),
+                        b_.as_mut_ptr(),
+                        int(ldb),
+                    )
+                };
+                let context = format!("omatcopy {:?} {:?}", layout, trans);
+                assert_close(&b_, &general(layout, &expected, ldb), &context);
+            }
+        }
+    }
+
+    // The matrix is copied in place into a storage of a different shape and
+    // leading dimension, which is compared where B is stored.
+    fn imatcopy<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (rows, cols) = (3, 5);
+        for &layout in &LAYOUTS {
+            for &trans in &TRANSPOSES {
+                let a = Dense::<T>::random(&mut rng, rows, cols);
+                let alpha = rng.value::<T>();
+                let expected = scaled(alpha, &a, trans);
+                let lda = leading(layout, rows, cols);
+                let ldb = leading(layout, expected.rows, expected.cols);
+                let mut storage = general(layout, &a, lda);
+                let major = if layout == CblasColMajor {
+                    expected.cols
+                } else {
+                    expected.rows
+                };
+                storage.resize(storage.len().max(ldb * major), nan());
+                unsafe {
+                    T::imatcopy(
+                        layout,
+                        trans,
+                        int(rows),
+                        int(cols),
+                        alpha,
+                        storage.as_mut_ptr(),
+                        int(lda),
+                        int(ldb),
+                    )
+                };
+                let mut actual = Dense::zeros(expected.rows, expected.cols);
+                for i in 0..expected.rows {
+                    for j in 0..expected.cols {
+                        actual.set(i, j, storage[general_offset(layout, ldb, i, j)]);
+                    }
+                }
+                let context = format!("imatcopy {:?} {:?}", layout, trans);
+                assert_close(&actual.data, &expected.data, &context);
+            }
+        }
+    }
+
+    fn geadd<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (rows, cols) = (3, 4);
+        for &layout in &LAYOUTS {
+            for &zero_beta in &[false, true] {
+                let a = Dense::<T>::random(&mut rng, rows, cols);
+                let mut c = Dense::<T>::random(&mut rng, rows, cols);
+                if zero_beta {
+                    c.data.iter_mut().for_each(|value| *value = nan());
+                }
+                let (lda, ldc) = (leading(layout, rows, cols), leading(layout, rows, cols) + 2);
+                let a_ = general(layout, &a, lda);
+                let mut c_ = general(layout, &c, ldc);
+                let alpha = rng.value::<T>();
+                let beta = if zero_beta { zero() } else { rng.value::<T>() };
+                unsafe {
+                    T::geadd(
+                        layout,
+                        int(rows),
+                        int(cols),
+                        alpha,
+                        a_.as_ptr(),
+                        int(lda),
+                        beta,
+                        c_.as_mut_ptr(),
+                        int(ldc),
+                    )
+                };
+                let mut expected = c.clone();
+                for i in 0..rows {
+                    for j in 0..cols {
+                        expected.set(i, j, update(alpha, a.get(i, j), beta, c.get(i, j)));
+                    }
+                }
+                let context = format!("geadd {:?} {}", layout, zero_beta);
+                assert_close(&c_, &general(layout, &expected, ldc), &context);
+            }
+        }
+    }
+
+    fn iamin<T: Extensions>() {
+        let mut rng = Rng::new();
+        for &inc in &[1, 2] {
+            let n = 6;
+            let mut x = rng.vector::<T>(n);
+            // The first of equal magnitudes is taken.
+            x[1] = T::new(0.01, -0.02);
+            x[4] = x[1];
+            let xs = strided(&x, inc);
+            let iamin = unsafe { T::iamin(int(n), xs.as_ptr(), inc as blasint) };
+            let magnitudes = x
+                .iter()
+                .map(|x| x.re().abs() + x.im().abs())
+                .collect::<Vec<f64>>();
+            let mut expected = 0;
+            for (i, &magnitude) in magnitudes.iter().enumerate() {
+                if magnitude < magnitudes[expected] {
+                    expected = i;
+                }
+            }
+            assert_eq!(iamin, expected, "iamin {}", inc);
+        }
+    }
+
+    // Only the triangle selected by uplo is updated, the other one keeping
+    // the values of C.
+    fn gemmt<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (n, k) = (4, 3);
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &transa in &TRANSPOSES {
+                    for &transb in &TRANSPOSES {
+                        let a = Dense::<T>::random(&mut rng, n, k).op(transa);
+                        let b = Dense::<T>::random(&mut rng, k, n).op(transb);
+                        let c = Dense::<T>::random(&mut rng, n, n);
+                        let (lda, ldb, ldc) = (
+                            leading(layout, a.rows, a.cols),
+                            leading(layout, b.rows, b.cols),
+                            leading(layout, n, n),
+                        );
+                        let (a_, b_) = (general(layout, &a, lda), general(layout, &b, ldb));
+                        let mut c_ = general(layout, &c, ldc);
+                        let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                        unsafe {
+                            T::gemmt(
+                                layout,
+                                uplo,
+                                transa,
+                                transb,
+                                int(n),
+                                int(k),
+                                alpha,
+                                a_.as_ptr(),
+                                int(lda),
+                                b_.as_ptr(),
+                                int(ldb),
+                                beta,
+                                c_.as_mut_ptr(),
+                                int(ldc),
+                            )
+                        };
+                        let product = a.op(transa).times(&b.op(transb));
+                        let mut expected = c.clone();
+                        for i in 0..n {
+                            for j in 0..n {
+                                let stored = if uplo == CblasUpper { i <= j } else { i >= j };
+                                if stored {
+                                    let value = update(alpha, product.get(i, j), beta, c.get(i, j));
+                                    expected.set(i, j, value);
+                                }
+                            }
+                        }
+                        let context =
+                            format!("gemmt {:?} {:?} {:?} {:?}", layout, uplo, transa, transb);
+                        assert_close(&c_, &general(layout, &expected, ldc), &context);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The shape of the storage of a matrix whose op(A) is rows-by-cols.
+    fn stored(trans: CBLAS_TRANSPOSE, rows: usize, cols: usize) -> (usize, usize) {
+        if trans == CblasNoTrans {
+            (rows, cols)
+        } else {
+            (cols, rows)
+        }
+    }
+
+    /// C := alpha * op(A) * op(B) + beta * C, where C is not read if beta is
+    /// zero.
+    fn multiplied<T: Blas>(
+        alpha: T,
+        a: &Dense<T>,
+        transa: CBLAS_TRANSPOSE,
+        b: &Dense<T>,
+        transb: CBLAS_TRANSPOSE,
+        beta: T,
+        c: &Dense<T>,
+    ) -> Dense<T> {
+        let product = a.op(transa).times(&b.op(transb));
+        let mut result = c.clone();
+        for i in 0..c.rows {
+            for j in 0..c.cols {
+                result.set(i, j, update(alpha, product.get(i, j), beta, c.get(i, j)));
+            }
+        }
+        result
+    }
+
+    // The groups of a batch of matrix multiplications, i.e., the transposes,
+    // the dimensions m, n, and k, and the number of problems. The first group
+    // is a run of problems with equal parameters, and consecutive groups
+    // differ in every one of them.
+    const GEMM_GROUPS: [(CBLAS_TRANSPOSE, CBLAS_TRANSPOSE, usize, usize, usize, usize); 3] = [
+        (CblasNoTrans, CblasNoTrans, 3, 4, 2, 3),
+        (CblasTrans, CblasConjTrans, 2, 3, 4, 1),
+        (CblasConjTrans, CblasTrans, 4, 2, 3, 2),
+    ];
+
+    // Every group has its own scalars and leading dimensions, the last one a
+    // zero beta with a C of NaN, which must not be read. Every group has the
+    // given multiple of its number of problems, so that a large batch is
+    // computed in parallel with the `rayon` feature.
+    fn gemm_batch<T: Extensions>(repeat: usize) {
+        let mut rng = Rng::new();
+        for &layout in &LAYOUTS {
+            let (mut transa, mut transb) = (Vec::new(), Vec::new());
+            let (mut m, mut n, mut k) = (Vec::new(), Vec::new(), Vec::new());
+            let (mut alpha, mut beta) = (Vec::new(), Vec::new());
+            let (mut lda, mut ldb, mut ldc) = (Vec::new(), Vec::new(), Vec::new());
+            let mut group_size = Vec::new();
+            let (mut a_, mut b_, mut c_) = (Vec::new(), Vec::new(), Vec::new());
+            let mut expected = Vec::new();
+            for (g, &(ta, tb, rows, cols, depth, size)) in GEMM_GROUPS.iter().enumerate() {
+                let last = g + 1 == GEMM_GROUPS.len();
+                let (ga, gb) = (
+                    rng.value::<T>(),
+                    if last { zero() } else { rng.value::<T>() },
+                );
+                let (arows, acols) = stored(ta, rows, depth);
+                let (brows, bcols) = stored(tb, depth, cols);
+                let (la, lb, lc) = (
+                    leading(layout, arows, acols) + g,
+                    leading(layout, brows, bcols) + g,
+                    leading(layout, rows, cols) + g,
+                );
+                transa.push(ta);
+                transb.push(tb);
+                m.push(int(rows));
+                n.push(int(cols));
+                k.push(int(depth));
+                alpha.push(ga);
+                beta.push(gb);
+                lda.push(int(la));
+                ldb.push(int(lb));
+                ldc.push(int(lc));
+                group_size.push(int(size * repeat));
+                for _ in 0..size * repeat {
+                    let a = Dense::<T>::random(&mut rng, arows, acols);
+                    let b = Dense::<T>::random(&mut rng, brows, bcols);
+                    let mut c = Dense::<T>::random(&mut rng, rows, cols);
+                    if last {
+                        c.data.iter_mut().for_each(|value| *value = nan());
+                    }
+                    let result = multiplied(ga, &a, ta, &b, tb, gb, &c);
+                    a_.push(general(layout, &a, la));
+                    b_.push(general(layout, &b, lb));
+                    c_.push(general(layout, &c, lc));
+                    expected.push(general(layout, &result, lc));
+                }
+            }
+            let a_array = a_.iter().map(|a| a.as_ptr()).collect::<Vec<_>>();
+            let b_array = b_.iter().map(|b| b.as_ptr()).collect::<Vec<_>>();
+            let c_array = c_.iter_mut().map(|c| c.as_mut_ptr()).collect::<Vec<_>>();
+            unsafe {
+                T::gemm_batch(
+                    layout,
+                    transa.as_ptr(),
+                    transb.as_ptr(),
+                    m.as_ptr(),
+                    n.as_ptr(),
+                    k.as_ptr(),
+                    alpha.as_ptr(),
+                    a_array.as_ptr(),
+                    lda.as_ptr(),
+                    b_array.as_ptr(),
+                    ldb.as_ptr(),
+                    beta.as_ptr(),
+                    c_array.as_ptr(),
+                    ldc.as_ptr(),
+                    int(group_size.len()),
+                    group_size.as_ptr(),
+                )
+            };
+            for (i, (c, expected)) in c_.iter().zip(&expected).enumerate() {
+                let context = format!("gemm_batch {:?} {} {}", layout, repeat, i);
+                assert_close(c, expected, &context);
+            }
+        }
+    }
+
+    /// The storages of a batch of operands at the given stride, whose gaps are
+    /// NaN.
+    fn batched<T: Blas>(storages: &[Vec<T>], stride: usize) -> Vec<T> {
+        let mut batch = vec![nan(); stride * storages.len()];
+        for (i, storage) in storages.iter().enumerate() {
+            batch[i * stride..i * stride + storage.len()].copy_from_slice(storage);
+        }
+        batch
+    }
+
+    // Every problem has its own A and C, which are stored at strides larger
+    // than their storages, while B is shared via a zero stride.
+    fn gemm_batch_strided<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (m, n, k, batch_size) = (3, 4, 2, 3);
+        for &layout in &LAYOUTS {
+            for &transa in &TRANSPOSES {
+                for &transb in &TRANSPOSES {
+                    let (arows, acols) = stored(transa, m, k);
+                    let (brows, bcols) = stored(transb, k, n);
+                    let (lda, ldb, ldc) = (
+                        leading(layout, arows, acols),
+                        leading(layout, brows, bcols),
+                        leading(layout, m, n),
+                    );
+                    let b = Dense::<T>::random(&mut rng, brows, bcols);
+                    let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                    let (mut a_, mut c_, mut expected) = (Vec::new(), Vec::new(), Vec::new());
+                    for _ in 0..batch_size {
+                        let a = Dense::<T>::random(&mut rng, arows, acols);
+                        let c = Dense::<T>::random(&mut rng, m, n);
+                        let result = multiplied(alpha, &a, transa, &b, transb, beta, &c);
+                        a_.push(general(layout, &a, lda));
+                        c_.push(general(layout, &c, ldc));
+                        expected.push(general(layout, &result, ldc));
+                    }
+                    let (stridea, stridec) = (a_[0].len() + 2, c_[0].len() + 1);
+                    let (a_, b_) = (batched(&a_, stridea), general(layout, &b, ldb));
+                    let mut c_ = batched(&c_, stridec);
+                    unsafe {
+                        T::gemm_batch_strided(
+                            layout,
+                            transa,
+                            transb,
+                            int(m),
+                            int(n),
+                            int(k),
+                            alpha,
+                            a_.as_ptr(),
+                            int(lda),
+                            int(stridea),
+                            b_.as_ptr(),
+                            int(ldb),
+                            0,
+                            beta,
+                            c_.as_mut_ptr(),
+                            int(ldc),
+                            int(stridec),
+                            int(batch_size),
+                        )
+                    };
+                    let context =
+                        format!("gemm_batch_strided {:?} {:?} {:?}", layout, transa, transb);
+                    assert_close(&c_, &batched(&expected, stridec), &context);
+                }
+            }
+        }
+    }
+
+    // The groups of a batch of vector additions, i.e., n, the increments, and
+    // the number of problems.
+    const AXPY_GROUPS: [(usize, isize, isize, usize); 3] =
+        [(5, 1, 1, 3), (4, 2, -1, 1), (3, -3, 2, 2)];
+
+    // Every group has its own alpha, and the given multiple of its number of
+    // problems, as for `gemm_batch`.
+    fn axpy_batch<T: Extensions>(repeat: usize) {
+        let mut rng = Rng::new();
+        let (mut n, mut alpha, mut incx, mut incy) =
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        let mut group_size = Vec::new();
+        let (mut x_, mut y_, mut expected) = (Vec::new(), Vec::new(), Vec::new());
+        for &(len, gx, gy, size) in &AXPY_GROUPS {
+            let ga = rng.value::<T>();
+            n.push(int(len));
+            alpha.push(ga);
+            incx.push(gx as blasint);
+            incy.push(gy as blasint);
+            group_size.push(int(size * repeat));
+            for _ in 0..size * repeat {
+                let (x, y) = (rng.vector::<T>(len), rng.vector::<T>(len));
+                let result = (0..len)
+                    .map(|i| update(ga, x[i], one(), y[i]))
+                    .collect::<Vec<T>>();
+                x_.push(strided(&x, gx));
+                y_.push(strided(&y, gy));
+                expected.push(strided(&result, gy));
+            }
+        }
+        let x_array = x_.iter().map(|x| x.as_ptr()).collect::<Vec<_>>();
+        let y_array = y_.iter_mut().map(|y| y.as_mut_ptr()).collect::<Vec<_>>();
+        unsafe {
+            T::axpy_batch(
+                n.as_ptr(),
+                alpha.as_ptr(),
+                x_array.as_ptr(),
+                incx.as_ptr(),
+                y_array.as_ptr(),
+                incy.as_ptr(),
+                int(group_size.len()),
+                group_size.as_ptr(),
+            )
+        };
+        for (i, (y, expected)) in y_.iter().zip(&expected).enumerate() {
+            assert_close(y, expected, &format!("axpy_batch {} {}", repeat, i));
+        }
+    }
+
+    // x and y of every problem are stored at strides larger than their
+    // storages.
+    fn axpy_batch_strided<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (n, batch_size) = (4, 3);
+        for &(incx, incy) in &INCREMENTS {
+            let alpha = rng.value::<T>();
+            let (mut x_, mut y_, mut expected) = (Vec::new(), Vec::new(), Vec::new());
+            for _ in 0..batch_size {
+                let (x, y) = (rng.vector::<T>(n), rng.vector::<T>(n));
+                let result = (0..n)
+                    .map(|i| update(alpha, x[i], one(), y[i]))
+                    .collect::<Vec<T>>();
+                x_.push(strided(&x, incx));
+                y_.push(strided(&y, incy));
+                expected.push(strided(&result, incy));
+            }
+            let (stridex, stridey) = (x_[0].len() + 1, y_[0].len() + 2);
+            let x_ = batched(&x_, stridex);
+            let mut y_ = batched(&y_, stridey);
+            unsafe {
+                T::axpy_batch_strided(
+                    int(n),
+                    alpha,
+                    x_.as_ptr(),
+                    incx as blasint,
+                    int(stridex),
+                    y_.as_mut_ptr(),
+                    incy as blasint,
+                    int(stridey),
+                    int(batch_size),
+                )
+            };
+            let context = format!("axpy_batch_strided {} {}", incx, incy);
+            assert_close(&y_, &batched(&expected, stridey), &context);
+        }
+    }
+
+    // The groups of a batch of matrix-vector multiplications, i.e., the
+    // transpose, the dimensions m and n, the increments, and the number of
+    // problems.
+    const GEMV_GROUPS: [(CBLAS_TRANSPOSE, usize, usize, isize, isize, usize); 3] = [
+        (CblasNoTrans, 3, 4, 1, 1, 3),
+        (CblasTrans, 2, 3, 2, -1, 1),
+        (CblasConjTrans, 4, 2, -3, 2, 2),
+    ];
+
+    /// y := alpha * op(A) * x + beta * y, where y is not read if beta is zero.
+    fn multiplied_vector<T: Blas>(
+        alpha: T,
+        a: &Dense<T>,
+        trans: CBLAS_TRANSPOSE,
+        x: &[T],
+        beta: T,
+        y: &[T],
+    ) -> Vec<T> {
+        let ax = a.op(trans).times_vector(x);
+        (0..y.len())
+            .map(|i| update(alpha, ax[i], beta, y[i]))
+            .collect()
+    }
+
+    // As for `gemm_batch`, the last group has a zero beta and a y of NaN.
+    fn gemv_batch<T: Extensions>(repeat: usize) {
+        let mut rng = Rng::new();
+        for &layout in &LAYOUTS {
+            let (mut trans, mut m, mut n) = (Vec::new(), Vec::new(), Vec::new());
+            let (mut alpha, mut beta) = (Vec::new(), Vec::new());
+            let (mut lda, mut incx, mut incy) = (Vec::new(), Vec::new(), Vec::new());
+            let mut group_size = Vec::new();
+            let (mut a_, mut x_, mut y_) = (Vec::new(), Vec::new(), Vec::new());
+            let mut expected = Vec::new();
+            for (g, &(t, rows, cols, gx, gy, size)) in GEMV_GROUPS.iter().enumerate() {
+                let last = g + 1 == GEMV_GROUPS.len();
+                let (ga, gb) = (
+                    rng.value::<T>(),
+                    if last { zero() } else { rng.value::<T>() },
+                );
+                let la = leading(layout, rows, cols) + g;
+                let (lenx, leny) = stored(t, cols, rows);
+                trans.push(t);
+                m.push(int(rows));
+                n.push(int(cols));
+                alpha.push(ga);
+                beta.push(gb);
+                lda.push(int(la));
+                incx.push(gx as blasint);
+                incy.push(gy as blasint);
+                group_size.push(int(size * repeat));
+                for _ in 0..size * repeat {
+                    let a = Dense::<T>::random(&mut rng, rows, cols);
+                    let x = rng.vector::<T>(lenx);
+                    let y = if last {
+                        vec![nan::<T>(); leny]
+                    } else {
+                        rng.vector::<T>(leny)
+                    };
+                    let result = multiplied_vector(ga, &a, t, &x, gb, &y);
+                    a_.push(general(layout, &a, la));
+                    x_.push(strided(&x, gx));
+                    y_.push(strided(&y, gy));
+                    expected.push(strided(&result, gy));
+                }
+            }
+            let a_array = a_.iter().map(|a| a.as_ptr()).collect::<Vec<_>>();
+            let x_array = x_.iter().map(|x| x.as_ptr()).collect::<Vec<_>>();
+            let y_array = y_.iter_mut().map(|y| y.as_mut_ptr()).collect::<Vec<_>>();
+            unsafe {
+                T::gemv_batch(
+                    layout,
+                    trans.as_ptr(),
+                    m.as_ptr(),
+                    n.as_ptr(),
+                    alpha.as_ptr(),
+                    a_array.as_ptr(),
+                    lda.as_ptr(),
+                    x_array.as_ptr(),
+                    incx.as_ptr(),
+                    beta.as_ptr(),
+                    y_array.as_ptr(),
+                    incy.as_ptr(),
+                    int(group_size.len()),
+                    group_size.as_ptr(),
+                )
+            };
+            for (i, (y, expected)) in y_.iter().zip(&expected).enumerate() {
+                let context = format!("gemv_batch {:?} {} {}", layout, repeat, i);
+                assert_close(y, expected, &context);
+            }
+        }
+    }
+
+    // Every problem has its own A and y, which are stored at strides larger
+    // than their storages, while x is shared via a zero stride.
+    fn gemv_batch_strided<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (m, n, batch_size) = (3, 4, 3);
+        let (incx, incy) = (2, -1);
+        for &layout in &LAYOUTS {
+            for &trans in &TRANSPOSES {
+                let (lenx, leny) = stored(trans, n, m);
+                let lda = leading(layout, m, n);
+                let x = rng.vector::<T>(lenx);
+                let (alpha, beta) = (rng.value::<T>(), rng.value::<T>());
+                let (mut a_, mut y_, mut expected) = (Vec::new(), Vec::new(), Vec::new());
+                for _ in 0..batch_size {
+                    let a = Dense::<T>::random(&mut rng, m, n);
+                    let y = rng.vector::<T>(leny);
+                    let result = multiplied_vector(alpha, &a, trans, &x, beta, &y);
+                    a_.push(general(layout, &a, lda));
+                    y_.push(strided(&y, incy));
+                    expected.push(strided(&result, incy));
+                }
+                let (stridea, stridey) = (a_[0].len() + 2, y_[0].len() + 1);
+                let (a_, x_) = (batched(&a_, stridea), strided(&x, incx));
+                let mut y_ = batched(&y_, stridey);
+                unsafe {
+                    T::gemv_batch_strided(
+                        layout,
+                        trans,
+                        int(m),
+                        int(n),
+                        alpha,
+                        a_.as_ptr(),
+                        int(lda),
+                        int(stridea),
+                        x_.as_ptr(),
+                        incx as blasint,
+                        0,
+                        beta,
+                        y_.as_mut_ptr(),
+                        incy as blasint,
+                        int(stridey),
+                        int(batch_size),
+                    )
+                };
+                let context = format!("gemv_batch_strided {:?} {:?}", layout, trans);
+                assert_close(&y_, &batched(&expected, stridey), &context);
+            }
+        }
+    }
+
+    // The side, the triangle, the transpose, and the diagonal of a triangular
+    // matrix.
+    type Triangular = (CBLAS_SIDE, CBLAS_UPLO, CBLAS_TRANSPOSE, CBLAS_DIAG);
+
+    /// The storage of a random triangular A, whose diagonal is dominant, and
+    /// the right-hand side B := op(A) * X, or X * op(A) depending on the side,
+    /// for a random X, which alpha * X solves for the given alpha.
+    fn system<T: Blas>(
+        rng: &mut Rng,
+        layout: CBLAS_LAYOUT,
+        (side, uplo, transa, diag): Triangular,
+        m: usize,
+        n: usize,
+        alpha: T,
+    ) -> (Vec<T>, Dense<T>, Dense<T>) {
+        let order = if side == CblasLeft { m } else { n };
+        let a = Storage::Full.random::<T>(rng, layout, uplo, order, true);
+        let op = Storage::Full
+            .triangular(&a, layout, uplo, diag, order)
+            .op(transa);
+        let x = Dense::<T>::random(rng, m, n);
+        let b = if side == CblasLeft {
+            op.times(&x)
+        } else {
+            x.times(&op)
+        };
+        let mut solution = x;
+        for value in solution.data.iter_mut() {
+            *value = super::mul(alpha, *value);
+        }
+        (a, b, solution)
+    }
+
+    // The groups of a batch of triangular solves, i.e., the options, the
+    // dimensions m and n, and the number of problems.
+    const TRSM_GROUPS: [(Triangular, usize, usize, usize); 3] = [
+        ((CblasLeft, CblasUpper, CblasNoTrans, CblasNonUnit), 3, 2, 3),
+        ((CblasRight, CblasLower, CblasTrans, CblasUnit), 2, 3, 1),
+        ((CblasLeft, CblasLower, CblasConjTrans, CblasUnit), 2, 2, 2),
+    ];
+
+    // Every group has its own alpha and leading dimension of B.
+    fn trsm_batch<T: Extensions>(repeat: usize) {
+        let mut rng = Rng::new();
+        for &layout in &LAYOUTS {
+            let (mut side, mut uplo, mut transa, mut diag) =
+                (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+            let (mut m, mut n, mut alpha) = (Vec::new(), Vec::new(), Vec::new());
+            let (mut lda, mut ldb, mut group_size) = (Vec::new(), Vec::new(), Vec::new());
+            let (mut a_, mut b_, mut expected) = (Vec::new(), Vec::new(), Vec::new());
+            for (g, &(options, rows, cols, size)) in TRSM_GROUPS.iter().enumerate() {
+                let ga = rng.value::<T>();
+                let order = if options.0 == CblasLeft { rows } else { cols };
+                let lb = leading(layout, rows, cols) + g;
+                side.push(options.0);
+                uplo.push(options.1);
+                transa.push(options.2);
+                diag.push(options.3);
+                m.push(int(rows));
+                n.push(int(cols));
+                alpha.push(ga);
+                lda.push(int(Storage::Full.leading(order)));
+                ldb.push(int(lb));
+                group_size.push(int(size * repeat));
+                for _ in 0..size * repeat {
+                    let (a, b, solution) = system(&mut rng, layout, options, rows, cols, ga);
+                    a_.push(a);
+                    b_.push(general(layout, &b, lb));
+                    expected.push(general(layout, &solution, lb));
+                }
+            }
+            let a_array = a_.iter().map(|a| a.as_ptr()).collect::<Vec<_>>();
+            let b_array = b_.iter_mut().map(|b| b.as_mut_ptr()).collect::<Vec<_>>();
+            unsafe {
+                T::trsm_batch(
+                    layout,
+                    side.as_ptr(),
+                    uplo.as_ptr(),
+                    transa.as_ptr(),
+                    diag.as_ptr(),
+                    m.as_ptr(),
+                    n.as_ptr(),
+                    alpha.as_ptr(),
+                    a_array.as_ptr(),
+                    lda.as_ptr(),
+                    b_array.as_ptr(),
+                    ldb.as_ptr(),
+                    int(group_size.len()),
+                    group_size.as_ptr(),
+                )
+            };
+            for (i, (b, expected)) in b_.iter().zip(&expected).enumerate() {
+                let context = format!("trsm_batch {:?} {} {}", layout, repeat, i);
+                assert_close(b, expected, &context);
+            }
+        }
+    }
+
+    // A and B of every problem are stored at strides larger than their
+    // storages.
+    fn trsm_batch_strided<T: Extensions>() {
+        let mut rng = Rng::new();
+        let (m, n, batch_size) = (3, 2, 3);
+        for &layout in &LAYOUTS {
+            for &side in &SIDES {
+                for &uplo in &UPLOS {
+                    for &transa in &TRANSPOSES {
+                        for &diag in &DIAGS {
+                            let options = (side, uplo, transa, diag);
+                            let order = if side == CblasLeft { m } else { n };
+                            let (lda, ldb) = (Storage::Full.leading(order), leading(layout, m, n));
+                            let alpha = rng.value::<T>();
+                            let (mut a_, mut b_, mut expected) =
+                                (Vec::new(), Vec::new(), Vec::new());
+                            for _ in 0..batch_size {
+                                let (a, b, solution) =
+                                    system(&mut rng, layout, options, m, n, alpha);
+                                a_.push(a);
+                                b_.push(general(layout, &b, ldb));
+                                expected.push(general(layout, &solution, ldb));
+                            }
+                            let (stridea, strideb) = (a_[0].len() + 2, b_[0].len() + 1);
+                            let a_ = batched(&a_, stridea);
+                            let mut b_ = batched(&b_, strideb);
+                            unsafe {
+                                T::trsm_batch_strided(
+                                    layout,
+                                    side,
+                                    uplo,
+                                    transa,
+                                    diag,
+                                    int(m),
+                                    int(n),
+                                    alpha,
+                                    a_.as_ptr(),
+                                    int(lda),
+                                    int(stridea),
+                                    b_.as_mut_ptr(),
+                                    int(ldb),
+                                    int(strideb),
+                                    int(batch_size),
+                                )
+                            };
+                            let context = format!(
+                                "trsm_batch_strided {:?} {:?} {:?} {:?} {:?}",
+                                layout, side, uplo, transa, diag
+                            );
+                            assert_close(&b_, &batched(&expected, strideb), &context);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    macro_rules! tests {
+        ($($module:ident: $t:ty,)*) => {$(
+            mod $module {
+                #[test]
+                fn axpby() {
+                    super::axpby::<$t>();
+                }
+
+                #[test]
+                fn omatcopy() {
+                    super::omatcopy::<$t>();
+                }
+
+                #[test]
+                fn imatcopy() {
+                    super::imatcopy::<$t>();
+                }
+
+                #[test]
+                fn geadd() {
+                    super::geadd::<$t>();
+                }
+
+                #[test]
+                fn iamin() {
+                    super::iamin::<$t>();
+                }
+
+                #[test]
+                fn gemmt() {
+                    super::gemmt::<$t>();
+                }
+
+                #[test]
+                fn axpy_batch() {
+                    super::axpy_batch::<$t>(1);
+                }
+
+                #[test]
+                fn axpy_batch_parallel() {
+                    super::axpy_batch::<$t>(50);
+                }
+
+                #[test]
+                fn axpy_batch_strided() {
+                    super::axpy_batch_strided::<$t>();
+                }
+
+                #[test]
+                fn gemv_batch() {
+                    super::gemv_batch::<$t>(1);
+                }
+
+                #[test]
+                fn gemv_batch_parallel() {
+                    super::gemv_batch::<$t>(50);
+                }
+
+                #[test]
+                fn gemv_batch_strided() {
+                    super::gemv_batch_strided::<$t>();
+                }
+
+                #[test]
+                fn gemm_batch() {
+                    super::gemm_batch::<$t>(1);
+                }
+
+                #[test]
+                fn gemm_batch_parallel() {
+                    super::gemm_batch::<$t>(50);
+                }
+
+                #[test]
+                fn gemm_batch_strided() {
+                    super::gemm_batch_strided::<$t>();
+                }
+
+                #[test]
+                fn trsm_batch() {
+                    super::trsm_batch::<$t>(1);
+                }
+
+                #[test]
+                fn trsm_batch_parallel() {
+                    super::trsm_batch::<$t>(50);
+                }
+
+                #[test]
+                fn trsm_batch_strided() {
+                    super::trsm_batch_strided::<$t>();
+                }
+            }
+        )*};
+    }
+
+    tests! {
+        single: f32,
+        double: f64,
+        complex: ::cblas_sys::c_float_complex,
+        complex16: ::cblas_sys::c_double_complex,
+    }
+}
@@ -0,0 +1,180 @@
+//! The complex Givens rotations that the bundled implementations and the
+//! `fortran` feature export, compared with the formulas of the reference
+//! implementation since version 3.10. They are not declared in the crate
+//! root, since older libraries lack them, and are hence declared here.
+//!
+//! With `fortran`, the rotations call the Fortran routines of the linked
+//! library, which has to provide them.
+
+#![cfg(all(
+    any(feature = "pure-rust", feature = "vendored", feature = "fortran"),
+    not(any(feature = "suffix-64_", feature = "suffix-_64"))
+))]
+
+extern crate cblas_sys;
+
+use cblas_sys::{blasint, c_double_complex, c_float_complex};
+
+extern "C" {
+    fn cblas_crotg(
+        a: *mut c_float_complex,
+        b: *mut c_float_complex,
+        c: *mut f32,
+        s: *mut c_float_complex,
+    );
+    fn cblas_zrotg(
+        a: *mut c_double_complex,
+        b: *mut c_double_complex,
+        c: *mut f64,
+        s: *mut c_double_complex,
+    );
+    fn cblas_csrot(
+        n: blasint,
+        x: *mut c_float_complex,
+        incx: blasint,
+        y: *mut c_float_complex,
+        incy: blasint,
+        c: f32,
+        s: f32,
+    );
+    fn cblas_zdrot(
+        n: blasint,
+        x: *mut c_double_complex,
+        incx: blasint,
+        y: *mut c_double_complex,
+        incy: blasint,
+        c: f64,
+        s: f64,
+    );
+}
+
+type C = [f64; 2];
+
+fn mul(a: C, b: C) -> C {
+    [a[0] * b[0] - a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+}
+
+fn scale(a: C, factor: f64) -> C {
+    [a[0] * factor, a[1] * factor]
+}
+
+fn abs(a: C) -> f64 {
+    a[0].hypot(a[1])
+}
+
+fn close(actual: &[f64], expected: &[f64], epsilon: f64, context: &str) {
+    for (&actual, &expected) in actual.iter().zip(expected) {
+        assert!(
+            (actual - expected).abs() <= epsilon * (1.0 + expected.abs()),
+            "{}: {:?} instead of {:?}",
+            context,
+            actual,
+            expected,
+        );
+    }
+}
+
+// The rotation zeroing g with c real and nonnegative, i.e., the cosine, the
+// sine, and r, which replaces f.
+fn rotation(f: C, g: C) -> (f64, C, C) {
+    if g == [0.0, 0.0] {
+        (1.0, [0.0, 0.0], f)
+    } else if f == [0.0, 0.0] {
+        (0.0, scale([g[0], -g[1]], 1.0 / abs(g)), [abs(g), 0.0])
+    } else {
+        let norm = abs(f).hypot(abs(g));
+        let alpha = scale(f, 1.0 / abs(f));
+        (
+            abs(f) / norm,
+            scale(mul(alpha, [g[0], -g[1]]), 1.0 / norm),
+            scale(alpha, norm),
+        )
+    }
+}
+
+const PAIRS: [(C, C); 5] = [
+    ([3.0, 4.0], [0.0, 0.0]),
+    ([0.0, 0.0], [-1.0, 2.0]),
+    ([0.0, 0.0], [0.0, 0.0]),
+    ([3.0, -1.0], [2.0, 5.0]),
+    ([-1e-3, 2e-3], [4e2, -3e2]),
+];
+
+macro_rules! rotg {
+    ($name:ident, $rotg:ident, $t:ty, $epsilon:expr) => {
+        #[test]
+        fn $name() {
+            for &(f, g) in PAIRS.iter() {
+                let (c, s, r) = rotation(f, g);
+                let mut a = [f[0] as $t, f[1] as $t];
+                let mut b = [g[0] as $t, g[1] as $t];
+                let (mut cos, mut sin) = (0.0, [0.0; 2]);
+                unsafe { $rotg(&mut a, &mut b, &mut cos, &mut sin) };
+                let context = format!("{:?} {:?}", f, g);
+                close(&[cos as f64], &[c], $epsilon, &context);
+                close(&[sin[0] as f64, sin[1] as f64], &s, $epsilon, &context);
+                close(&[a[0] as f64, a[1] as f64], &r, $epsilon, &context);
+            }
+        }
+    };
+}
+
+rotg!(crotg, cblas_crotg, f32, 1e-6);
+rotg!(zrotg, cblas_zrotg, f64, 1e-14);
+
+// The rotation of two vectors of three elements, x := c * x + s * y and
+// y := c * y - s * x, at the given increments.
+macro_rules! rot {
+    ($name:ident, $rot:ident, $t:ty, $epsilon:expr) => {
+        #[test]
+        fn $name() {
+            let x: [C; 3] = [[1.0, 2.0], [-3.0, 0.5], [0.0, -1.0]];
+            let y: [C; 3] = [[2.0, -1.0], [4.0, 1.0], [-0.5, 3.0]];
+            let (c, s) = (0.6, 0.8);
+            for &(incx, incy) in [(1, 1), (2, -1), (-3, 2)].iter() {
+                let position = |inc: isize, i: usize| {
+                    if inc < 0 {
+                        (2 - i) * (-inc) as usize
+                    } else {
+                        i * inc as usize
+                    }
+                };
+                let (mut u, mut v) = (vec![[9.0 as $t; 2]; 7], vec![[9.0 as $t; 2]; 7]);
+                for i in 0..3 {
+                    u[position(incx, i)] = [x[i][0] as $t, x[i][1] as $t];
+                    v[position(incy, i)] = [y[i][0] as $t, y[i][1] as $t];
+                }
+                let flatten = |v: &[[$t; 2]]| {
+                    v.iter()
+                        .flat_map(|z| z.iter().map(|&p| p as f64))
+                        .collect::<Vec<_>>()
+                };
+                let (mut expected_u, mut expected_v) = (flatten(&u), flatten(&v));
+                for i in 0..3 {
+                    let (p, q) = (position(incx, i), position(incy, i));
+                    for k in 0..2 {
+                        expected_u[2 * p + k] = c * x[i][k] + s * y[i][k];
+                        expected_v[2 * q + k] = c * y[i][k] - s * x[i][k];
+                    }
+                }
+                unsafe {
+                    $rot(
+                        3,
+                        u.as_mut_ptr(),
+                        incx as blasint,
+                        v.as_mut_ptr(),
+                        incy as blasint,
+                        c as $t,
+                        s as $t,
+                    )
+                };
+                let context = format!("{} {}", incx, incy);
+                close(&flatten(&u), &expected_u, $epsilon, &context);
+                close(&flatten(&v), &expected_v, $epsilon, &context);
+            }
+        }
+    };
+}
+
+rot!(csrot, cblas_csrot, f32, 1e-6);
+rot!(zdrot, cblas_zdrot, f64, 1e-14);
@@ -0,0 +1,868 @@
+//! The checks of the safe wrappers, which call one of the bundled
+//! implementations when the arguments are valid.
+
+#![cfg(any(feature = "pure-rust", feature = "vendored"))]
+
+extern crate cblas_sys;
+
+use cblas_sys::safe::{level1, CblasError};
+
+#[test]
+fn zero_increment() {
+    let x = [2.0];
+    let y = [1.0, 2.0, 3.0];
+    assert_eq!(level1::ddot(3, &x, 0, &y, 1), Ok(12.0));
+    assert_eq!(level1::ddot(3, &y, 1, &x, 0), Ok(12.0));
+
+    let mut z = [1.0, 1.0, 1.0];
+    level1::daxpy(3, 2.0, &x, 0, &mut z, 1).unwrap();
+    assert_eq!(z, [5.0, 5.0, 5.0]);
+    level1::dcopy(3, &x, 0, &mut z, -1).unwrap();
+    assert_eq!(z, [2.0, 2.0, 2.0]);
+
+    let w = [[1.0, 1.0]];
+    assert_eq!(level1::zdotu_value(2, &w, 0, &w, 0), Ok([0.0, 4.0]));
+    assert_eq!(level1::zdotc_value(2, &w, 0, &w, 0), Ok([4.0, 0.0]));
+    let mut dot = [0.0; 2];
+    level1::zdotu(2, &w, 0, &w, 0, &mut dot).unwrap();
+    assert_eq!(dot, [0.0, 4.0]);
+}
+
+#[test]
+fn zero_increment_of_output() {
+    let x = [2.0];
+    let mut y = [1.0, 2.0];
+    assert_eq!(
+        level1::dcopy(2, &x, 0, &mut y, 0),
+        Err(CblasError::InvalidArgument { name: "incy" })
+    );
+    assert_eq!(
+        level1::dswap(2, &mut [1.0, 2.0], 1, &mut y, 0),
+        Err(CblasError::InvalidArgument { name: "incy" })
+    );
+    assert_eq!(
+        level1::dscal(2, 2.0, &mut y, 0),
+        Err(CblasError::InvalidArgument { name: "incx" })
+    );
+}
+
+#[test]
+fn empty_input() {
+    let mut y = [1.0, 2.0];
+    assert_eq!(
+        level1::ddot(2, &[], 0, &y, 1),
+        Err(CblasError::BufferTooSmall {
+            name: "x",
+            required: 1,
+            actual: 0,
+        })
+    );
+    assert_eq!(level1::ddot(0, &[], 0, &[], 0), Ok(0.0));
+    level1::daxpy(0, 2.0, &[], 0, &mut y, 1).unwrap();
+    assert_eq!(y, [1.0, 2.0]);
+}
+
+#[test]
+fn level2_validation() {
+    use cblas_sys::safe::level2;
+    use cblas_sys::{CblasColMajor, CblasLower, CblasNoTrans, CblasNonUnit, CblasRowMajor};
+    use cblas_sys::{CblasTrans, CblasUpper};
+
+    // A is 2-by-3 with lda = 3 in column-major order, hence 3 * 2 + 2 = 8
+    // elements.
+    let a = [1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0];
+    let x = [1.0, 1.0, 1.0];
+    let mut y = [0.0; 2];
+    level2::dgemv(
+        CblasColMajor,
+        CblasNoTrans,
+        2,
+        3,
+        1.0,
+        &a,
+        3,
+        &x,
+        1,
+        0.0,
+        &mut y,
+        1,
+    )
+    .unwrap();
+    assert_eq!(y, [9.0, 12.0]);
+
+    assert_eq!(
+        level2::dgemv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1.0,
+            &a[..7],
+            3,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "a",
+            required: 8,
+            actual: 7,
+        })
+    );
+    assert_eq!(
+        level2::dgemv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1.0,
+            &a,
+            1,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+    // In row-major order, lda has to cover the three columns.
+    assert_eq!(
+        level2::dgemv(
+            CblasRowMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1.0,
+            &a,
+            2,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+    // Transposed, x has two elements and y three.
+    assert_eq!(
+        level2::dgemv(
+            CblasColMajor,
+            CblasTrans,
+            2,
+            3,
+            1.0,
+            &a,
+            3,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "y",
+            required: 3,
+            actual: 2,
+        })
+    );
+    assert_eq!(
+        level2::dgemv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1.0,
+            &a,
+            3,
+            &x,
+            2,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "x",
+            required: 5,
+            actual: 3,
+        })
+    );
+    assert_eq!(
+        level2::dgemv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1.0,
+            &a,
+            3,
+            &x,
+            0,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::InvalidArgument { name: "incx" })
+    );
+
+    // A band matrix needs kl + ku + 1 rows.
+    assert_eq!(
+        level2::dgbmv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1,
+            1,
+            1.0,
+            &a,
+            2,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+    assert_eq!(
+        level2::dgbmv(
+            CblasColMajor,
+            CblasNoTrans,
+            2,
+            3,
+            1,
+            1,
+            1.0,
+            &a,
+            3,
+            &x,
+            1,
+            0.0,
+            &mut y,
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "a",
+            required: 9,
+            actual: 8,
+        })
+    );
+
+    let mut x = [1.0, 1.0, 1.0];
+    assert_eq!(
+        level2::dtrmv(
+            CblasColMajor,
+            CblasUpper,
+            CblasNoTrans,
+            CblasNonUnit,
+            3,
+            &a,
+            3,
+            &mut x,
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "a",
+            required: 9,
+            actual: 8,
+        })
+    );
+    assert_eq!(
+        level2::dtpmv(
+            CblasRowMajor,
+            CblasLower,
+            CblasNoTrans,
+            CblasNonUnit,
+            4,
+            &a,
+            &mut [0.0; 4],
+            1
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "ap",
+            required: 10,
+            actual: 8,
+        })
+    );
+    // The packed lower triangle of [[1, 0, 0], [2, 3, 0], [4, 5, 6]] by rows.
+    let ap = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    level2::dtpmv(
+        CblasRowMajor,
+        CblasLower,
+        CblasNoTrans,
+        CblasNonUnit,
+        3,
+        &ap,
+        &mut x,
+        1,
+    )
+    .unwrap();
+    assert_eq!(x, [1.0, 5.0, 15.0]);
+
+    let mut c = [0.0; 4];
+    assert_eq!(
+        level2::dsyr(CblasColMajor, CblasUpper, 2, 1.0, &[1.0, 2.0], 1, &mut c, 1),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+    level2::dsyr(CblasColMajor, CblasUpper, 2, 1.0, &[1.0, 2.0], 1, &mut c, 2).unwrap();
+    assert_eq!(c, [1.0, 0.0, 2.0, 4.0]);
+    assert_eq!(
+        level2::dger(
+            CblasRowMajor,
+            2,
+            2,
+            1.0,
+            &[1.0, 2.0],
+            1,
+            &[1.0],
+            1,
+            &mut c,
+            2
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "y",
+            required: 2,
+            actual: 1,
+        })
+    );
+}
+
+#[test]
+fn level3_validation() {
+    use cblas_sys::safe::level3;
+    use cblas_sys::{CblasColMajor, CblasConjTrans, CblasLeft, CblasNoTrans, CblasNonUnit};
+    use cblas_sys::{CblasRight, CblasRowMajor, CblasTrans, CblasUpper};
+
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [1.0, 0.0, 0.0, 1.0];
+    let mut c = [0.0; 4];
+    level3::dgemm(
+        CblasRowMajor,
+        CblasNoTrans,
+        CblasNoTrans,
+        2,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        &b,
+        2,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap();
+    assert_eq!(c, a);
+    assert_eq!(
+        level3::dgemm(
+            CblasRowMajor,
+            CblasNoTrans,
+            CblasNoTrans,
+            2,
+            2,
+            2,
+            1.0,
+            &a,
+            2,
+            &b[..3],
+            2,
+            0.0,
+            &mut c,
+            2,
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "b",
+            required: 4,
+            actual: 3,
+        })
+    );
+    assert_eq!(
+        level3::dgemm(
+            CblasColMajor,
+            CblasNoTrans,
+            CblasNoTrans,
+            2,
+            2,
+            2,
+            1.0,
+            &a,
+            2,
+            &b,
+            2,
+            0.0,
+            &mut c,
+            1,
+        ),
+        Err(CblasError::InvalidArgument { name: "ldc" })
+    );
+    // Transposed, A is stored as 3-by-2, so that lda = 2 is too small in
+    // column-major order.
+    assert_eq!(
+        level3::dgemm(
+            CblasColMajor,
+            CblasTrans,
+            CblasNoTrans,
+            2,
+            2,
+            3,
+            1.0,
+            &[0.0; 6],
+            2,
+            &[0.0; 6],
+            3,
+            0.0,
+            &mut c,
+            2,
+        ),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+
+    // The order of A follows the side.
+    let mut b = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    assert_eq!(
+        level3::dtrsm(
+            CblasColMajor,
+            CblasRight,
+            CblasUpper,
+            CblasNoTrans,
+            CblasNonUnit,
+            2,
+            3,
+            1.0,
+            &a,
+            2,
+            &mut b,
+            2,
+        ),
+        Err(CblasError::InvalidArgument { name: "lda" })
+    );
+    level3::dtrsm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        CblasNoTrans,
+        CblasNonUnit,
+        2,
+        3,
+        2.0,
+        &[1.0, 0.0, 0.0, 2.0],
+        2,
+        &mut b,
+        2,
+    )
+    .unwrap();
+    assert_eq!(b, [2.0, 2.0, 6.0, 4.0, 10.0, 6.0]);
+
+    // The conjugate transpose is invalid for the complex symmetric updates
+    // and the transpose for the Hermitian ones.
+    let z = [[1.0, 1.0]; 4];
+    let mut w = [[0.0; 2]; 4];
+    let one = [1.0, 0.0];
+    assert_eq!(
+        level3::zsyrk(
+            CblasColMajor,
+            CblasUpper,
+            CblasConjTrans,
+            2,
+            2,
+            one,
+            &z,
+            2,
+            one,
+            &mut w,
+            2
+        ),
+        Err(CblasError::InvalidArgument { name: "trans" })
+    );
+    assert_eq!(
+        level3::zherk(
+            CblasColMajor,
+            CblasUpper,
+            CblasTrans,
+            2,
+            2,
+            1.0,
+            &z,
+            2,
+            1.0,
+            &mut w,
+            2
+        ),
+        Err(CblasError::InvalidArgument { name: "trans" })
+    );
+    assert_eq!(
+        level3::zsyr2k(
+            CblasColMajor,
+            CblasUpper,
+            CblasConjTrans,
+            2,
+            2,
+            one,
+            &z,
+            2,
+            &z,
+            2,
+            one,
+            &mut w,
+            2,
+        ),
+        Err(CblasError::InvalidArgument { name: "trans" })
+    );
+    assert_eq!(
+        level3::zher2k(
+            CblasColMajor,
+            CblasUpper,
+            CblasTrans,
+            2,
+            2,
+            one,
+            &z,
+            2,
+            &z,
+            2,
+            1.0,
+            &mut w,
+            2,
+        ),
+        Err(CblasError::InvalidArgument { name: "trans" })
+    );
+    // For the real types, the conjugate transpose is the transpose.
+    let mut c = [0.0; 4];
+    level3::dsyrk(
+        CblasColMajor,
+        CblasUpper,
+        CblasConjTrans,
+        2,
+        2,
+        1.0,
+        &a,
+        2,
+        0.0,
+        &mut c,
+        2,
+    )
+    .unwrap();
+    assert_eq!(c, [5.0, 0.0, 11.0, 25.0]);
+    level3::zherk(
+        CblasColMajor,
+        CblasUpper,
+        CblasConjTrans,
+        2,
+        2,
+        1.0,
+        &z,
+        2,
+        0.0,
+        &mut w,
+        2,
+    )
+    .unwrap();
+    assert_eq!(w, [[4.0, 0.0], [0.0, 0.0], [4.0, 0.0], [4.0, 0.0]]);
+    assert_eq!(
+        level3::zherk(
+            CblasColMajor,
+            CblasUpper,
+            CblasNoTrans,
+            2,
+            2,
+            1.0,
+            &z[..3],
+            2,
+            0.0,
+            &mut w,
+            2
+        ),
+        Err(CblasError::BufferTooSmall {
+            name: "a",
+            required: 4,
+            actual: 3,
+        })
+    );
+}
+
+// The batched wrappers, whose batches mix runs of problems with equal
+// parameters and problems with other ones, compared with the unbatched
+// wrappers, which compute the same values.
+#[cfg(feature = "extensions")]
+mod batch {
+    use cblas_sys::safe::batch::{self, Axpy, AxpyParams, Gemm, GemmParams};
+    use cblas_sys::safe::batch::{Gemv, GemvParams, Trsm, TrsmParams};
+    use cblas_sys::safe::{level1, level2, level3, CblasError};
+    use cblas_sys::{CblasColMajor, CblasConjTrans, CblasLeft, CblasLower, CblasNoTrans};
+    use cblas_sys::{CblasNonUnit, CblasRight, CblasRowMajor, CblasTrans, CblasUnit, CblasUpper};
+
+    /// Nonzero values of an operand, which differ between problems.
+    fn values(len: usize, problem: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| ((7 * i + 3 * problem) % 11) as f64 - 4.5)
+            .collect()
+    }
+
+    fn complex_values(len: usize, problem: usize) -> Vec<[f64; 2]> {
+        let parts = values(2 * len, problem);
+        parts.chunks(2).map(|z| [z[0], z[1]]).collect()
+    }
+
+    #[test]
+    fn axpy() {
+        let params = AxpyParams {
+            n: 3,
+            alpha: 2.0,
+            incx: 1,
+            incy: 1,
+        };
+        let other = AxpyParams {
+            alpha: -1.0,
+            incx: 0,
+            incy: -2,
+            ..params
+        };
+        let all = [params, params, other, other, params];
+        let x = (0..all.len()).map(|p| values(5, p)).collect::<Vec<_>>();
+        let mut y = (0..all.len()).map(|p| values(5, p + 5)).collect::<Vec<_>>();
+        let mut expected = y.clone();
+        for (p, params) in all.iter().enumerate() {
+            let (n, alpha, incx, incy) = (params.n, params.alpha, params.incx, params.incy);
+            level1::daxpy(n, alpha, &x[p], incx, &mut expected[p], incy).unwrap();
+        }
+        let mut problems = all
+            .iter()
+            .zip(&x)
+            .zip(y.iter_mut())
+            .map(|((&params, x), y)| Axpy { params, x, y })
+            .collect::<Vec<_>>();
+        batch::daxpy_batch(&mut problems).unwrap();
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn gemv() {
+        let params = GemvParams {
+            trans: CblasNoTrans,
+            m: 2,
+            n: 3,
+            alpha: 1.0,
+            lda: 3,
+            incx: 1,
+            beta: 0.0,
+            incy: 1,
+        };
+        let other = GemvParams {
+            trans: CblasTrans,
+            alpha: -2.0,
+            beta: 1.0,
+            incy: -1,
+            ..params
+        };
+        let all = [params, other, other, params, params];
+        let a = (0..all.len()).map(|p| values(6, p)).collect::<Vec<_>>();
+        let x = (0..all.len()).map(|p| values(3, p + 5)).collect::<Vec<_>>();
+        let mut y = (0..all.len())
+            .map(|p| values(3, p + 10))
+            .collect::<Vec<_>>();
+        let mut expected = y.clone();
+        for (p, params) in all.iter().enumerate() {
+            level2::dgemv(
+                CblasRowMajor,
+                params.trans,
+                params.m,
+                params.n,
+                params.alpha,
+                &a[p],
+                params.lda,
+                &x[p],
+                params.incx,
+                params.beta,
+                &mut expected[p],
+                params.incy,
+            )
+            .unwrap();
+        }
+        let mut problems = all
+            .iter()
+            .zip(a.iter().zip(&x))
+            .zip(y.iter_mut())
+            .map(|((&params, (a, x)), y)| Gemv { params, a, x, y })
+            .collect::<Vec<_>>();
+        batch::dgemv_batch(CblasRowMajor, &mut problems).unwrap();
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn gemm() {
+        let params = GemmParams {
+            transa: CblasNoTrans,
+            transb: CblasNoTrans,
+            m: 2,
+            n: 2,
+            k: 3,
+            alpha: [1.0, 0.0],
+            lda: 3,
+            ldb: 2,
+            beta: [0.0, 0.0],
+            ldc: 2,
+        };
+        let other = GemmParams {
+            transa: CblasConjTrans,
+            alpha: [0.0, 1.0],
+            lda: 2,
+            beta: [1.0, -1.0],
+            ..params
+        };
+        let all = [params, params, other, params];
+        let a = (0..all.len())
+            .map(|p| complex_values(6, p))
+            .collect::<Vec<_>>();
+        let b = (0..all.len())
+            .map(|p| complex_values(6, p + 4))
+            .collect::<Vec<_>>();
+        let mut c = (0..all.len())
+            .map(|p| complex_values(4, p + 8))
+            .collect::<Vec<_>>();
+        let mut expected = c.clone();
+        for (p, params) in all.iter().enumerate() {
+            level3::zgemm(
+                CblasRowMajor,
+                params.transa,
+                params.transb,
+                params.m,
+                params.n,
+                params.k,
+                params.alpha,
+                &a[p],
+                params.lda,
+                &b[p],
+                params.ldb,
+                params.beta,
+                &mut expected[p],
+                params.ldc,
+            )
+            .unwrap();
+        }
+        let mut problems = all
+            .iter()
+            .zip(a.iter().zip(&b))
+            .zip(c.iter_mut())
+            .map(|((&params, (a, b)), c)| Gemm { params, a, b, c })
+            .collect::<Vec<_>>();
+        batch::zgemm_batch(CblasRowMajor, &mut problems).unwrap();
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn trsm() {
+        let params = TrsmParams {
+            side: CblasLeft,
+            uplo: CblasUpper,
+            transa: CblasNoTrans,
+            diag: CblasNonUnit,
+            m: 2,
+            n: 3,
+            alpha: 1.0,
+            lda: 2,
+            ldb: 2,
+        };
+        let other = TrsmParams {
+            side: CblasRight,
+            uplo: CblasLower,
+            transa: CblasTrans,
+            diag: CblasUnit,
+            alpha: 0.5,
+            lda: 3,
+            ..params
+        };
+        let all = [other, params, params, params, other];
+        let a = (0..all.len()).map(|p| values(9, p)).collect::<Vec<_>>();
+        let mut b = (0..all.len()).map(|p| values(6, p + 5)).collect::<Vec<_>>();
+        let mut expected = b.clone();
+        for (p, params) in all.iter().enumerate() {
+            level3::dtrsm(
+                CblasColMajor,
+                params.side,
+                params.uplo,
+                params.transa,
+                params.diag,
+                params.m,
+                params.n,
+                params.alpha,
+                &a[p],
+                params.lda,
+                &mut expected[p],
+                params.ldb,
+            )
+            .unwrap();
+        }
+        let mut problems = all
+            .iter()
+            .zip(&a)
+            .zip(b.iter_mut())
+            .map(|((&params, a), b)| Trsm { params, a, b })
+            .collect::<Vec<_>>();
+        batch::dtrsm_batch(CblasColMajor, &mut problems).unwrap();
+        assert_eq!(b, expected);
+    }
+
+    // Nothing is computed if any problem is invalid.
+    #[test]
+    fn invalid_problem() {
+        let params = AxpyParams {
+            n: 3,
+            alpha: 1.0,
+            incx: 1,
+            incy: 1,
+        };
+        let x = [1.0; 3];
+        let (mut y, mut z) = ([0.0; 3], [0.0; 2]);
+        let mut problems = [
+            Axpy {
+                params,
+                x: &x,
+                y: &mut y,
+            },
+            Axpy {
+                params,
+                x: &x,
+                y: &mut z,
+            },
+        ];
+        assert_eq!(
+            batch::daxpy_batch(&mut problems),
+            Err(CblasError::BufferTooSmall {
+                name: "y",
+                required: 3,
+                actual: 2,
+            })
+        );
+        assert_eq!(y, [0.0; 3]);
+
+        let params = GemmParams {
+            transa: CblasNoTrans,
+            transb: CblasNoTrans,
+            m: 2,
+            n: 2,
+            k: 2,
+            alpha: 1.0,
+            lda: 2,
+            ldb: 2,
+            beta: 0.0,
+            ldc: 1,
+        };
+        let (a, mut c) = ([1.0; 4], [0.0; 4]);
+        let mut problems = [Gemm {
+            params,
+            a: &a,
+            b: &a,
+            c: &mut c,
+        }];
+        assert_eq!(
+            batch::dgemm_batch(CblasColMajor, &mut problems),
+            Err(CblasError::InvalidArgument { name: "ldc" })
+        );
+        assert_eq!(batch::dgemm_batch(CblasColMajor, &mut []), Ok(()));
+        assert_eq!(c, [0.0; 4]);
+    }
+}
@@ -0,0 +1,420 @@
+//! The checks that the generic functions of `BlasScalar` call the routines of
+//! the corresponding type, which are compared with straightforward loops on
+//! values that every type represents exactly.
+
+#![cfg(any(feature = "pure-rust", feature = "vendored"))]
+
+extern crate cblas_sys;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+use cblas_sys::safe::BlasScalar;
+use cblas_sys::{c_double_complex, c_float_complex};
+use cblas_sys::{CblasColMajor, CblasConjTrans, CblasLeft, CblasLower, CblasNoTrans};
+use cblas_sys::{CblasRowMajor, CblasTrans, CblasUnit, CblasUpper};
+
+type C = [f64; 2];
+
+fn add(a: C, b: C) -> C {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn mul(a: C, b: C) -> C {
+    [a[0] * b[0] - a[1] * b[1], a[0] * b[1] + a[1] * b[0]]
+}
+
+fn conj(a: C) -> C {
+    [a[0], -a[1]]
+}
+
+trait Element: BlasScalar + Debug + PartialEq {
+    // The imaginary part is dropped by the real types.
+    fn new(re: f64, im: f64) -> Self;
+    fn parts(self) -> C;
+    fn real(value: Self::Real) -> f64;
+}
+
+macro_rules! real {
+    ($t:ty) => {
+        impl Element for $t {
+            fn new(re: f64, _: f64) -> Self {
+                re as $t
+            }
+
+            fn parts(self) -> C {
+                [self as f64, 0.0]
+            }
+
+            fn real(value: $t) -> f64 {
+                value as f64
+            }
+        }
+    };
+}
+
+macro_rules! complex {
+    ($t:ty, $real:ty) => {
+        impl Element for $t {
+            fn new(re: f64, im: f64) -> Self {
+                [re as $real, im as $real]
+            }
+
+            fn parts(self) -> C {
+                [self[0] as f64, self[1] as f64]
+            }
+
+            fn real(value: $real) -> f64 {
+                value as f64
+            }
+        }
+    };
+}
+
+real!(f32);
+real!(f64);
+complex!(c_float_complex, f32);
+complex!(c_double_complex, f64);
+
+fn vector<T: Element>(values: &[C]) -> Vec<T> {
+    values
+        .iter()
+        .map(|value| T::new(value[0], value[1]))
+        .collect()
+}
+
+fn parts<T: Element>(values: &[T]) -> Vec<C> {
+    values.iter().map(|&value| value.parts()).collect()
+}
+
+fn dispatch<T: Element>() {
+    let x = parts(&vector::<T>(&[[1.0, 2.0], [3.0, -1.0], [-2.0, 0.5]]));
+    let y = parts(&vector::<T>(&[[2.0, 1.0], [-1.0, 4.0], [0.5, -1.0]]));
+    let alpha = T::new(2.0, -1.0);
+    let a = alpha.parts();
+    let (tx, ty) = (vector::<T>(&x), vector::<T>(&y));
+
+    // Level 1.
+    let dot = (0..3).fold([0.0; 2], |sum, i| add(sum, mul(x[i], y[i])));
+    let dotc = (0..3).fold([0.0; 2], |sum, i| add(sum, mul(conj(x[i]), y[i])));
+    assert_eq!(T::dot(3, &tx, 1, &ty, 1).unwrap().parts(), dot);
+    assert_eq!(T::dotc(3, &tx, 1, &ty, 1).unwrap().parts(), dotc);
+    let squares: f64 = x.iter().map(|v| v[0] * v[0] + v[1] * v[1]).sum();
+    let nrm2 = T::real(T::nrm2(3, &tx, 1).unwrap());
+    assert!((nrm2 - squares.sqrt()).abs() < 1e-5, "{} {}", nrm2, squares);
+    let asum: f64 = x.iter().map(|v| v[0].abs() + v[1].abs()).sum();
+    assert_eq!(T::real(T::asum(3, &tx, 1).unwrap()), asum);
+    let abs1 = |v: C| v[0].abs() + v[1].abs();
+    let iamax = (1..3).fold(0, |k, i| if abs1(x[i]) > abs1(x[k]) { i } else { k });
+    assert_eq!(T::iamax(3, &tx, 1).unwrap(), iamax);
+    let iamax = if abs1(y[2]) > abs1(y[0]) { 1 } else { 0 };
+    assert_eq!(T::iamax(2, &ty, 2).unwrap(), iamax);
+
+    let (mut u, mut v) = (tx.clone(), ty.clone());
+    T::swap(3, &mut u, 1, &mut v, -1).unwrap();
+    assert_eq!(
+        (parts(&u), parts(&v)),
+        (vec![y[2], y[1], y[0]], vec![x[2], x[1], x[0]])
+    );
+    T::copy(3, &tx, 1, &mut u, 1).unwrap();
+    assert_eq!(u, tx);
+
+    let mut v = ty.clone();
+    T::axpy(3, alpha, &tx, 1, &mut v, 1).unwrap();
+    let axpy = (0..3).map(|i| add(mul(a, x[i]), y[i])).collect::<Vec<_>>();
+    assert_eq!(parts(&v), axpy);
+    T::scal(3, alpha, &mut u, 1).unwrap();
+    assert_eq!(parts(&u), (0..3).map(|i| mul(a, x[i])).collect::<Vec<_>>());
+
+    // Level 2 with the row-major 2-by-3 matrix M.
+    let m = [
+        [1.0, -1.0],
+        [2.0, 0.0],
+        [0.5, 1.0],
+        [-1.0, 2.0],
+        [3.0, 0.5],
+        [1.0, 1.0],
+    ];
+    let m = parts(&vector::<T>(&m));
+    let tm = vector::<T>(&m);
+    let mut w = ty[..2].to_vec();
+    T::gemv(
+        CblasRowMajor,
+        CblasNoTrans,
+        2,
+        3,
+        alpha,
+        &tm,
+        3,
+        &tx,
+        1,
+        T::new(1.0, 0.0),
+        &mut w,
+        1,
+    )
+    .unwrap();
+    let gemv = (0..2)
+        .map(|i| {
+            add(
+                mul(
+                    a,
+                    (0..3).fold([0.0; 2], |s, j| add(s, mul(m[3 * i + j], x[j]))),
+                ),
+                y[i],
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&w), gemv);
+    let mut w = ty.clone();
+    let zero = T::new(0.0, 0.0);
+    T::gemv(
+        CblasRowMajor,
+        CblasConjTrans,
+        2,
+        3,
+        alpha,
+        &tm,
+        3,
+        &tx,
+        1,
+        zero,
+        &mut w,
+        1,
+    )
+    .unwrap();
+    let gemv = (0..3)
+        .map(|j| {
+            mul(
+                a,
+                (0..2).fold([0.0; 2], |s, i| add(s, mul(conj(m[3 * i + j]), x[i]))),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&w), gemv);
+
+    let mut n = tm.clone();
+    T::ger(CblasRowMajor, 2, 3, alpha, &tx, 1, &ty, 1, &mut n, 3).unwrap();
+    let ger = (0..6)
+        .map(|k| add(mul(a, mul(x[k / 3], y[k % 3])), m[k]))
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&n), ger);
+
+    // The unit lower triangle of the column-major 3-by-3 matrix L, whose
+    // upper triangle is ignored.
+    let l = parts(&vector::<T>(&[
+        [9.0, 9.0],
+        [2.0, 1.0],
+        [-1.0, 0.5],
+        [9.0, 9.0],
+        [9.0, 9.0],
+        [0.5, -2.0],
+        [9.0, 9.0],
+        [9.0, 9.0],
+        [9.0, 9.0],
+    ]));
+    let tl = vector::<T>(&l);
+    let lower = |i: usize, j: usize| match i.cmp(&j) {
+        Ordering::Less => [0.0; 2],
+        Ordering::Equal => [1.0, 0.0],
+        Ordering::Greater => l[i + 3 * j],
+    };
+    let mut z = tx.clone();
+    T::trmv(
+        CblasColMajor,
+        CblasLower,
+        CblasTrans,
+        CblasUnit,
+        3,
+        &tl,
+        3,
+        &mut z,
+        1,
+    )
+    .unwrap();
+    let trmv = (0..3)
+        .map(|i| (0..3).fold([0.0; 2], |s, j| add(s, mul(lower(j, i), x[j]))))
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&z), trmv);
+    T::trsv(
+        CblasColMajor,
+        CblasLower,
+        CblasTrans,
+        CblasUnit,
+        3,
+        &tl,
+        3,
+        &mut z,
+        1,
+    )
+    .unwrap();
+    assert_eq!(z, tx);
+
+    // Level 3 with the column-major 3-by-2 matrix B built from x and y.
+    let b = [x.clone(), y.clone()].concat();
+    let tb = vector::<T>(&b);
+    let mut c = tb.clone();
+    T::gemm(
+        CblasColMajor,
+        CblasNoTrans,
+        CblasNoTrans,
+        3,
+        2,
+        3,
+        alpha,
+        &tl,
+        3,
+        &tb,
+        3,
+        zero,
+        &mut c,
+        3,
+    )
+    .unwrap();
+    let gemm = (0..6)
+        .map(|k| {
+            mul(
+                a,
+                (0..3).fold([0.0; 2], |s, p| {
+                    add(s, mul(l[k % 3 + 3 * p], b[p + 3 * (k / 3)]))
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&c), gemm);
+
+    // The symmetric matrix S of order 3 whose upper triangle is that of L.
+    let upper = |i: usize, j: usize| if i <= j { l[i + 3 * j] } else { l[j + 3 * i] };
+    let mut c = tb.clone();
+    T::symm(
+        CblasColMajor,
+        CblasLeft,
+        CblasUpper,
+        3,
+        2,
+        alpha,
+        &tl,
+        3,
+        &tb,
+        3,
+        zero,
+        &mut c,
+        3,
+    )
+    .unwrap();
+    let symm = (0..6)
+        .map(|k| {
+            mul(
+                a,
+                (0..3).fold([0.0; 2], |s, p| {
+                    add(s, mul(upper(k % 3, p), b[p + 3 * (k / 3)]))
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&c), symm);
+
+    // Only the lower triangle of C := alpha * B * B^T is written.
+    let mut c = vec![T::new(7.0, 0.0); 9];
+    T::syrk(
+        CblasColMajor,
+        CblasLower,
+        CblasNoTrans,
+        3,
+        2,
+        alpha,
+        &tb,
+        3,
+        zero,
+        &mut c,
+        3,
+    )
+    .unwrap();
+    let syrk = (0..9)
+        .map(|k| {
+            let (i, j) = (k % 3, k / 3);
+            if i < j {
+                [7.0, 0.0]
+            } else {
+                mul(
+                    a,
+                    (0..2).fold([0.0; 2], |s, p| add(s, mul(b[i + 3 * p], b[j + 3 * p]))),
+                )
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&c), syrk);
+
+    let mut c = tb.clone();
+    T::trmm(
+        CblasColMajor,
+        CblasLeft,
+        CblasLower,
+        CblasNoTrans,
+        CblasUnit,
+        3,
+        2,
+        alpha,
+        &tl,
+        3,
+        &mut c,
+        3,
+    )
+    .unwrap();
+    let trmm = (0..6)
+        .map(|k| {
+            mul(
+                a,
+                (0..3).fold([0.0; 2], |s, p| {
+                    add(s, mul(lower(k % 3, p), b[p + 3 * (k / 3)]))
+                }),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(parts(&c), trmm);
+    let one = T::new(1.0, 0.0);
+    let mut c = vector::<T>(
+        &(0..6)
+            .map(|k| {
+                (0..3).fold([0.0; 2], |s, p| {
+                    add(s, mul(lower(k % 3, p), b[p + 3 * (k / 3)]))
+                })
+            })
+            .collect::<Vec<_>>(),
+    );
+    T::trsm(
+        CblasColMajor,
+        CblasLeft,
+        CblasLower,
+        CblasNoTrans,
+        CblasUnit,
+        3,
+        2,
+        one,
+        &tl,
+        3,
+        &mut c,
+        3,
+    )
+    .unwrap();
+    assert_eq!(c, tb);
+}
+
+#[test]
+fn single() {
+    dispatch::<f32>();
+}
+
+#[test]
+fn double() {
+    dispatch::<f64>();
+}
+
+#[test]
+fn complex() {
+    dispatch::<c_float_complex>();
+}
+
+#[test]
+fn complex16() {
+    dispatch::<c_double_complex>();
+}
@@ -0,0 +1,67 @@
+//! The checks of the reporting of invalid arguments by one of the bundled
+//! implementations.
+
+#![cfg(all(feature = "xerbla", any(feature = "pure-rust", feature = "vendored")))]
+
+extern crate cblas_sys;
+
+use std::sync::Mutex;
+use std::thread;
+
+use cblas_sys::xerbla::{self, InvalidArgument};
+use cblas_sys::{cblas_dgemv, CblasColMajor, CblasNoTrans};
+
+static REPORTED: Mutex<Vec<InvalidArgument>> = Mutex::new(Vec::new());
+
+// Call `cblas_dgemv` with a leading dimension smaller than the number of
+// rows, which is the seventh argument.
+fn invalid_gemv() {
+    let (a, x, mut y) = ([1.0; 6], [1.0; 2], [2.0; 3]);
+    unsafe {
+        cblas_dgemv(
+            CblasColMajor,
+            CblasNoTrans,
+            3,
+            2,
+            1.0,
+            a.as_ptr(),
+            1,
+            x.as_ptr(),
+            1,
+            0.0,
+            y.as_mut_ptr(),
+            1,
+        );
+    }
+    assert_eq!(y, [2.0; 3]);
+}
+
+#[test]
+fn invalid_argument() {
+    let expected = InvalidArgument {
+        routine: "cblas_dgemv".into(),
+        position: 7,
+    };
+
+    xerbla::set_handler(|error| REPORTED.lock().unwrap().push(error.clone()));
+    invalid_gemv();
+    assert_eq!(*REPORTED.lock().unwrap(), vec![expected.clone()]);
+    assert_eq!(xerbla::last_error(), Some(expected.clone()));
+    assert_eq!(xerbla::take_last_error(), Some(expected.clone()));
+    assert_eq!(xerbla::take_last_error(), None);
+    assert_eq!(xerbla::last_error(), None);
+
+    // The error is recorded on the thread that called the routine.
+    let reported = thread::spawn(|| {
+        invalid_gemv();
+        xerbla::take_last_error()
+    });
+    assert_eq!(reported.join().unwrap(), Some(expected.clone()));
+    assert_eq!(xerbla::last_error(), None);
+    assert_eq!(REPORTED.lock().unwrap().len(), 2);
+
+    xerbla::clear_handler();
+    invalid_gemv();
+    assert_eq!(REPORTED.lock().unwrap().len(), 2);
+    assert_eq!(xerbla::take_last_error(), Some(expected));
+}